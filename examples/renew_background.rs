@@ -5,8 +5,7 @@ use hc_vault::Config;
 fn main() {
     println!("Starting");
 
-    let auth =
-        token::Session::new("testToken".to_string(), std::time::Duration::from_secs(10)).unwrap();
+    let auth = token::Session::new("testToken".to_string()).unwrap();
 
     let conf = Config {
         ..Default::default()
@@ -14,14 +13,12 @@ fn main() {
 
     let client = Client::new(conf, auth).unwrap();
 
-    let client_arc = std::sync::Arc::new(client);
-
-    let cloned = client_arc.clone();
+    // All clones share the same session
+    let cloned = client.clone();
     std::thread::spawn(move || {
-        match cloned.renew_background() {
-            Err(e) => println!("{}", e),
-            Ok(_) => {}
-        };
+        if let Err(e) = cloned.renew_background() {
+            println!("{}", e);
+        }
     });
 
     loop {
@@ -1,157 +1,83 @@
+use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
-use std::time::SystemTime;
-use url::Url;
 
 use crate::internals;
 use crate::Auth as AuthTrait;
 use crate::Error;
+use crate::HttpClient;
 
 /// The Config for approle login
 #[derive(Clone, Serialize)]
 pub struct ApproleLogin {
     /// The role-id for the role to use
     pub role_id: String,
-    /// The secret-it for the role
+    /// The secret-id for the role, this is not sent to vault if it is empty,
+    /// which is needed for roles that don't require a secret-id
+    #[serde(skip_serializing_if = "String::is_empty")]
     pub secret_id: String,
 }
 
-#[allow(dead_code)]
-#[derive(Deserialize)]
-struct Auth {
-    /// Whether or not the auth-session is renewable
-    pub renewable: bool,
-    /// The duration for which this session is valid
-    pub lease_duration: u64,
-    /// The policies associated with this session/token
-    pub token_policies: Vec<String>,
-    /// IDK
-    pub accessor: String,
-    /// The actual Token that will also be needed/used for further
-    /// requests to vault to authenticate with this session
-    pub client_token: String,
-}
-
-#[allow(dead_code)]
-#[derive(Deserialize)]
-struct ApproleResponse {
-    /// The actual auth content
-    pub auth: Auth,
-    /// The duration for which this lease is valid
-    pub lease_duration: i64,
-    /// Whether or not this lease is renewable
-    pub renewable: bool,
-    /// The id of this lease
-    pub lease_id: String,
+/// A response-wrapped Secret-ID that still needs to be unwrapped
+struct WrappedSecretID {
+    /// The wrapping Token that contains the Secret-ID
+    wrapping_token: String,
+    /// The name of the role the Secret-ID has to be generated for
+    role_name: String,
 }
 
 #[derive(Deserialize)]
-struct RenewAuth {
-    /// Whether or not the auth-session is renewable
-    pub renewable: bool,
-    /// The duration for which this session is valid
-    pub lease_duration: u64,
-    /// The policies associated with this session/token
-    pub policies: Vec<String>,
-    /// The actual Token that will also be needed/used for further
-    /// requests to vault to authenticate with this session
-    pub client_token: String,
+struct UnwrappedSecretID {
+    secret_id: String,
 }
 
-#[derive(Deserialize)]
-struct RenewResponse {
-    /// The new auth data after renewal
-    pub auth: RenewAuth,
+/// Turns a rejected login into an Error, logins that are rejected because of the
+/// `secret_id_bound_cidrs` of the role or the `cidr_list` of the Secret-ID get
+/// their own Error to make it clear why the otherwise valid credentials were
+/// rejected. Vault rejects logins outside of the `token_bound_cidrs` with a
+/// plain "permission denied", so these remain `Error::Unauthorized`
+fn login_error(err: Error) -> Error {
+    let response = match err {
+        Error::InvalidRequest(r) | Error::Unauthorized(r) => r,
+        _ => return err,
+    };
+
+    // Vault rejects these with "source address ... unauthorized by CIDR
+    // restrictions on the role" or "... through CIDR restrictions on the secret ID"
+    let is_bound_cidr = response.errors.iter().any(|e| {
+        let e = e.to_lowercase();
+        e.starts_with("source address") && e.contains("unauthorized")
+    });
+    match response.status {
+        400 | 403 if is_bound_cidr => Error::BoundCIDR(response),
+        _ => Error::from(response),
+    }
 }
 
 /// The Auth session for the approle backend, used by the vault client itself
 /// to authenticate using approle
 pub struct Session {
-    approle: ApproleLogin,
+    approle: std::sync::RwLock<ApproleLogin>,
+    wrapped: tokio::sync::Mutex<Option<WrappedSecretID>>,
 
     token: internals::TokenContainer,
 }
 
+#[async_trait]
 impl AuthTrait for Session {
     fn is_expired(&self) -> bool {
-        let start_time = self.token.get_start();
-        let current_time = SystemTime::now()
-            .duration_since(SystemTime::UNIX_EPOCH)
-            .unwrap()
-            .as_secs();
-
-        let elapsed = current_time - start_time;
-        let duration = self.token.get_duration();
-
-        elapsed >= duration
+        self.token.is_expired()
     }
     fn get_token(&self) -> String {
-        // Safety:
-        // This Operation is indirectly synchronized, because the validity of
-        // the session is checked before the Token is read and if the Token
-        // needs to be updated, all further operations (including reading the
-        // Token) are blocked until the Update of the Token is done.
-        // Therefore the Token is never read while it is also being modified.
-        match self.token.get_token() {
-            None => String::from(""),
-            Some(s) => s,
-        }
+        self.token.get_token()
     }
-    fn auth(&self, vault_url: &str) -> Result<(), Error> {
-        let mut login_url = match Url::parse(vault_url) {
-            Err(e) => {
-                return Err(Error::from(e));
-            }
-            Ok(url) => url,
-        };
-        login_url = match login_url.join("v1/auth/approle/login") {
-            Err(e) => {
-                return Err(Error::from(e));
-            }
-            Ok(u) => u,
-        };
+    async fn auth(&self, http: &HttpClient) -> Result<(), Error> {
+        self.unwrap_secret_id(http).await?;
 
-        let http_client = reqwest::blocking::Client::new();
-        let res = http_client.post(login_url).json(&self.approle).send();
-
-        let response = match res {
-            Err(e) => {
-                return Err(Error::from(e));
-            }
-            Ok(resp) => resp,
-        };
-
-        let status_code = response.status().as_u16();
-        if status_code != 200 && status_code != 204 {
-            return Err(Error::from(status_code));
-        }
-
-        let data = match response.json::<ApproleResponse>() {
-            Err(e) => Err(Error::from(e)),
-            Ok(json) => Ok(json),
-        };
-
-        let data = data.unwrap();
-
-        let token = data.auth.client_token;
-        let current_time = SystemTime::now()
-            .duration_since(SystemTime::UNIX_EPOCH)
-            .unwrap()
-            .as_secs();
-        let duration = data.auth.lease_duration;
-
-        // Safety:
-        // This is safe to do, because we are the only thread to access the
-        // token therefore updating it is safe
-        self.token.set_token(token);
-
-        self.token.set_renewable(data.auth.renewable);
-
-        // Update the Times afterwards to make sure that no thread could see
-        // these new valid times and try to read the token before the update
-        // is actually done, as these Times basically work as an indicator if
-        // the token can be accessed or not
-        self.token.set_start(current_time);
-        self.token.set_duration(duration);
+        let approle = self.approle.read().unwrap().clone();
+        let auth = internals::login(http, "auth/approle/login", &approle)
+            .await
+            .map_err(login_error)?;
+        self.token.set_login(auth);
 
         Ok(())
     }
@@ -164,59 +90,16 @@ impl AuthTrait for Session {
         self.token.get_duration()
     }
 
-    fn renew(&self, vault_url: &str) -> Result<(), Error> {
-        let mut renew_url = match Url::parse(vault_url) {
-            Err(e) => {
-                return Err(Error::from(e));
-            }
-            Ok(url) => url,
-        };
-        renew_url = match renew_url.join("v1/auth/token/renew-self") {
-            Err(e) => {
-                return Err(Error::from(e));
-            }
-            Ok(u) => u,
-        };
-
-        let http_client = reqwest::blocking::Client::new();
-        let res = http_client
-            .post(renew_url)
-            .header("X-Vault-Token", self.token.get_token().unwrap())
-            .send();
-
-        let response = match res {
-            Err(e) => {
-                return Err(Error::from(e));
-            }
-            Ok(resp) => resp,
-        };
-
-        let status_code = response.status().as_u16();
-        if status_code != 200 && status_code != 204 {
-            return Err(Error::from(status_code));
-        }
-
-        let data = match response.json::<RenewResponse>() {
-            Err(e) => Err(Error::from(e)),
-            Ok(json) => Ok(json),
-        };
-
-        let data = data.unwrap();
-
-        let current_time = SystemTime::now()
-            .duration_since(SystemTime::UNIX_EPOCH)
-            .unwrap()
-            .as_secs();
-        let duration = data.auth.lease_duration;
-        let renewable = data.auth.renewable;
-
-        self.token.set_renewable(renewable);
+    async fn renew(&self, http: &HttpClient) -> Result<(), Error> {
+        self.renew_with(http, None).await
+    }
 
-        // The times should again be set at the end after everything else is done already
-        self.token.set_start(current_time);
-        self.token.set_duration(duration);
+    async fn renew_with(&self, http: &HttpClient, increment: Option<u64>) -> Result<(), Error> {
+        internals::renew_token(http, &self.token, increment).await
+    }
 
-        Ok(())
+    fn get_max_ttl_remaining(&self) -> Option<u64> {
+        self.token.get_max_ttl_remaining()
     }
 }
 
@@ -227,8 +110,71 @@ impl Session {
         let approle = ApproleLogin { role_id, secret_id };
 
         Ok(Session {
-            approle,
+            approle: std::sync::RwLock::new(approle),
+            wrapped: tokio::sync::Mutex::new(None),
+            token: internals::TokenContainer::new(),
+        })
+    }
+
+    /// This function returns a new Approle-Auth-Session that only uses the
+    /// Role-ID to login, which is meant for roles that were created with
+    /// `bind_secret_id` set to false
+    pub fn new_role_id_only(role_id: String) -> Result<Session, Error> {
+        Session::new(role_id, "".to_string())
+    }
+
+    /// This function returns a new Approle-Auth-Session, for which the
+    /// Secret-ID is delivered as a response-wrapping Token.
+    ///
+    /// The Secret-ID is unwrapped on the first login, but only after making
+    /// sure that the wrapping Token was created when generating a Secret-ID
+    /// for the given role, otherwise `Error::InvalidWrapping` is returned
+    ///
+    /// # Arguments:
+    /// * `role_id`: The Role-ID of the role to login as
+    /// * `role_name`: The name of the role the Secret-ID was generated for
+    /// * `wrapping_token`: The wrapping Token that contains the Secret-ID
+    pub fn new_wrapped(
+        role_id: String,
+        role_name: String,
+        wrapping_token: String,
+    ) -> Result<Session, Error> {
+        let approle = ApproleLogin {
+            role_id,
+            secret_id: "".to_string(),
+        };
+
+        Ok(Session {
+            approle: std::sync::RwLock::new(approle),
+            wrapped: tokio::sync::Mutex::new(Some(WrappedSecretID {
+                wrapping_token,
+                role_name,
+            })),
             token: internals::TokenContainer::new(),
         })
     }
+
+    /// Unwraps the Secret-ID, if it was response-wrapped and has not been
+    /// unwrapped yet
+    async fn unwrap_secret_id(&self, http: &HttpClient) -> Result<(), Error> {
+        let mut wrapped = self.wrapped.lock().await;
+        let secret = match wrapped.as_ref() {
+            None => return Ok(()),
+            Some(w) => w,
+        };
+
+        // Check the creation path before unwrapping, so that a tampered token
+        // is never used, as unwrapping it would consume it
+        let info = internals::lookup_wrapping(http, &secret.wrapping_token).await?;
+        let expected_path = format!("auth/approle/role/{}/secret-id", secret.role_name);
+        if info.creation_path != expected_path {
+            return Err(Error::InvalidWrapping(info.creation_path));
+        }
+
+        let unwrapped: UnwrappedSecretID = internals::unwrap(http, &secret.wrapping_token).await?;
+        self.approle.write().unwrap().secret_id = unwrapped.secret_id;
+        *wrapped = None;
+
+        Ok(())
+    }
 }
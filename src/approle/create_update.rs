@@ -1,5 +1,6 @@
 use serde::Serialize;
 
+use crate::transport::Method;
 use crate::Auth;
 use crate::Client;
 use crate::Error;
@@ -8,7 +9,7 @@ use crate::Error;
 /// options that are possible to set on said Role
 ///
 /// [Vault-Documentation](https://www.vaultproject.io/api-docs/auth/approle#create-update-approle)
-#[derive(Debug, Serialize)]
+#[derive(Debug, Default, Serialize)]
 pub struct ApproleOptions {
     /// If the `secret_id` is required to be present when logging in
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -61,7 +62,6 @@ pub struct ApproleOptions {
     pub token_type: Option<String>,
 }
 
-// TODO: Add test for this function
 /// Used to create or update an Approle-Role with the given options
 ///
 /// # Arguments:
@@ -77,10 +77,7 @@ pub async fn create_update(
 ) -> Result<(), Error> {
     let path = format!("auth/approle/role/{}", name);
 
-    match client
-        .vault_request(reqwest::Method::POST, &path, Some(&opts))
-        .await
-    {
+    match client.vault_request(Method::POST, &path, Some(&opts)).await {
         Err(e) => Err(e),
         Ok(_) => Ok(()),
     }
@@ -1,5 +1,13 @@
 mod auth;
 mod create_update;
+mod role;
+mod role_id;
+mod secret_id;
+mod tidy;
 
 pub use auth::*;
 pub use create_update::*;
+pub use role::*;
+pub use role_id::*;
+pub use secret_id::*;
+pub use tidy::*;
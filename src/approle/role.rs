@@ -0,0 +1,77 @@
+use serde::Deserialize;
+
+use crate::Auth;
+use crate::Client;
+use crate::Error;
+
+/// An Approle-Role as it is returned by vault, mirrors the fields of
+/// [`ApproleOptions`](super::ApproleOptions), but all TTLs are returned
+/// in seconds
+///
+/// [Vault-Documentation](https://www.vaultproject.io/api-docs/auth/approle#read-approle)
+#[derive(Debug, Default, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct ApproleRole {
+    /// If the `secret_id` is required to be present when logging in
+    pub bind_secret_id: Option<bool>,
+    /// Specifies blocks of IP-addresses that can use this role
+    pub secret_id_bound_cidrs: Option<Vec<String>>,
+    /// The Number of times a single Secret-ID can be used for login.
+    /// 0 means unlimited
+    pub secret_id_num_uses: Option<u64>,
+    /// The TTL of a Secret-ID in seconds
+    pub secret_id_ttl: Option<u64>,
+    /// If the Secret-IDs generated for this role are cluster local
+    pub local_secret_ids: Option<bool>,
+    /// The TTL of the generated Tokens in seconds
+    pub token_ttl: Option<u64>,
+    /// The maximum TTL of generated Tokens in seconds
+    pub token_max_ttl: Option<u64>,
+    /// The Policies assigned to the generated Tokens
+    pub token_policies: Option<Vec<String>>,
+    /// Specifies blocks of IP-addresses that can authenticate using this role
+    /// and ties the tokens to these blocks as well
+    pub token_bound_cidrs: Option<Vec<String>>,
+    /// The explicit maximum TTL after which every token will expire even
+    /// if it was renewed before
+    pub token_explicit_max_ttl: Option<u64>,
+    /// If the `default` Policy is not set on generated tokens
+    pub token_no_default_policy: Option<bool>,
+    /// The maximum Number of uses per generated Token, in it's lifetime
+    pub token_num_uses: Option<u64>,
+    /// The Period, if any, of the Tokens
+    pub token_period: Option<u64>,
+    /// The Type of Token that is generated
+    pub token_type: Option<String>,
+}
+
+/// Used to read the Approle-Role with the given name
+///
+/// [Vault-Documentation](https://www.vaultproject.io/api-docs/auth/approle#read-approle)
+pub async fn read_role(client: &Client<impl Auth>, name: &str) -> Result<ApproleRole, Error> {
+    let path = format!("auth/approle/role/{}", name);
+
+    match client.read::<ApproleRole>(&path).await {
+        Err(e) => Err(e),
+        Ok(body) => Ok(body.data),
+    }
+}
+
+/// Used to list the names of all the Approle-Roles
+///
+/// [Vault-Documentation](https://www.vaultproject.io/api-docs/auth/approle#list-roles)
+pub async fn list_roles(client: &Client<impl Auth>) -> Result<Vec<String>, Error> {
+    match client.list("auth/approle/role").await {
+        Err(e) => Err(e),
+        Ok(body) => Ok(body.data.keys),
+    }
+}
+
+/// Used to delete the Approle-Role with the given name
+///
+/// [Vault-Documentation](https://www.vaultproject.io/api-docs/auth/approle#delete-approle)
+pub async fn delete_role(client: &Client<impl Auth>, name: &str) -> Result<(), Error> {
+    let path = format!("auth/approle/role/{}", name);
+
+    client.delete(&path).await
+}
@@ -0,0 +1,42 @@
+use serde::de::IgnoredAny;
+use serde::{Deserialize, Serialize};
+
+use crate::Auth;
+use crate::Client;
+use crate::Error;
+
+#[derive(Serialize, Deserialize)]
+struct RoleID {
+    role_id: String,
+}
+
+/// Used to read the Role-ID of the Approle-Role with the given name
+///
+/// [Vault-Documentation](https://www.vaultproject.io/api-docs/auth/approle#read-approle-role-id)
+pub async fn read_role_id(client: &Client<impl Auth>, name: &str) -> Result<String, Error> {
+    let path = format!("auth/approle/role/{}/role-id", name);
+
+    match client.read::<RoleID>(&path).await {
+        Err(e) => Err(e),
+        Ok(body) => Ok(body.data.role_id),
+    }
+}
+
+/// Used to set a custom Role-ID for the Approle-Role with the given name
+///
+/// [Vault-Documentation](https://www.vaultproject.io/api-docs/auth/approle#update-approle-role-id)
+pub async fn set_role_id(
+    client: &Client<impl Auth>,
+    name: &str,
+    role_id: &str,
+) -> Result<(), Error> {
+    let path = format!("auth/approle/role/{}/role-id", name);
+    let body = RoleID {
+        role_id: role_id.to_string(),
+    };
+
+    match client.write::<RoleID, IgnoredAny>(&path, &body).await {
+        Err(e) => Err(e),
+        Ok(_) => Ok(()),
+    }
+}
@@ -0,0 +1,271 @@
+use serde::{Deserialize, Serialize, Serializer};
+use std::collections::HashMap;
+
+use crate::transport::Method;
+use crate::Auth;
+use crate::Client;
+use crate::Error;
+use crate::ResponseError;
+
+/// The Options used when generating a new Secret-ID
+///
+/// [Vault-Documentation](https://www.vaultproject.io/api-docs/auth/approle#generate-new-secret-id)
+#[derive(Debug, Default)]
+pub struct SecretIDOptions {
+    /// Specifies blocks of IP-addresses that can use the Secret-ID, these
+    /// need to be a subset of the `secret_id_bound_cidrs` of the role
+    pub cidr_list: Option<Vec<String>>,
+    /// Metadata that is tied to the Secret-ID and attached to the Tokens
+    /// created using it
+    pub metadata: Option<HashMap<String, String>>,
+    /// Specifies blocks of IP-addresses that can use the Tokens created
+    /// using the Secret-ID
+    pub token_bound_cidrs: Option<Vec<String>>,
+}
+
+/// A newly generated Secret-ID
+#[derive(Debug, Deserialize)]
+pub struct SecretID {
+    /// The actual Secret-ID used to login
+    pub secret_id: String,
+    /// The accessor of the Secret-ID, used to manage it without knowing
+    /// the Secret-ID itself
+    pub secret_id_accessor: String,
+    /// The TTL of the Secret-ID in seconds
+    #[serde(default)]
+    pub secret_id_ttl: u64,
+    /// The Number of times the Secret-ID can be used, 0 means unlimited
+    #[serde(default)]
+    pub secret_id_num_uses: u64,
+}
+
+/// The Information about an existing Secret-ID
+///
+/// [Vault-Documentation](https://www.vaultproject.io/api-docs/auth/approle#read-approle-secret-id)
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct SecretIDInfo {
+    /// The blocks of IP-addresses that can use the Secret-ID
+    pub cidr_list: Option<Vec<String>>,
+    /// The time at which the Secret-ID was created
+    pub creation_time: String,
+    /// The time at which the Secret-ID expires
+    pub expiration_time: String,
+    /// The time at which the Secret-ID was last updated
+    pub last_updated_time: String,
+    /// The Metadata tied to the Secret-ID
+    pub metadata: Option<HashMap<String, String>>,
+    /// The accessor of the Secret-ID
+    pub secret_id_accessor: String,
+    /// The remaining Number of times the Secret-ID can be used
+    pub secret_id_num_uses: u64,
+    /// The TTL of the Secret-ID in seconds
+    pub secret_id_ttl: u64,
+    /// The blocks of IP-addresses that can use the Tokens created using
+    /// the Secret-ID
+    pub token_bound_cidrs: Option<Vec<String>>,
+}
+
+/// Vault expects the metadata to be a JSON-encoded string instead of an object
+fn serialize_metadata<S: Serializer>(
+    metadata: &Option<&HashMap<String, String>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match metadata {
+        None => serializer.serialize_none(),
+        Some(metadata) => match serde_json::to_string(metadata) {
+            Err(e) => Err(serde::ser::Error::custom(e)),
+            Ok(encoded) => serializer.serialize_str(&encoded),
+        },
+    }
+}
+
+#[derive(Serialize)]
+struct SecretIDRequest<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cidr_list: Option<&'a Vec<String>>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_metadata"
+    )]
+    metadata: Option<&'a HashMap<String, String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    secret_id: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    token_bound_cidrs: Option<&'a Vec<String>>,
+}
+
+impl<'a> SecretIDRequest<'a> {
+    fn new(opts: &'a SecretIDOptions, secret_id: Option<&'a str>) -> Self {
+        SecretIDRequest {
+            cidr_list: opts.cidr_list.as_ref(),
+            metadata: opts.metadata.as_ref(),
+            secret_id,
+            token_bound_cidrs: opts.token_bound_cidrs.as_ref(),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct SecretIDInfoResponse {
+    data: SecretIDInfo,
+}
+
+#[derive(Serialize)]
+struct SecretIDBody<'a> {
+    secret_id: &'a str,
+}
+
+#[derive(Serialize)]
+struct AccessorBody<'a> {
+    secret_id_accessor: &'a str,
+}
+
+async fn send_secret_id_request(
+    client: &Client<impl Auth>,
+    path: &str,
+    body: &SecretIDRequest<'_>,
+) -> Result<SecretID, Error> {
+    match client
+        .request::<_, SecretID>(Method::POST, path, Some(body))
+        .await
+    {
+        Err(e) => Err(e),
+        Ok(body) => Ok(body.data),
+    }
+}
+
+async fn send_lookup<B: Serialize>(
+    client: &Client<impl Auth>,
+    path: &str,
+    body: &B,
+) -> Result<SecretIDInfo, Error> {
+    let response = match client.vault_request(Method::POST, path, Some(body)).await {
+        Err(e) => return Err(e),
+        Ok(r) => r,
+    };
+
+    // Vault responds with an empty body if the Secret-ID does not exist
+    if response.status().as_u16() == 204 {
+        return Err(Error::NotFound(ResponseError::new(
+            404,
+            Method::POST,
+            response.path(),
+            &[],
+        )));
+    }
+
+    match response.json::<SecretIDInfoResponse>() {
+        Err(e) => Err(Error::from(e)),
+        Ok(body) => Ok(body.data),
+    }
+}
+
+/// Used to generate a new Secret-ID for the Approle-Role with the given name
+///
+/// [Vault-Documentation](https://www.vaultproject.io/api-docs/auth/approle#generate-new-secret-id)
+pub async fn generate_secret_id(
+    client: &Client<impl Auth>,
+    role: &str,
+    opts: &SecretIDOptions,
+) -> Result<SecretID, Error> {
+    let path = format!("auth/approle/role/{}/secret-id", role);
+    send_secret_id_request(client, &path, &SecretIDRequest::new(opts, None)).await
+}
+
+/// Used to assign the given custom Secret-ID to the Approle-Role with the
+/// given name
+///
+/// [Vault-Documentation](https://www.vaultproject.io/api-docs/auth/approle#create-custom-approle-secret-id)
+pub async fn custom_secret_id(
+    client: &Client<impl Auth>,
+    role: &str,
+    secret_id: &str,
+    opts: &SecretIDOptions,
+) -> Result<SecretID, Error> {
+    let path = format!("auth/approle/role/{}/custom-secret-id", role);
+    send_secret_id_request(client, &path, &SecretIDRequest::new(opts, Some(secret_id))).await
+}
+
+/// Used to load the Information about the given Secret-ID, returns
+/// `Error::NotFound` if the Secret-ID does not exist
+///
+/// [Vault-Documentation](https://www.vaultproject.io/api-docs/auth/approle#read-approle-secret-id)
+pub async fn lookup_secret_id(
+    client: &Client<impl Auth>,
+    role: &str,
+    secret_id: &str,
+) -> Result<SecretIDInfo, Error> {
+    let path = format!("auth/approle/role/{}/secret-id/lookup", role);
+    send_lookup(client, &path, &SecretIDBody { secret_id }).await
+}
+
+/// Used to load the Information about the Secret-ID with the given
+/// accessor, returns `Error::NotFound` if the Secret-ID does not exist
+///
+/// [Vault-Documentation](https://www.vaultproject.io/api-docs/auth/approle#read-approle-secret-id-accessor)
+pub async fn lookup_secret_id_accessor(
+    client: &Client<impl Auth>,
+    role: &str,
+    accessor: &str,
+) -> Result<SecretIDInfo, Error> {
+    let path = format!("auth/approle/role/{}/secret-id-accessor/lookup", role);
+    let body = AccessorBody {
+        secret_id_accessor: accessor,
+    };
+    send_lookup(client, &path, &body).await
+}
+
+/// Used to destroy the given Secret-ID
+///
+/// [Vault-Documentation](https://www.vaultproject.io/api-docs/auth/approle#destroy-approle-secret-id)
+pub async fn destroy_secret_id(
+    client: &Client<impl Auth>,
+    role: &str,
+    secret_id: &str,
+) -> Result<(), Error> {
+    let path = format!("auth/approle/role/{}/secret-id/destroy", role);
+
+    match client
+        .vault_request(Method::POST, &path, Some(&SecretIDBody { secret_id }))
+        .await
+    {
+        Err(e) => Err(e),
+        Ok(_) => Ok(()),
+    }
+}
+
+/// Used to destroy the Secret-ID with the given accessor
+///
+/// [Vault-Documentation](https://www.vaultproject.io/api-docs/auth/approle#destroy-approle-secret-id-accessor)
+pub async fn destroy_secret_id_accessor(
+    client: &Client<impl Auth>,
+    role: &str,
+    accessor: &str,
+) -> Result<(), Error> {
+    let path = format!("auth/approle/role/{}/secret-id-accessor/destroy", role);
+    let body = AccessorBody {
+        secret_id_accessor: accessor,
+    };
+
+    match client.vault_request(Method::POST, &path, Some(&body)).await {
+        Err(e) => Err(e),
+        Ok(_) => Ok(()),
+    }
+}
+
+/// Used to list the accessors of all the Secret-IDs of the Approle-Role
+/// with the given name
+///
+/// [Vault-Documentation](https://www.vaultproject.io/api-docs/auth/approle#list-secret-id-accessors)
+pub async fn list_secret_id_accessors(
+    client: &Client<impl Auth>,
+    role: &str,
+) -> Result<Vec<String>, Error> {
+    let path = format!("auth/approle/role/{}/secret-id", role);
+
+    match client.list(&path).await {
+        Err(e) => Err(e),
+        Ok(body) => Ok(body.data.keys),
+    }
+}
@@ -0,0 +1,18 @@
+use crate::transport::Method;
+use crate::Auth;
+use crate::Client;
+use crate::Error;
+
+/// Used to clean up expired Secret-IDs and their accessors, vault performs
+/// the actual cleanup in the background
+///
+/// [Vault-Documentation](https://www.vaultproject.io/api-docs/auth/approle#tidy-tokens)
+pub async fn tidy(client: &Client<impl Auth>) -> Result<(), Error> {
+    match client
+        .vault_request::<String>(Method::POST, "auth/approle/tidy/secret-id", None)
+        .await
+    {
+        Err(e) => Err(e),
+        Ok(_) => Ok(()),
+    }
+}
@@ -0,0 +1,195 @@
+use async_trait::async_trait;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::time::SystemTime;
+use url::Url;
+
+use super::signing;
+use super::CredentialsProvider;
+use crate::internals;
+use crate::Auth as AuthTrait;
+use crate::Error;
+use crate::HttpClient;
+
+const GET_CALLER_IDENTITY_BODY: &str = "Action=GetCallerIdentity&Version=2011-06-15";
+
+/// The Config for the IAM-Login using the AWS Auth-Backend
+pub struct IamConfig {
+    /// The Vault-Role to login as
+    pub role: String,
+    /// The Value for the `X-Vault-AWS-IAM-Server-ID` Header, which needs
+    /// to be set if the auth-backend in vault is configured to require it
+    ///
+    /// Default: None
+    pub server_id: Option<String>,
+    /// The Region of the STS-Endpoint that the signed request targets
+    ///
+    /// Default: `us-east-1`
+    pub sts_region: String,
+    /// The STS-Endpoint that the signed request targets, this needs to match
+    /// the endpoint configured in vault
+    ///
+    /// Default: `https://sts.amazonaws.com/`
+    pub sts_endpoint: String,
+    /// The path at which the AWS Auth-Backend is mounted
+    ///
+    /// Default: `aws`
+    pub mount: String,
+}
+
+impl Default for IamConfig {
+    fn default() -> IamConfig {
+        IamConfig {
+            role: "".to_string(),
+            server_id: None,
+            sts_region: "us-east-1".to_string(),
+            sts_endpoint: "https://sts.amazonaws.com/".to_string(),
+            mount: "aws".to_string(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct IamLogin<'a> {
+    role: &'a str,
+    iam_http_request_method: &'a str,
+    iam_request_url: String,
+    iam_request_body: String,
+    iam_request_headers: String,
+}
+
+/// The Auth session for the AWS Backend using the IAM method, used by the
+/// vault client to authenticate requests.
+///
+/// The `sts:GetCallerIdentity` request is signed locally using the credentials
+/// returned by the configured CredentialsProvider and is then sent to vault,
+/// which performs the actual request against AWS to verify the identity
+pub struct Session {
+    config: IamConfig,
+    credentials: Box<dyn CredentialsProvider + Send + Sync>,
+    token: internals::TokenContainer,
+}
+
+#[async_trait]
+impl AuthTrait for Session {
+    fn is_expired(&self) -> bool {
+        self.token.is_expired()
+    }
+
+    fn get_token(&self) -> String {
+        self.token.get_token()
+    }
+
+    async fn auth(&self, http: &HttpClient) -> Result<(), Error> {
+        let login_path = format!("auth/{}/login", self.config.mount);
+
+        let current_time = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        let body = self.login_body(current_time)?;
+
+        let auth = internals::login(http, &login_path, &body).await?;
+        self.token.set_login(auth);
+
+        Ok(())
+    }
+
+    fn is_renewable(&self) -> bool {
+        self.token.get_renewable()
+    }
+
+    fn get_total_duration(&self) -> u64 {
+        self.token.get_duration()
+    }
+
+    async fn renew(&self, http: &HttpClient) -> Result<(), Error> {
+        self.renew_with(http, None).await
+    }
+
+    async fn renew_with(&self, http: &HttpClient, increment: Option<u64>) -> Result<(), Error> {
+        internals::renew_token(http, &self.token, increment).await
+    }
+
+    fn get_max_ttl_remaining(&self) -> Option<u64> {
+        self.token.get_max_ttl_remaining()
+    }
+}
+
+impl Session {
+    /// This is used to obtain a new Auth-Session for the AWS Auth-Backend
+    /// using the IAM method
+    ///
+    /// # Arguments:
+    /// * `config`: The Configuration for the login
+    /// * `credentials`: The Provider used to load the AWS-Credentials for signing
+    pub fn new(
+        config: IamConfig,
+        credentials: impl CredentialsProvider + Send + Sync + 'static,
+    ) -> Result<Session, Error> {
+        // Make sure the STS-Endpoint is valid right away
+        Url::parse(&config.sts_endpoint)?;
+
+        Ok(Session {
+            config,
+            credentials: Box::new(credentials),
+            token: internals::TokenContainer::new(),
+        })
+    }
+
+    /// Builds and signs the `sts:GetCallerIdentity` request and encodes it
+    /// into the login body expected by vault
+    fn login_body(&self, current_time: u64) -> Result<IamLogin<'_>, Error> {
+        let credentials = self.credentials.credentials()?;
+        let sts_url = Url::parse(&self.config.sts_endpoint)?;
+
+        let host = match (sts_url.host_str(), sts_url.port()) {
+            (Some(host), Some(port)) => format!("{}:{}", host, port),
+            (Some(host), None) => host.to_string(),
+            (None, _) => return Err(Error::ParseError(url::ParseError::EmptyHost)),
+        };
+        let (_, timestamp) = signing::amz_date(current_time);
+
+        let mut headers = BTreeMap::new();
+        headers.insert("Host".to_string(), host);
+        headers.insert(
+            "Content-Type".to_string(),
+            "application/x-www-form-urlencoded; charset=utf-8".to_string(),
+        );
+        headers.insert("X-Amz-Date".to_string(), timestamp.clone());
+        if let Some(session_token) = &credentials.session_token {
+            headers.insert("X-Amz-Security-Token".to_string(), session_token.clone());
+        }
+        if let Some(server_id) = &self.config.server_id {
+            headers.insert("X-Vault-AWS-IAM-Server-ID".to_string(), server_id.clone());
+        }
+
+        let authorization = signing::sign_v4(
+            "POST",
+            &sts_url,
+            &headers,
+            GET_CALLER_IDENTITY_BODY.as_bytes(),
+            &credentials,
+            &self.config.sts_region,
+            "sts",
+            &timestamp,
+        );
+        headers.insert("Authorization".to_string(), authorization);
+
+        // Vault expects every header to be a list of values
+        let encoded_headers: BTreeMap<String, Vec<String>> =
+            headers.into_iter().map(|(k, v)| (k, vec![v])).collect();
+        let headers_json = match serde_json::to_vec(&encoded_headers) {
+            Err(e) => return Err(Error::from(e)),
+            Ok(h) => h,
+        };
+
+        Ok(IamLogin {
+            role: &self.config.role,
+            iam_http_request_method: "POST",
+            iam_request_url: base64::encode(sts_url.as_str()),
+            iam_request_body: base64::encode(GET_CALLER_IDENTITY_BODY),
+            iam_request_headers: base64::encode(headers_json),
+        })
+    }
+}
@@ -0,0 +1,63 @@
+use crate::Error;
+
+/// A single set of AWS-Credentials that is used to sign the login request
+#[derive(Debug, Clone)]
+pub struct Credentials {
+    /// The AWS Access-Key-ID
+    pub access_key_id: String,
+    /// The AWS Secret-Access-Key
+    pub secret_access_key: String,
+    /// The Session-Token, only needed when using temporary credentials,
+    /// like the ones obtained from an assumed role or an instance profile
+    pub session_token: Option<String>,
+}
+
+/// A CredentialsProvider is used by the AWS-Session to obtain the Credentials
+/// everytime it needs to sign a new login request, this allows the credentials
+/// to be rotated without having to create a new Session
+pub trait CredentialsProvider {
+    /// Returns the Credentials that should be used for the next login request
+    fn credentials(&self) -> Result<Credentials, Error>;
+}
+
+/// Static Credentials simply always return themselves
+impl CredentialsProvider for Credentials {
+    fn credentials(&self) -> Result<Credentials, Error> {
+        Ok(self.clone())
+    }
+}
+
+/// Allows any closure to be used as a CredentialsProvider
+impl<F> CredentialsProvider for F
+where
+    F: Fn() -> Result<Credentials, Error>,
+{
+    fn credentials(&self) -> Result<Credentials, Error> {
+        self()
+    }
+}
+
+/// The EnvProvider loads the Credentials from the standard AWS environment
+/// variables `AWS_ACCESS_KEY_ID`, `AWS_SECRET_ACCESS_KEY` and the optional
+/// `AWS_SESSION_TOKEN` everytime they are requested
+pub struct EnvProvider;
+
+impl CredentialsProvider for EnvProvider {
+    fn credentials(&self) -> Result<Credentials, Error> {
+        let access_key_id = match std::env::var("AWS_ACCESS_KEY_ID") {
+            Err(_) => return Err(Error::MissingCredentials("AWS_ACCESS_KEY_ID")),
+            Ok(v) => v,
+        };
+        let secret_access_key = match std::env::var("AWS_SECRET_ACCESS_KEY") {
+            Err(_) => return Err(Error::MissingCredentials("AWS_SECRET_ACCESS_KEY")),
+            Ok(v) => v,
+        };
+        let session_token = std::env::var("AWS_SESSION_TOKEN").ok();
+
+        Ok(Credentials {
+            access_key_id,
+            secret_access_key,
+            session_token,
+        })
+    }
+}
@@ -0,0 +1,7 @@
+mod auth;
+mod credentials;
+mod signing;
+
+pub use auth::*;
+pub use credentials::*;
+pub use signing::*;
@@ -0,0 +1,174 @@
+use hmac::{Hmac, Mac, NewMac};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use url::Url;
+
+use super::Credentials;
+
+type HmacSha256 = Hmac<Sha256>;
+
+fn hmac_sha256(key: &[u8], data: &[u8]) -> Vec<u8> {
+    // HMAC accepts keys of any size, so this can never fail
+    let mut mac = HmacSha256::new_varkey(key).unwrap();
+    mac.update(data);
+    mac.finalize().into_bytes().to_vec()
+}
+
+fn sha256_hex(data: &[u8]) -> String {
+    hex::encode(Sha256::digest(data))
+}
+
+/// URI-encodes the given value according to the rules defined for SigV4,
+/// which only leave the unreserved characters untouched
+fn uri_encode(value: &str, encode_slash: bool) -> String {
+    let mut result = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                result.push(byte as char)
+            }
+            b'/' if !encode_slash => result.push('/'),
+            _ => result.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    result
+}
+
+fn canonical_uri(url: &Url) -> String {
+    let path = url.path();
+    if path.is_empty() {
+        return "/".to_string();
+    }
+
+    // The Path in the Url is already percent-encoded, so it needs to be decoded
+    // first to avoid encoding it twice
+    let decoded: Vec<u8> = percent_encoding::percent_decode(path.as_bytes()).collect();
+    uri_encode(&String::from_utf8_lossy(&decoded), false)
+}
+
+fn canonical_query(url: &Url) -> String {
+    let mut pairs: Vec<(String, String)> = url
+        .query_pairs()
+        .map(|(k, v)| (uri_encode(&k, true), uri_encode(&v, true)))
+        .collect();
+    pairs.sort();
+
+    pairs
+        .iter()
+        .map(|(k, v)| format!("{}={}", k, v))
+        .collect::<Vec<String>>()
+        .join("&")
+}
+
+/// Returns the Date (`YYYYMMDD`) and the Timestamp (`YYYYMMDD'T'HHMMSS'Z'`)
+/// in the format expected by AWS for the given unix timestamp
+pub fn amz_date(unix_secs: u64) -> (String, String) {
+    let days = (unix_secs / 86400) as i64;
+    let secs_of_day = unix_secs % 86400;
+
+    // Converts the days since the epoch into a civil date, based on
+    // http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    let date = format!("{:04}{:02}{:02}", year, month, day);
+    let timestamp = format!(
+        "{}T{:02}{:02}{:02}Z",
+        date,
+        secs_of_day / 3600,
+        (secs_of_day % 3600) / 60,
+        secs_of_day % 60
+    );
+
+    (date, timestamp)
+}
+
+/// Derives the SigV4 signing key for the given secret, date, region and service
+pub fn signing_key(secret_access_key: &str, date: &str, region: &str, service: &str) -> Vec<u8> {
+    let k_date = hmac_sha256(
+        format!("AWS4{}", secret_access_key).as_bytes(),
+        date.as_bytes(),
+    );
+    let k_region = hmac_sha256(&k_date, region.as_bytes());
+    let k_service = hmac_sha256(&k_region, service.as_bytes());
+    hmac_sha256(&k_service, b"aws4_request")
+}
+
+/// Signs the given request using the AWS Signature Version 4 process and
+/// returns the value for the `Authorization` header.
+///
+/// Every given header will be signed and the headers need to already contain
+/// the `Host` and `X-Amz-Date` headers, where `X-Amz-Date` needs to match the
+/// given timestamp.
+///
+/// # Arguments:
+/// * `method`: The HTTP-Method of the request
+/// * `url`: The complete URL of the request
+/// * `headers`: All the headers that should be signed
+/// * `body`: The payload of the request
+/// * `credentials`: The credentials used to sign the request
+/// * `region`: The AWS-Region of the targeted service
+/// * `service`: The name of the targeted service, like `sts`
+/// * `timestamp`: The timestamp in the `YYYYMMDD'T'HHMMSS'Z'` format
+///
+/// [AWS-Documentation](https://docs.aws.amazon.com/general/latest/gr/sigv4_signing.html)
+#[allow(clippy::too_many_arguments)]
+pub fn sign_v4(
+    method: &str,
+    url: &Url,
+    headers: &BTreeMap<String, String>,
+    body: &[u8],
+    credentials: &Credentials,
+    region: &str,
+    service: &str,
+    timestamp: &str,
+) -> String {
+    let date = &timestamp[..8];
+
+    let canonical_headers: BTreeMap<String, String> = headers
+        .iter()
+        .map(|(k, v)| {
+            let value = v.split_whitespace().collect::<Vec<&str>>().join(" ");
+            (k.to_lowercase(), value)
+        })
+        .collect();
+    let signed_headers = canonical_headers
+        .keys()
+        .cloned()
+        .collect::<Vec<String>>()
+        .join(";");
+
+    let mut canonical_request = format!(
+        "{}\n{}\n{}\n",
+        method,
+        canonical_uri(url),
+        canonical_query(url)
+    );
+    for (key, value) in canonical_headers.iter() {
+        canonical_request.push_str(&format!("{}:{}\n", key, value));
+    }
+    canonical_request.push_str(&format!("\n{}\n{}", signed_headers, sha256_hex(body)));
+
+    let scope = format!("{}/{}/{}/aws4_request", date, region, service);
+    let string_to_sign = format!(
+        "AWS4-HMAC-SHA256\n{}\n{}\n{}",
+        timestamp,
+        scope,
+        sha256_hex(canonical_request.as_bytes())
+    );
+
+    let key = signing_key(&credentials.secret_access_key, date, region, service);
+    let signature = hex::encode(hmac_sha256(&key, string_to_sign.as_bytes()));
+
+    format!(
+        "AWS4-HMAC-SHA256 Credential={}/{}, SignedHeaders={}, Signature={}",
+        credentials.access_key_id, scope, signed_headers, signature
+    )
+}
@@ -0,0 +1,192 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::internals;
+use crate::transport::{Method, Request};
+use crate::Auth as AuthTrait;
+use crate::Error;
+use crate::HttpClient;
+
+/// The Config for the Login using the Azure Auth-Backend
+pub struct AzureConfig {
+    /// The Vault-Role to login as
+    pub role: String,
+    /// The path at which the Azure Auth-Backend is mounted
+    ///
+    /// Default: `azure`
+    pub mount: String,
+    /// The Resource for which the MSI-Token is requested, this needs to match
+    /// the resource configured in vault
+    ///
+    /// Default: `https://management.azure.com/`
+    pub resource: String,
+    /// The URL of the Azure Instance Metadata Service, which is used to load
+    /// the MSI-Token as well as the metadata of the VM
+    ///
+    /// Default: `http://169.254.169.254`
+    pub metadata_url: String,
+}
+
+impl Default for AzureConfig {
+    fn default() -> AzureConfig {
+        AzureConfig {
+            role: "".to_string(),
+            mount: "azure".to_string(),
+            resource: "https://management.azure.com/".to_string(),
+            metadata_url: "http://169.254.169.254".to_string(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct AzureLogin<'a> {
+    jwt: String,
+    resource_group_name: String,
+    role: &'a str,
+    subscription_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    vm_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    vmss_name: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct MsiToken {
+    access_token: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ComputeMetadata {
+    subscription_id: String,
+    resource_group_name: String,
+    name: String,
+    #[serde(default)]
+    vm_scale_set_name: String,
+}
+
+#[derive(Deserialize)]
+struct InstanceMetadata {
+    compute: ComputeMetadata,
+}
+
+/// The Auth session for the Azure Backend, used by the vault client
+/// to authenticate requests using the Managed Service Identity of the VM
+pub struct Session {
+    config: AzureConfig,
+    token: internals::TokenContainer,
+}
+
+#[async_trait]
+impl AuthTrait for Session {
+    fn is_expired(&self) -> bool {
+        self.token.is_expired()
+    }
+
+    fn get_token(&self) -> String {
+        self.token.get_token()
+    }
+
+    async fn auth(&self, http: &HttpClient) -> Result<(), Error> {
+        let msi_token: MsiToken = self
+            .load_metadata(
+                http,
+                "metadata/identity/oauth2/token",
+                &[
+                    ("api-version", "2018-02-01"),
+                    ("resource", &self.config.resource),
+                ],
+            )
+            .await?;
+        let metadata: InstanceMetadata = self
+            .load_metadata(http, "metadata/instance", &[("api-version", "2017-08-01")])
+            .await?;
+
+        // VMs that are part of a scale set need to login using the name of the
+        // scale set instead of their own name
+        let (vm_name, vmss_name) = if metadata.compute.vm_scale_set_name.is_empty() {
+            (Some(metadata.compute.name), None)
+        } else {
+            (None, Some(metadata.compute.vm_scale_set_name))
+        };
+
+        let body = AzureLogin {
+            jwt: msi_token.access_token,
+            resource_group_name: metadata.compute.resource_group_name,
+            role: &self.config.role,
+            subscription_id: metadata.compute.subscription_id,
+            vm_name,
+            vmss_name,
+        };
+        let path = format!("auth/{}/login", self.config.mount);
+        let auth = internals::login(http, &path, &body).await?;
+        self.token.set_login(auth);
+
+        Ok(())
+    }
+
+    fn is_renewable(&self) -> bool {
+        self.token.get_renewable()
+    }
+
+    fn get_total_duration(&self) -> u64 {
+        self.token.get_duration()
+    }
+
+    async fn renew(&self, http: &HttpClient) -> Result<(), Error> {
+        self.renew_with(http, None).await
+    }
+
+    async fn renew_with(&self, http: &HttpClient, increment: Option<u64>) -> Result<(), Error> {
+        internals::renew_token(http, &self.token, increment).await
+    }
+
+    fn get_max_ttl_remaining(&self) -> Option<u64> {
+        self.token.get_max_ttl_remaining()
+    }
+}
+
+impl Session {
+    /// This is used to obtain a new Auth-Session for the Azure Auth-Backend
+    pub fn new(config: AzureConfig) -> Result<Session, Error> {
+        // Make sure the metadata url is valid right away
+        Url::parse(&config.metadata_url)?;
+
+        Ok(Session {
+            config,
+            token: internals::TokenContainer::new(),
+        })
+    }
+
+    /// Loads the given path from the Instance Metadata Service
+    async fn load_metadata<T: serde::de::DeserializeOwned>(
+        &self,
+        http: &HttpClient,
+        path: &str,
+        query: &[(&str, &str)],
+    ) -> Result<T, Error> {
+        let mut url = match Url::parse(&self.config.metadata_url) {
+            Err(e) => return Err(Error::from(e)),
+            Ok(url) => url,
+        };
+        url = match url.join(path) {
+            Err(e) => return Err(Error::from(e)),
+            Ok(u) => u,
+        };
+        url.query_pairs_mut().extend_pairs(query);
+
+        let req = Request::new(Method::GET, url).header("Metadata", "true");
+        let response = http.send(req, None).await?;
+
+        let status_code = response.status().as_u16();
+        if status_code != 200 {
+            return Err(Error::from_response(Method::GET, response));
+        }
+
+        match response.json::<T>() {
+            Err(e) => Err(Error::from(e)),
+            Ok(json) => Ok(json),
+        }
+    }
+}
@@ -0,0 +1,138 @@
+use super::Client;
+use crate::approle::{ApproleOptions, ApproleRole, SecretID, SecretIDInfo, SecretIDOptions};
+use crate::Auth;
+use crate::Error;
+
+/// Blocking version of [`approle::create_update`](crate::approle::create_update)
+pub fn create_update(
+    client: &Client<impl Auth>,
+    name: &str,
+    opts: ApproleOptions,
+) -> Result<(), Error> {
+    client.block_on(crate::approle::create_update(client.as_async(), name, opts))
+}
+
+/// Blocking version of [`approle::read_role`](crate::approle::read_role)
+pub fn read_role(client: &Client<impl Auth>, name: &str) -> Result<ApproleRole, Error> {
+    client.block_on(crate::approle::read_role(client.as_async(), name))
+}
+
+/// Blocking version of [`approle::list_roles`](crate::approle::list_roles)
+pub fn list_roles(client: &Client<impl Auth>) -> Result<Vec<String>, Error> {
+    client.block_on(crate::approle::list_roles(client.as_async()))
+}
+
+/// Blocking version of [`approle::delete_role`](crate::approle::delete_role)
+pub fn delete_role(client: &Client<impl Auth>, name: &str) -> Result<(), Error> {
+    client.block_on(crate::approle::delete_role(client.as_async(), name))
+}
+
+/// Blocking version of [`approle::read_role_id`](crate::approle::read_role_id)
+pub fn read_role_id(client: &Client<impl Auth>, name: &str) -> Result<String, Error> {
+    client.block_on(crate::approle::read_role_id(client.as_async(), name))
+}
+
+/// Blocking version of [`approle::set_role_id`](crate::approle::set_role_id)
+pub fn set_role_id(client: &Client<impl Auth>, name: &str, role_id: &str) -> Result<(), Error> {
+    client.block_on(crate::approle::set_role_id(
+        client.as_async(),
+        name,
+        role_id,
+    ))
+}
+
+/// Blocking version of [`approle::generate_secret_id`](crate::approle::generate_secret_id)
+pub fn generate_secret_id(
+    client: &Client<impl Auth>,
+    role: &str,
+    opts: &SecretIDOptions,
+) -> Result<SecretID, Error> {
+    client.block_on(crate::approle::generate_secret_id(
+        client.as_async(),
+        role,
+        opts,
+    ))
+}
+
+/// Blocking version of [`approle::custom_secret_id`](crate::approle::custom_secret_id)
+pub fn custom_secret_id(
+    client: &Client<impl Auth>,
+    role: &str,
+    secret_id: &str,
+    opts: &SecretIDOptions,
+) -> Result<SecretID, Error> {
+    client.block_on(crate::approle::custom_secret_id(
+        client.as_async(),
+        role,
+        secret_id,
+        opts,
+    ))
+}
+
+/// Blocking version of [`approle::lookup_secret_id`](crate::approle::lookup_secret_id)
+pub fn lookup_secret_id(
+    client: &Client<impl Auth>,
+    role: &str,
+    secret_id: &str,
+) -> Result<SecretIDInfo, Error> {
+    client.block_on(crate::approle::lookup_secret_id(
+        client.as_async(),
+        role,
+        secret_id,
+    ))
+}
+
+/// Blocking version of [`approle::lookup_secret_id_accessor`](crate::approle::lookup_secret_id_accessor)
+pub fn lookup_secret_id_accessor(
+    client: &Client<impl Auth>,
+    role: &str,
+    accessor: &str,
+) -> Result<SecretIDInfo, Error> {
+    client.block_on(crate::approle::lookup_secret_id_accessor(
+        client.as_async(),
+        role,
+        accessor,
+    ))
+}
+
+/// Blocking version of [`approle::destroy_secret_id`](crate::approle::destroy_secret_id)
+pub fn destroy_secret_id(
+    client: &Client<impl Auth>,
+    role: &str,
+    secret_id: &str,
+) -> Result<(), Error> {
+    client.block_on(crate::approle::destroy_secret_id(
+        client.as_async(),
+        role,
+        secret_id,
+    ))
+}
+
+/// Blocking version of [`approle::destroy_secret_id_accessor`](crate::approle::destroy_secret_id_accessor)
+pub fn destroy_secret_id_accessor(
+    client: &Client<impl Auth>,
+    role: &str,
+    accessor: &str,
+) -> Result<(), Error> {
+    client.block_on(crate::approle::destroy_secret_id_accessor(
+        client.as_async(),
+        role,
+        accessor,
+    ))
+}
+
+/// Blocking version of [`approle::list_secret_id_accessors`](crate::approle::list_secret_id_accessors)
+pub fn list_secret_id_accessors(
+    client: &Client<impl Auth>,
+    role: &str,
+) -> Result<Vec<String>, Error> {
+    client.block_on(crate::approle::list_secret_id_accessors(
+        client.as_async(),
+        role,
+    ))
+}
+
+/// Blocking version of [`approle::tidy`](crate::approle::tidy)
+pub fn tidy(client: &Client<impl Auth>) -> Result<(), Error> {
+    client.block_on(crate::approle::tidy(client.as_async()))
+}
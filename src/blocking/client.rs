@@ -0,0 +1,198 @@
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::transport::{Method, Response};
+use crate::Auth;
+use crate::Config;
+use crate::Error;
+use crate::KeyList;
+use crate::RenewError;
+use crate::RenewalHandle;
+use crate::VaultResponse;
+
+/// The blocking Client represents a single Vault-Connection/Session, like the
+/// async [`Client`](crate::Client), but every request blocks the current thread
+/// until it is done
+///
+/// The Client starts its own runtime, which drives the connections to vault,
+/// so it must not be created or dropped from within an async context
+///
+/// Like the async Client, it can be cloned cheaply and all clones share the
+/// session, the connections to vault, the renewal task and the runtime
+pub struct Client<T>
+where
+    T: Auth,
+{
+    inner: crate::Client<T>,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+/// A blocking Client that is not generic over its auth backend, like the
+/// async [`DynClient`](crate::DynClient)
+pub type DynClient = Client<Box<dyn Auth>>;
+
+impl<T> Clone for Client<T>
+where
+    T: Auth,
+{
+    fn clone(&self) -> Self {
+        Client {
+            inner: self.inner.clone(),
+            runtime: self.runtime.clone(),
+        }
+    }
+}
+
+impl<T> Client<T>
+where
+    T: Auth,
+{
+    /// This function is used to obtain a new vault session with the given config and
+    /// auth settings
+    pub fn new(conf: Config, auth_opts: T) -> Result<Client<T>, Error> {
+        let runtime = tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(1)
+            .thread_name("hc-vault-blocking")
+            .enable_all()
+            .build()?;
+
+        let inner = runtime
+            .handle()
+            .block_on(crate::Client::new_async(conf, auth_opts))?;
+
+        Ok(Client {
+            inner,
+            runtime: Arc::new(runtime),
+        })
+    }
+
+    /// Returns a Client that makes all its requests in the given namespace,
+    /// like [`Client::with_namespace`](crate::Client::with_namespace)
+    pub fn with_namespace(&self, namespace: &str) -> Client<T> {
+        Client {
+            inner: self.inner.with_namespace(namespace),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// The namespace used for the requests of this Client, None for the
+    /// root namespace
+    pub fn namespace(&self) -> Option<&str> {
+        self.inner.namespace()
+    }
+
+    /// Returns a Client whose requests have to complete within the given
+    /// duration, like [`Client::with_timeout`](crate::Client::with_timeout)
+    pub fn with_timeout(&self, timeout: Duration) -> Client<T> {
+        Client {
+            inner: self.inner.with_timeout(timeout),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// The deadline for the requests of this Client, None if the timeout in
+    /// the config is used
+    pub fn timeout(&self) -> Option<Duration> {
+        self.inner.timeout()
+    }
+
+    /// The async Client, which is used to make all the requests, this can be
+    /// used to call async functions that have no blocking version
+    pub fn as_async(&self) -> &crate::Client<T> {
+        &self.inner
+    }
+
+    /// This function will enter an infitive Loop and blocks the current thread.
+    /// It will do everything related to renewing the token/session, like
+    /// [`Client::renew_background`](crate::Client::renew_background)
+    pub fn renew_background(&self) -> Result<(), RenewError> {
+        self.block_on(self.inner.renew_background_async())
+    }
+
+    /// Starts renewing the session in the background on the runtime of this
+    /// Client, like [`Client::renewal_task`](crate::Client::renewal_task), and
+    /// returns the handle to stop it and to watch the state of the session
+    pub fn start_renewal(&self) -> Result<RenewalHandle, RenewError>
+    where
+        T: 'static,
+    {
+        let (task, handle) = self.inner.renewal_task()?;
+        self.runtime.spawn(task);
+
+        Ok(handle)
+    }
+
+    /// Returns the handle of the renewal task of the session, if one was
+    /// started by this Client or any of its clones
+    pub fn renewal_handle(&self) -> Option<RenewalHandle> {
+        self.inner.renewal_handle()
+    }
+
+    /// A simple method to get the underlying vault session/client token
+    /// for the current vault session
+    pub fn get_token(&self) -> String {
+        self.inner.get_token()
+    }
+
+    /// This function is used to check if the current session is still valid
+    /// and if not to renew the session/obtain a new one
+    pub fn check_session(&self) -> Result<(), Error> {
+        self.block_on(self.inner.check_session())
+    }
+
+    /// Makes a request to vault using the current session, like
+    /// [`Client::vault_request`](crate::Client::vault_request)
+    pub fn vault_request<P: Serialize>(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<&P>,
+    ) -> Result<Response, Error> {
+        self.block_on(self.inner.vault_request(method, path, body))
+    }
+
+    /// Makes a request to vault and parses the body into the standard vault
+    /// envelope, like [`Client::request`](crate::Client::request)
+    pub fn request<B: Serialize, D: DeserializeOwned>(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<&B>,
+    ) -> Result<VaultResponse<D>, Error> {
+        self.block_on(self.inner.request(method, path, body))
+    }
+
+    /// Reads the data at the given path
+    pub fn read<D: DeserializeOwned>(&self, path: &str) -> Result<VaultResponse<D>, Error> {
+        self.block_on(self.inner.read(path))
+    }
+
+    /// Writes the given body to the given path, returns None if vault did not
+    /// respond with any data
+    pub fn write<B: Serialize, D: DeserializeOwned>(
+        &self,
+        path: &str,
+        body: &B,
+    ) -> Result<Option<VaultResponse<D>>, Error> {
+        self.block_on(self.inner.write(path, body))
+    }
+
+    /// Lists the keys at the given path
+    pub fn list(&self, path: &str) -> Result<VaultResponse<KeyList>, Error> {
+        self.block_on(self.inner.list(path))
+    }
+
+    /// Deletes the data at the given path
+    pub fn delete(&self, path: &str) -> Result<(), Error> {
+        self.block_on(self.inner.delete(path))
+    }
+
+    /// Runs the given Future on the runtime of this Client and blocks the
+    /// current thread until it is done
+    pub(crate) fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.handle().block_on(future)
+    }
+}
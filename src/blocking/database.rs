@@ -0,0 +1,9 @@
+use super::Client;
+use crate::database::DatabaseCreds;
+use crate::Auth;
+use crate::Error;
+
+/// Blocking version of [`database::get_credentials`](crate::database::get_credentials)
+pub fn get_credentials(client: &Client<impl Auth>, name: &str) -> Result<DatabaseCreds, Error> {
+    client.block_on(crate::database::get_credentials(client.as_async(), name))
+}
@@ -0,0 +1,111 @@
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use super::Client;
+use crate::kv2::Configuration;
+use crate::Auth;
+use crate::Error;
+
+/// Blocking version of [`kv2::configure`](crate::kv2::configure)
+pub fn configure(
+    client: &Client<impl Auth>,
+    mount: &str,
+    config: &Configuration,
+) -> Result<(), Error> {
+    client.block_on(crate::kv2::configure(client.as_async(), mount, config))
+}
+
+/// Blocking version of [`kv2::get_configuration`](crate::kv2::get_configuration)
+pub fn get_configuration(client: &Client<impl Auth>, mount: &str) -> Result<Configuration, Error> {
+    client.block_on(crate::kv2::get_configuration(client.as_async(), mount))
+}
+
+/// Blocking version of [`kv2::delete`](crate::kv2::delete)
+pub fn delete(client: &Client<impl Auth>, mount: &str, name: &str) -> Result<(), Error> {
+    client.block_on(crate::kv2::delete(client.as_async(), mount, name))
+}
+
+/// Blocking version of [`kv2::delete_metadata_all_versions`](crate::kv2::delete_metadata_all_versions)
+pub fn delete_metadata_all_versions(
+    client: &Client<impl Auth>,
+    mount: &str,
+    name: &str,
+) -> Result<(), Error> {
+    client.block_on(crate::kv2::delete_metadata_all_versions(
+        client.as_async(),
+        mount,
+        name,
+    ))
+}
+
+/// Blocking version of [`kv2::delete_versions`](crate::kv2::delete_versions)
+pub fn delete_versions(
+    client: &Client<impl Auth>,
+    mount: &str,
+    name: &str,
+    versions: Vec<u32>,
+) -> Result<(), Error> {
+    client.block_on(crate::kv2::delete_versions(
+        client.as_async(),
+        mount,
+        name,
+        versions,
+    ))
+}
+
+/// Blocking version of [`kv2::destroy_versions`](crate::kv2::destroy_versions)
+pub fn destroy_versions(
+    client: &Client<impl Auth>,
+    mount: &str,
+    name: &str,
+    versions: Vec<u32>,
+) -> Result<(), Error> {
+    client.block_on(crate::kv2::destroy_versions(
+        client.as_async(),
+        mount,
+        name,
+        versions,
+    ))
+}
+
+/// Blocking version of [`kv2::get`](crate::kv2::get)
+pub fn get<T: DeserializeOwned>(
+    client: &Client<impl Auth>,
+    mount: &str,
+    name: &str,
+    version: Option<u32>,
+) -> Result<T, Error> {
+    client.block_on(crate::kv2::get(client.as_async(), mount, name, version))
+}
+
+/// Blocking version of [`kv2::undelete_versions`](crate::kv2::undelete_versions)
+pub fn undelete_versions(
+    client: &Client<impl Auth>,
+    mount: &str,
+    name: &str,
+    versions: Vec<u32>,
+) -> Result<(), Error> {
+    client.block_on(crate::kv2::undelete_versions(
+        client.as_async(),
+        mount,
+        name,
+        versions,
+    ))
+}
+
+/// Blocking version of [`kv2::update_set`](crate::kv2::update_set)
+pub fn update_set<T: Serialize>(
+    client: &Client<impl Auth>,
+    mount: &str,
+    name: &str,
+    data: T,
+    cas: Option<u16>,
+) -> Result<(), Error> {
+    client.block_on(crate::kv2::update_set(
+        client.as_async(),
+        mount,
+        name,
+        data,
+        cas,
+    ))
+}
@@ -0,0 +1,16 @@
+/// Blocking versions of the functions in the [`approle`](crate::approle) module
+pub mod approle;
+/// Blocking versions of the functions in the [`database`](crate::database) module
+pub mod database;
+/// Blocking versions of the functions in the [`kv2`](crate::kv2) module
+pub mod kv2;
+/// Blocking versions of the functions in the [`okta`](crate::okta) module
+pub mod okta;
+/// Blocking versions of the functions in the [`sys`](crate::sys) module
+pub mod sys;
+/// Blocking versions of the functions in the [`token`](crate::token) module
+pub mod token;
+
+mod client;
+
+pub use client::*;
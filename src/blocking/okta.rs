@@ -0,0 +1,8 @@
+use crate::internals;
+use crate::Error;
+use crate::HttpClient;
+
+/// Blocking version of [`okta::verify_number`](crate::okta::verify_number)
+pub fn verify_number(http: &HttpClient, mount: &str, nonce: &str) -> Result<u64, Error> {
+    internals::block_on(crate::okta::verify_number(http, mount, nonce))?
+}
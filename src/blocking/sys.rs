@@ -0,0 +1,47 @@
+use std::collections::HashMap;
+
+use super::Client;
+use crate::sys::Namespace;
+use crate::Auth;
+use crate::Error;
+
+/// Blocking version of [`sys::create_namespace`](crate::sys::create_namespace)
+pub fn create_namespace(
+    client: &Client<impl Auth>,
+    path: &str,
+    custom_metadata: Option<&HashMap<String, String>>,
+) -> Result<Namespace, Error> {
+    client.block_on(crate::sys::create_namespace(
+        client.as_async(),
+        path,
+        custom_metadata,
+    ))
+}
+
+/// Blocking version of [`sys::update_namespace`](crate::sys::update_namespace)
+pub fn update_namespace(
+    client: &Client<impl Auth>,
+    path: &str,
+    custom_metadata: &HashMap<String, String>,
+) -> Result<Namespace, Error> {
+    client.block_on(crate::sys::update_namespace(
+        client.as_async(),
+        path,
+        custom_metadata,
+    ))
+}
+
+/// Blocking version of [`sys::read_namespace`](crate::sys::read_namespace)
+pub fn read_namespace(client: &Client<impl Auth>, path: &str) -> Result<Namespace, Error> {
+    client.block_on(crate::sys::read_namespace(client.as_async(), path))
+}
+
+/// Blocking version of [`sys::list_namespaces`](crate::sys::list_namespaces)
+pub fn list_namespaces(client: &Client<impl Auth>) -> Result<Vec<String>, Error> {
+    client.block_on(crate::sys::list_namespaces(client.as_async()))
+}
+
+/// Blocking version of [`sys::delete_namespace`](crate::sys::delete_namespace)
+pub fn delete_namespace(client: &Client<impl Auth>, path: &str) -> Result<(), Error> {
+    client.block_on(crate::sys::delete_namespace(client.as_async(), path))
+}
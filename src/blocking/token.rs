@@ -0,0 +1,100 @@
+use super::Client;
+use crate::token::{TokenAuth, TokenCreateRequest, TokenInfo, TokenRole};
+use crate::Auth;
+use crate::Error;
+
+/// Blocking version of [`token::create`](crate::token::create)
+pub fn create(
+    client: &Client<impl Auth>,
+    request: &TokenCreateRequest,
+) -> Result<TokenAuth, Error> {
+    client.block_on(crate::token::create(client.as_async(), request))
+}
+
+/// Blocking version of [`token::create_orphan`](crate::token::create_orphan)
+pub fn create_orphan(
+    client: &Client<impl Auth>,
+    request: &TokenCreateRequest,
+) -> Result<TokenAuth, Error> {
+    client.block_on(crate::token::create_orphan(client.as_async(), request))
+}
+
+/// Blocking version of [`token::create_with_role`](crate::token::create_with_role)
+pub fn create_with_role(
+    client: &Client<impl Auth>,
+    role: &str,
+    request: &TokenCreateRequest,
+) -> Result<TokenAuth, Error> {
+    client.block_on(crate::token::create_with_role(
+        client.as_async(),
+        role,
+        request,
+    ))
+}
+
+/// Blocking version of [`token::lookup`](crate::token::lookup)
+pub fn lookup(client: &Client<impl Auth>, token: &str) -> Result<TokenInfo, Error> {
+    client.block_on(crate::token::lookup(client.as_async(), token))
+}
+
+/// Blocking version of [`token::lookup_accessor`](crate::token::lookup_accessor)
+pub fn lookup_accessor(client: &Client<impl Auth>, accessor: &str) -> Result<TokenInfo, Error> {
+    client.block_on(crate::token::lookup_accessor(client.as_async(), accessor))
+}
+
+/// Blocking version of [`token::renew`](crate::token::renew)
+pub fn renew(
+    client: &Client<impl Auth>,
+    token: &str,
+    increment: Option<u64>,
+) -> Result<TokenAuth, Error> {
+    client.block_on(crate::token::renew(client.as_async(), token, increment))
+}
+
+/// Blocking version of [`token::renew_accessor`](crate::token::renew_accessor)
+pub fn renew_accessor(
+    client: &Client<impl Auth>,
+    accessor: &str,
+    increment: Option<u64>,
+) -> Result<TokenAuth, Error> {
+    client.block_on(crate::token::renew_accessor(
+        client.as_async(),
+        accessor,
+        increment,
+    ))
+}
+
+/// Blocking version of [`token::revoke`](crate::token::revoke)
+pub fn revoke(client: &Client<impl Auth>, token: &str) -> Result<(), Error> {
+    client.block_on(crate::token::revoke(client.as_async(), token))
+}
+
+/// Blocking version of [`token::revoke_orphan`](crate::token::revoke_orphan)
+pub fn revoke_orphan(client: &Client<impl Auth>, token: &str) -> Result<(), Error> {
+    client.block_on(crate::token::revoke_orphan(client.as_async(), token))
+}
+
+/// Blocking version of [`token::revoke_accessor`](crate::token::revoke_accessor)
+pub fn revoke_accessor(client: &Client<impl Auth>, accessor: &str) -> Result<(), Error> {
+    client.block_on(crate::token::revoke_accessor(client.as_async(), accessor))
+}
+
+/// Blocking version of [`token::write_role`](crate::token::write_role)
+pub fn write_role(client: &Client<impl Auth>, name: &str, role: &TokenRole) -> Result<(), Error> {
+    client.block_on(crate::token::write_role(client.as_async(), name, role))
+}
+
+/// Blocking version of [`token::read_role`](crate::token::read_role)
+pub fn read_role(client: &Client<impl Auth>, name: &str) -> Result<TokenRole, Error> {
+    client.block_on(crate::token::read_role(client.as_async(), name))
+}
+
+/// Blocking version of [`token::list_roles`](crate::token::list_roles)
+pub fn list_roles(client: &Client<impl Auth>) -> Result<Vec<String>, Error> {
+    client.block_on(crate::token::list_roles(client.as_async()))
+}
+
+/// Blocking version of [`token::delete_role`](crate::token::delete_role)
+pub fn delete_role(client: &Client<impl Auth>, name: &str) -> Result<(), Error> {
+    client.block_on(crate::token::delete_role(client.as_async(), name))
+}
@@ -0,0 +1,93 @@
+use async_trait::async_trait;
+use serde::Serialize;
+
+use crate::http::IdentityClient;
+use crate::internals;
+use crate::Auth as AuthTrait;
+use crate::ClientCert;
+use crate::Error;
+use crate::HttpClient;
+
+/// The Config for Cert login
+#[derive(Clone, Serialize)]
+pub struct CertLogin {
+    /// The name of the certificate role to authenticate against, if this is
+    /// not set, vault will try every role that matches the presented certificate
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+/// The Auth session for the TLS-Certificate backend, used by the vault client
+/// to authenticate requests by presenting a client certificate
+pub struct Session {
+    cert: CertLogin,
+    /// The client certificate, which is presented as the client identity for
+    /// every login made by this session
+    identity: IdentityClient,
+    token: internals::TokenContainer,
+}
+
+#[async_trait]
+impl AuthTrait for Session {
+    fn is_expired(&self) -> bool {
+        self.token.is_expired()
+    }
+
+    fn get_token(&self) -> String {
+        self.token.get_token()
+    }
+
+    async fn auth(&self, http: &HttpClient) -> Result<(), Error> {
+        let http = self.identity.client(http)?;
+        let auth = internals::login(&http, "auth/cert/login", &self.cert).await?;
+        self.token.set_login(auth);
+
+        Ok(())
+    }
+
+    fn is_renewable(&self) -> bool {
+        self.token.get_renewable()
+    }
+
+    fn get_total_duration(&self) -> u64 {
+        self.token.get_duration()
+    }
+
+    async fn renew(&self, http: &HttpClient) -> Result<(), Error> {
+        self.renew_with(http, None).await
+    }
+
+    async fn renew_with(&self, http: &HttpClient, increment: Option<u64>) -> Result<(), Error> {
+        internals::renew_token(http, &self.token, increment).await
+    }
+
+    fn get_max_ttl_remaining(&self) -> Option<u64> {
+        self.token.get_max_ttl_remaining()
+    }
+}
+
+impl Session {
+    /// This is used to obtain a new Auth-Session for the Cert Auth-Backend
+    ///
+    /// # Arguments:
+    /// * `name`: The optional name of the certificate role to login as
+    /// * `cert_pem`: The PEM encoded client certificate (chain)
+    /// * `key_pem`: The PEM encoded private key belonging to the certificate
+    pub fn new(name: Option<String>, cert_pem: &[u8], key_pem: &[u8]) -> Result<Session, Error> {
+        let identity = ClientCert {
+            cert_pem: cert_pem.to_vec(),
+            key_pem: key_pem.to_vec(),
+        };
+
+        // Parse the identity once here already to make sure that invalid
+        // certificates or keys are reported right away and not only on the
+        // first login attempt
+        identity.parse()?;
+
+        Ok(Session {
+            cert: CertLogin { name },
+            identity: IdentityClient::new(identity),
+            token: internals::TokenContainer::new(),
+        })
+    }
+}
@@ -1,21 +1,65 @@
+use crate::http::{normalize_namespace, NAMESPACE_HEADER};
+use crate::internals;
+use crate::renewal;
+use crate::transport::{Method, Request, Response};
 use crate::Auth;
 use crate::Config;
 use crate::Error;
+use crate::HttpClient;
+use crate::KeyList;
 use crate::RenewError;
 use crate::RenewPolicy;
+use crate::RenewalHandle;
+use crate::RenewalTask;
+use crate::VaultResponse;
 
+use serde::de::{DeserializeOwned, IgnoredAny};
 use serde::Serialize;
-use url::Url;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 /// The Client struct represents a single Vault-Connection/Session that can be used for any
 /// further requests to vault
+///
+/// The Client is a cheap handle, that can be cloned to use it from multiple tasks or
+/// threads. All clones share the session, the connections to vault and the renewal task
 pub struct Client<T>
 where
     T: Auth,
 {
+    inner: Arc<Inner<T>>,
+    namespace: Option<String>,
+    timeout: Option<Duration>,
+}
+
+/// A Client that is not generic over its auth backend, for libraries that want to
+/// accept any Client. It can be created like any other Client, using a boxed
+/// auth backend
+///
+/// # Example:
+/// `Client::new(conf, Box::new(auth) as Box<dyn Auth>)` returns a DynClient
+pub type DynClient = Client<Box<dyn Auth>>;
+
+/// The State shared by a Client, all its clones and namespaced views
+struct Inner<T> {
     config: Config,
+    http: HttpClient,
     auth: T,
-    reauth_mutex: std::sync::Mutex<()>,
+    reauth_mutex: tokio::sync::Mutex<()>,
+    renewal: std::sync::Mutex<Option<RenewalHandle>>,
+}
+
+impl<T> Clone for Client<T>
+where
+    T: Auth,
+{
+    fn clone(&self) -> Self {
+        Client {
+            inner: self.inner.clone(),
+            namespace: self.namespace.clone(),
+            timeout: self.timeout,
+        }
+    }
 }
 
 impl<T> Client<T>
@@ -24,50 +68,183 @@ where
 {
     /// This function is used to obtain a new vault session with the given config and
     /// auth settings
+    ///
+    /// This is the blocking version of `new_async`, it performs the login on its
+    /// own runtime and therefore returns `Error::InsideRuntime` when called from
+    /// within an async context
     pub fn new(conf: Config, auth_opts: T) -> Result<Client<T>, Error> {
-        match auth_opts.auth(&conf.vault_url) {
-            Err(e) => return Err(e),
-            Ok(()) => {}
-        };
+        internals::block_on(Client::new_async(conf, auth_opts))?
+    }
+
+    /// This function is used to obtain a new vault session with the given config and
+    /// auth settings, without blocking the current thread while logging in
+    pub async fn new_async(conf: Config, auth_opts: T) -> Result<Client<T>, Error> {
+        let http = HttpClient::new(&conf)?;
+        auth_opts.auth(&http).await?;
 
         let client = Client::<T> {
-            config: conf,
-            auth: auth_opts,
-            reauth_mutex: std::sync::Mutex::new(()),
+            namespace: normalize_namespace(conf.namespace.as_deref()),
+            timeout: None,
+            inner: Arc::new(Inner {
+                config: conf,
+                http,
+                auth: auth_opts,
+                reauth_mutex: tokio::sync::Mutex::new(()),
+                renewal: std::sync::Mutex::new(None),
+            }),
         };
 
         Ok(client)
     }
 
+    /// Returns a Client that makes all its requests in the given namespace,
+    /// an empty namespace selects the root namespace.
+    /// The returned Client shares the session and connections with this one,
+    /// so it is cheap to create, even for a single request
+    ///
+    /// # Example:
+    /// To read a secret from the namespace `tenant-a` simply use
+    /// `client.with_namespace("tenant-a").read::<MyData>("secret/foo")`
+    pub fn with_namespace(&self, namespace: &str) -> Client<T> {
+        Client {
+            inner: self.inner.clone(),
+            namespace: normalize_namespace(Some(namespace)),
+            timeout: self.timeout,
+        }
+    }
+
+    /// The namespace used for the requests of this Client, None for the
+    /// root namespace
+    pub fn namespace(&self) -> Option<&str> {
+        self.namespace.as_deref()
+    }
+
+    /// Returns a Client whose requests have to complete within the given
+    /// duration, instead of the timeout in the config. The deadline covers
+    /// all retries of a request, no further retry is made once it is reached.
+    /// Like `with_namespace`, the returned Client shares the session and
+    /// connections with this one
+    ///
+    /// # Example:
+    /// To give up on reading a secret after 2 seconds simply use
+    /// `client.with_timeout(Duration::from_secs(2)).read::<MyData>("secret/foo")`
+    pub fn with_timeout(&self, timeout: Duration) -> Client<T> {
+        Client {
+            inner: self.inner.clone(),
+            namespace: self.namespace.clone(),
+            timeout: Some(timeout),
+        }
+    }
+
+    /// The deadline for the requests of this Client, None if the timeout in
+    /// the config is used
+    pub fn timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+
     /// This function will enter an infitive Loop and blocks the current thread.
     /// It will do everything related to renewing the token/session. This will
     /// idealy run inside it's own thread as to not block anything else important
+    ///
+    /// This is the blocking version of `renew_background_async`, which runs
+    /// the renewals on its own runtime
     pub fn renew_background(&self) -> Result<(), RenewError> {
-        let threshold = match self.config.renew_policy {
-            RenewPolicy::Renew(t) => t,
-            _ => return Err(RenewError::NotEnabled),
+        internals::block_on(self.renew_background_async())?
+    }
+
+    /// This function will enter an infitive Loop, that does everything related
+    /// to renewing the token/session, without blocking the current thread while
+    /// waiting for the next renewal. This will idealy be spawned as its own task
+    ///
+    /// Once the token gets close to its max TTL, a new session is obtained via
+    /// the provided auth config instead. Unlike `renewal_task`, any failed renew
+    /// or login ends the loop
+    pub async fn renew_background_async(&self) -> Result<(), RenewError> {
+        let options = match self.inner.config.renew_policy.renew_options() {
+            Some(options) => options,
+            None => return Err(RenewError::NotEnabled),
         };
 
         loop {
-            if !self.auth.is_renewable() {
+            if !self.inner.auth.is_renewable() {
                 return Err(RenewError::NotRenewable);
             }
 
-            let total_duration = self.auth.get_total_duration();
-            let wait_percentage = 1.0 - threshold;
+            let total_duration = self.inner.auth.get_total_duration();
+            let wait_percentage = 1.0 - options.threshold;
 
             let wait_duration =
                 std::time::Duration::from_secs(((total_duration as f32) * wait_percentage) as u64);
 
-            std::thread::sleep(wait_duration);
+            futures_timer::Delay::new(wait_duration).await;
 
-            match self.auth.renew(&self.config.vault_url) {
-                Ok(_) => {}
-                Err(e) => {
-                    return Err(RenewError::from(e));
-                }
-            };
+            // Renewing a token close to its max TTL only returns shorter and
+            // shorter leases, so a new session is obtained instead
+            if !renewal::needs_relogin(&self.inner.auth, &options) {
+                match self
+                    .inner
+                    .auth
+                    .renew_with(&self.inner.http, options.increment)
+                    .await
+                {
+                    Ok(_) => {}
+                    Err(e) => {
+                        return Err(RenewError::from(e));
+                    }
+                };
+            }
+            if renewal::needs_relogin(&self.inner.auth, &options) {
+                self.reauth().await?;
+            }
+        }
+    }
+
+    /// Creates a task that keeps renewing the session in the background and
+    /// a handle to stop it and to watch the state of the session.
+    /// The task needs to be spawned on the executor of the application, like
+    /// `tokio::spawn(task)`, and shares the session with this Client
+    ///
+    /// Unlike `renew_background_async`, the task does not give up when a
+    /// renew fails or the session can not be renewed anymore, but falls back
+    /// to a new login. If that fails too, the Error is reported using the
+    /// handle and the login is retried using the backoff of the retry policy.
+    /// A new login is also obtained once the token gets close to its max TTL,
+    /// see [`RenewOptions`](crate::RenewOptions)
+    ///
+    /// Only a single renewal task can run for the session shared by all clones
+    /// of this Client, until it is stopped `AlreadyRunning` is returned
+    pub fn renewal_task(&self) -> Result<(RenewalTask, RenewalHandle), RenewError>
+    where
+        T: 'static,
+    {
+        let options = match self.inner.config.renew_policy.renew_options() {
+            Some(options) => options,
+            None => return Err(RenewError::NotEnabled),
+        };
+
+        let mut renewal = self.inner.renewal.lock().unwrap();
+        if let Some(ref handle) = *renewal {
+            if !handle.state().stopped {
+                return Err(RenewError::AlreadyRunning);
+            }
         }
+
+        let client = Client {
+            inner: self.inner.clone(),
+            namespace: None,
+            timeout: None,
+        };
+
+        let (task, handle) = crate::renewal::start(client, options);
+        *renewal = Some(handle.clone());
+
+        Ok((task, handle))
+    }
+
+    /// Returns the handle of the renewal task of the session, if one was
+    /// created by this Client or any of its clones
+    pub fn renewal_handle(&self) -> Option<RenewalHandle> {
+        self.inner.renewal.lock().unwrap().clone()
     }
 
     /// A simple method to get the underlying vault session/client token
@@ -75,7 +252,7 @@ where
     /// It is not recommended to use this function, but rather stick to other
     /// more integrated parts, like the vault_request function
     pub fn get_token(&self) -> String {
-        self.auth.get_token()
+        self.inner.auth.get_token()
     }
 
     /// This function is used to check if the current
@@ -83,25 +260,55 @@ where
     /// the session/obtain a new one and update
     /// all data related to it
     pub async fn check_session(&self) -> Result<(), Error> {
-        if !self.auth.is_expired() {
+        if !self.inner.auth.is_expired() {
             return Ok(());
         }
 
-        // Take mutex to ensure only one thread can try to reauth at a time
-        let _data = self.reauth_mutex.lock().unwrap();
+        // Take mutex to ensure only one task can try to reauth at a time, the other tasks
+        // wait for it without blocking the thread they are running on
+        let _data = self.inner.reauth_mutex.lock().await;
         // If the mutex is acquired, check if the session still needs to be renewed or if another
-        // thread has already done this, in which case this one should just return as its all fine
+        // task has already done this, in which case this one should just return as its all fine
         // now
-        if !self.auth.is_expired() {
+        if !self.inner.auth.is_expired() {
             return Ok(());
         }
 
-        let result = match self.config.renew_policy {
-            RenewPolicy::Reauth => self.auth.auth(&self.config.vault_url),
-            RenewPolicy::Nothing | RenewPolicy::Renew(_) => Err(Error::SessionExpired),
-        };
+        match self.inner.config.renew_policy {
+            RenewPolicy::Reauth => self.inner.auth.auth(&self.inner.http).await,
+            RenewPolicy::Nothing | RenewPolicy::Renew(_) | RenewPolicy::RenewWith(_) => {
+                Err(Error::SessionExpired)
+            }
+        }
+    }
+
+    /// Obtains a new session, unless another task is already doing so, in
+    /// which case this waits for it and uses its result
+    pub(crate) async fn reauth(&self) -> Result<(), Error> {
+        let token = self.inner.auth.get_token();
+
+        let _data = self.inner.reauth_mutex.lock().await;
+        // The session was already replaced while waiting for the mutex
+        if self.inner.auth.get_token() != token && !self.inner.auth.is_expired() {
+            return Ok(());
+        }
 
-        return result;
+        self.inner.auth.auth(&self.inner.http).await
+    }
+
+    /// The Auth-Backend of this Client
+    pub(crate) fn auth_backend(&self) -> &T {
+        &self.inner.auth
+    }
+
+    /// The HttpClient used for the requests of this Client
+    pub(crate) fn http(&self) -> &HttpClient {
+        &self.inner.http
+    }
+
+    /// The retry policy in the config of this Client
+    pub(crate) fn retry_policy(&self) -> &crate::RetryPolicy {
+        &self.inner.config.retry
     }
 
     /// This function is a general way to directly make requests to vault using
@@ -109,53 +316,126 @@ where
     /// to mounts that are not directly covered by this crate.
     pub async fn vault_request<P: Serialize>(
         &self,
-        method: reqwest::Method,
+        method: Method,
         path: &str,
         body: Option<&P>,
-    ) -> Result<reqwest::Response, Error> {
+    ) -> Result<Response, Error> {
+        let deadline = self.timeout.map(|timeout| Instant::now() + timeout);
+
         self.check_session().await?;
 
-        let mut url = match Url::parse(&self.config.vault_url) {
-            Err(e) => {
-                return Err(Error::from(e));
-            }
-            Ok(url) => url,
-        };
-        url = match url.join("v1/") {
-            Err(e) => {
-                return Err(Error::from(e));
-            }
-            Ok(u) => u,
-        };
-        url = match url.join(path) {
-            Err(e) => {
-                return Err(Error::from(e));
-            }
-            Ok(u) => u,
-        };
+        let url = self.inner.http.api_url(path)?;
 
-        let token = self.auth.get_token();
+        let token = self.inner.auth.get_token();
 
-        let http_client = reqwest::Client::new();
-        let mut req = http_client
-            .request(method, url)
+        let mut req = Request::new(method.clone(), url)
             .header("X-Vault-Token", &token)
             .header("X-Vault-Request", "true");
 
-        if body.is_some() {
-            req = req.json(body.unwrap());
+        if let Some(ref namespace) = self.namespace {
+            req = req.header(NAMESPACE_HEADER, namespace);
         }
 
-        let resp = match req.send().await {
-            Err(e) => return Err(Error::from(e)),
-            Ok(resp) => resp,
-        };
+        if let Some(body) = body {
+            req = match method == Method::PATCH {
+                true => req.merge_patch(body)?,
+                false => req.json(body)?,
+            };
+        }
+
+        let resp = self.inner.http.send(req, deadline).await?;
 
         let status_code = resp.status().as_u16();
 
         match status_code {
-            200 | 204 => Ok(resp),
-            _ => Err(Error::from(status_code)),
+            200 | 202 | 204 => Ok(resp),
+            _ => Err(Error::from_response(method, resp)),
         }
     }
+
+    /// Makes a request to vault, like `vault_request`, but parses the body
+    /// into the standard vault envelope. Any warnings in the response are
+    /// passed to the configured `warning_handler`
+    pub async fn request<B: Serialize, D: DeserializeOwned>(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<&B>,
+    ) -> Result<VaultResponse<D>, Error> {
+        let response = self.vault_request(method, path, body).await?;
+
+        self.parse_response(path, response.body())
+    }
+
+    /// Reads the data at the given path
+    ///
+    /// # Example:
+    /// The path is relative to `v1/`, so to read a kv1-secret `secret/foo`
+    /// simply use `client.read::<MyData>("secret/foo")`
+    pub async fn read<D: DeserializeOwned>(&self, path: &str) -> Result<VaultResponse<D>, Error> {
+        self.request::<(), D>(Method::GET, path, None).await
+    }
+
+    /// Writes the given body to the given path, returns None if vault did not
+    /// respond with any data
+    pub async fn write<B: Serialize, D: DeserializeOwned>(
+        &self,
+        path: &str,
+        body: &B,
+    ) -> Result<Option<VaultResponse<D>>, Error> {
+        self.request_optional(Method::POST, path, Some(body)).await
+    }
+
+    /// Lists the keys at the given path
+    pub async fn list(&self, path: &str) -> Result<VaultResponse<KeyList>, Error> {
+        let path = format!("{}?list=true", path);
+        self.read::<KeyList>(&path).await
+    }
+
+    /// Deletes the data at the given path
+    pub async fn delete(&self, path: &str) -> Result<(), Error> {
+        match self
+            .request_optional::<(), IgnoredAny>(Method::DELETE, path, None)
+            .await
+        {
+            Err(e) => Err(e),
+            Ok(_) => Ok(()),
+        }
+    }
+
+    /// Makes the request and parses the body into the standard vault envelope,
+    /// returns None if the body of the response is empty
+    async fn request_optional<B: Serialize, D: DeserializeOwned>(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<&B>,
+    ) -> Result<Option<VaultResponse<D>>, Error> {
+        let response = self.vault_request(method, path, body).await?;
+
+        if response.body().is_empty() {
+            return Ok(None);
+        }
+
+        self.parse_response(path, response.body()).map(Some)
+    }
+
+    /// Parses the body into the standard vault envelope and reports any
+    /// warnings contained in it
+    fn parse_response<D: DeserializeOwned>(
+        &self,
+        path: &str,
+        body: &[u8],
+    ) -> Result<VaultResponse<D>, Error> {
+        let vault_response = match serde_json::from_slice::<VaultResponse<D>>(body) {
+            Err(e) => return Err(Error::from(e)),
+            Ok(r) => r,
+        };
+
+        self.inner
+            .http
+            .report_warnings(path, &vault_response.warnings);
+
+        Ok(vault_response)
+    }
 }
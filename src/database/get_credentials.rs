@@ -11,15 +11,6 @@ struct DBCreds {
     password: String,
 }
 
-#[allow(dead_code)]
-#[derive(Deserialize)]
-struct DBCredsResponse {
-    lease_id: String,
-    lease_duration: u64,
-    renewable: bool,
-    data: DBCreds,
-}
-
 /// This struct holds Database Credentials returned by vault
 #[derive(Debug)]
 pub struct DatabaseCreds {
@@ -45,16 +36,8 @@ pub async fn get_credentials(
     name: &str,
 ) -> Result<DatabaseCreds, Error> {
     let path = format!("database/creds/{}", name);
-    let response = match client
-        .vault_request::<String>(reqwest::Method::GET, &path, None)
-        .await
-    {
+    let resp_body = match client.read::<DBCreds>(&path).await {
         Err(e) => return Err(e),
-        Ok(res) => res,
-    };
-
-    let resp_body = match response.json::<DBCredsResponse>().await {
-        Err(e) => return Err(Error::from(e)),
         Ok(body) => body,
     };
 
@@ -0,0 +1,163 @@
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::ClientCert;
+use crate::Config;
+use crate::Error;
+
+/// Returns the value of the given environment variable, treating empty
+/// values the same as unset ones, like the vault CLI does
+pub(crate) fn var(name: &str) -> Option<String> {
+    match std::env::var(name) {
+        Ok(value) if !value.is_empty() => Some(value),
+        _ => None,
+    }
+}
+
+/// Parses a boolean the same way the vault CLI does
+fn parse_bool(name: &str, value: &str) -> Result<bool, Error> {
+    match value {
+        "1" | "t" | "T" | "true" | "TRUE" | "True" => Ok(true),
+        "0" | "f" | "F" | "false" | "FALSE" | "False" => Ok(false),
+        _ => Err(Error::InvalidEnvVar(format!(
+            "{}: '{}' is not a valid boolean",
+            name, value
+        ))),
+    }
+}
+
+/// Parses a duration the same way the vault CLI does, either as a number of
+/// seconds or as a sequence of numbers with units, like `1m30s` or `1.5h`.
+/// The supported units are `ns`, `us`, `ms`, `s`, `m`, `h` and `d`
+fn parse_duration(name: &str, value: &str) -> Result<Duration, Error> {
+    let invalid = || Error::InvalidEnvVar(format!("{}: '{}' is not a valid duration", name, value));
+    let overflow = || Error::InvalidEnvVar(format!("{}: '{}' is too large", name, value));
+
+    if let Ok(secs) = value.parse::<u64>() {
+        return Ok(Duration::from_secs(secs));
+    }
+    if value.is_empty() {
+        return Err(invalid());
+    }
+
+    let mut total_nanos: u64 = 0;
+    let mut rest = value;
+    while !rest.is_empty() {
+        let split = rest
+            .find(|c: char| !c.is_ascii_digit() && c != '.')
+            .unwrap_or(rest.len());
+        let (number, tail) = rest.split_at(split);
+        let split = tail
+            .find(|c: char| c.is_ascii_digit() || c == '.')
+            .unwrap_or(tail.len());
+        let (unit, tail) = tail.split_at(split);
+        rest = tail;
+
+        let unit_nanos: u64 = match unit {
+            "ns" => 1,
+            "us" | "µs" => 1_000,
+            "ms" => 1_000_000,
+            "s" => 1_000_000_000,
+            "m" => 60 * 1_000_000_000,
+            "h" => 60 * 60 * 1_000_000_000,
+            "d" => 24 * 60 * 60 * 1_000_000_000,
+            _ => return Err(invalid()),
+        };
+
+        let (whole, fraction) = number.split_once('.').unwrap_or((number, ""));
+        if whole.is_empty() && fraction.is_empty() {
+            return Err(invalid());
+        }
+        let whole = match whole {
+            "" => 0,
+            _ => whole.parse::<u64>().map_err(|_| overflow())?,
+        };
+
+        // Digits beyond nanoseconds are dropped, like the vault CLI does
+        let fraction = &fraction[..fraction.len().min(18)];
+        let fraction_nanos = match fraction {
+            "" => 0,
+            _ => {
+                let digits = fraction.parse::<u64>().map_err(|_| invalid())?;
+                let scale = 10u128.pow(fraction.len() as u32);
+                (u128::from(unit_nanos) * u128::from(digits) / scale) as u64
+            }
+        };
+
+        total_nanos = whole
+            .checked_mul(unit_nanos)
+            .and_then(|nanos| nanos.checked_add(fraction_nanos))
+            .and_then(|nanos| nanos.checked_add(total_nanos))
+            .ok_or_else(overflow)?;
+    }
+
+    Ok(Duration::from_nanos(total_nanos))
+}
+
+impl Config {
+    /// Creates a new Config from the environment variables used by the vault
+    /// CLI, every setting that is not set in the environment uses its default
+    /// value
+    ///
+    /// # Variables:
+    /// * `VAULT_ADDR`: The URL of vault
+    /// * `VAULT_AGENT_ADDR`: The URL of a vault agent, which takes precedence
+    ///   over `VAULT_ADDR`
+    /// * `VAULT_NAMESPACE`: The namespace used for all requests
+    /// * `VAULT_CACERT`: A PEM encoded CA certificate file
+    /// * `VAULT_CAPATH`: A directory of PEM encoded CA certificate files
+    /// * `VAULT_CLIENT_CERT`: A PEM encoded client certificate file, requires
+    ///   `VAULT_CLIENT_KEY` to also be set
+    /// * `VAULT_CLIENT_KEY`: The PEM encoded private key file for the client
+    ///   certificate
+    /// * `VAULT_TLS_SERVER_NAME`: The name that is sent as SNI and used to
+    ///   verify the certificate of vault
+    /// * `VAULT_SKIP_VERIFY`: Disables the verification of the certificate of
+    ///   vault if set to true
+    /// * `VAULT_CLIENT_TIMEOUT`: The timeout for every request, either in
+    ///   seconds or with units, like `30s`, `1m30s` or `1.5m`
+    /// * `VAULT_MAX_RETRIES`: The maximum number of retries for a request that
+    ///   failed with a transient error, `0` disables retries
+    ///
+    /// The token to use is loaded by [`token::Session::from_env`](crate::token::Session::from_env)
+    pub fn from_env() -> Result<Config, Error> {
+        let mut config = Config::default();
+
+        if let Some(addr) = var("VAULT_AGENT_ADDR").or_else(|| var("VAULT_ADDR")) {
+            config.vault_url = addr;
+        }
+
+        config.namespace = var("VAULT_NAMESPACE");
+
+        config.tls.ca_cert_file = var("VAULT_CACERT").map(PathBuf::from);
+        config.tls.ca_cert_dir = var("VAULT_CAPATH").map(PathBuf::from);
+        config.tls.client_cert = match (var("VAULT_CLIENT_CERT"), var("VAULT_CLIENT_KEY")) {
+            (Some(cert), Some(key)) => Some(ClientCert::from_files(cert, key)?),
+            (None, None) => None,
+            _ => {
+                return Err(Error::InvalidEnvVar(
+                    "VAULT_CLIENT_CERT and VAULT_CLIENT_KEY need to be set together".to_string(),
+                ))
+            }
+        };
+        config.tls.server_name = var("VAULT_TLS_SERVER_NAME");
+        if let Some(skip_verify) = var("VAULT_SKIP_VERIFY") {
+            config.tls.insecure_skip_verify = parse_bool("VAULT_SKIP_VERIFY", &skip_verify)?;
+        }
+
+        if let Some(timeout) = var("VAULT_CLIENT_TIMEOUT") {
+            config.timeout = Some(parse_duration("VAULT_CLIENT_TIMEOUT", &timeout)?);
+        }
+
+        if let Some(max_retries) = var("VAULT_MAX_RETRIES") {
+            config.retry.max_retries = max_retries.parse::<u32>().map_err(|_| {
+                Error::InvalidEnvVar(format!(
+                    "VAULT_MAX_RETRIES: '{}' is not a valid number of retries",
+                    max_retries
+                ))
+            })?;
+        }
+
+        Ok(config)
+    }
+}
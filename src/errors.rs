@@ -1,66 +1,264 @@
+use serde::Deserialize;
 use std::fmt;
 
+use crate::transport::{Method, Response, TransportError, TransportErrorKind};
+
+/// The Details of an unsuccessful response returned by vault
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResponseError {
+    /// The HTTP-Status-Code of the response
+    pub status: u16,
+    /// The error messages returned by vault, if any
+    pub errors: Vec<String>,
+    /// The HTTP-Method of the request, like `GET`
+    pub method: String,
+    /// The path of the request, like `/v1/auth/token/lookup-self`
+    pub path: String,
+}
+
+#[derive(Deserialize)]
+struct ErrorBody {
+    #[serde(default)]
+    errors: Vec<String>,
+}
+
+impl ResponseError {
+    /// Creates a new ResponseError from the given parts of the response, the
+    /// body is parsed as vault's error format and ignored if that fails
+    pub(crate) fn new(status: u16, method: Method, path: &str, body: &[u8]) -> Self {
+        let errors = match serde_json::from_slice::<ErrorBody>(body) {
+            Err(_) => Vec::new(),
+            Ok(body) => body.errors,
+        };
+
+        ResponseError {
+            status,
+            errors,
+            method: method.to_string(),
+            path: path.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for ResponseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {} returned {}", self.method, self.path, self.status)?;
+        if !self.errors.is_empty() {
+            write!(f, ": {}", self.errors.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
 /// The Error
 #[derive(Debug)]
 pub enum Error {
     /// ParseError is returned when there was an error parsing a url
     ParseError(url::ParseError),
-    /// ReqwestError is returned when the request made to vault itself fails
-    ReqwestError(reqwest::Error),
+    /// TransportError is returned when the request made to vault itself fails,
+    /// like when no connection could be established
+    TransportError(TransportError),
+    /// Timeout is returned when vault did not respond in time, either while
+    /// connecting or while handling the request, like when the configured
+    /// timeout or the deadline of a request is exceeded
+    Timeout(TransportError),
+    /// JSONError is returned when the body of a response from vault could not be
+    /// deserialized into the expected type
+    JSONError(serde_json::Error),
+    /// JWTError is returned when a JWT that is needed to login could not be
+    /// created, like when the private key used to sign it is invalid
+    JWTError(jsonwebtoken::errors::Error),
     /// IOError is returned by operations that have to do some sort of IO, like
     /// the helper function for the kubernetes backend, which loads the JWT token
     /// from a local file
     IOError(std::io::Error),
     /// InvalidRequest is returned when the made to vault was missing data or was invalid/
     /// malformed data and therefore was rejected by vault before doing anything
-    InvalidRequest,
+    InvalidRequest(ResponseError),
     /// IsSealed is returned when the given vault instance is not available because it
     /// is currently sealed and therefore does not accept or handle any requests other
     /// than to unseal it
-    IsSealed,
+    IsSealed(ResponseError),
     /// NotFound is returned when the given vault endpoint/path was not found on the
     /// actual vault instance that you are connected to
-    NotFound,
+    NotFound(ResponseError),
     /// Unauthorized is returned when your current Session has either expired and has not
     /// been renewed or when the credentials for login are not valid and therefore rejected
     /// or when you try to access something that you dont have the permissions to do so
-    Unauthorized,
+    Unauthorized(ResponseError),
+    /// ConsistencyNotSatisfied is returned when the vault node that handled the request
+    /// has not yet caught up with the state required by the request
+    ConsistencyNotSatisfied(ResponseError),
+    /// RateLimited is returned when the request was rejected, because it exceeded
+    /// the rate limit quota configured in vault
+    RateLimited(ResponseError),
+    /// PerformanceStandby is returned when the request was sent to a performance
+    /// standby node, that can not handle it
+    PerformanceStandby(ResponseError),
+    /// Internal is returned when vault, or a proxy in front of it, failed to handle
+    /// the request because of an internal error
+    Internal(ResponseError),
+    /// UnexpectedStatus is returned when vault responded with any other status code
+    /// that does not indicate success
+    UnexpectedStatus(ResponseError),
     /// SessionExpired is returned when the session you tried to use is expired and was
     /// configured to not automatically obtain a new session, when it notices that the
     /// current one is expired
     SessionExpired,
+    /// MissingCredentials is returned when an auth backend could not load the
+    /// credentials it needs to login, the value describes what is missing
+    MissingCredentials(&'static str),
+    /// BoundCIDR is returned when an approle login was rejected, because the request
+    /// did not originate from one of the CIDR blocks the Secret-ID is bound to, either
+    /// by the `secret_id_bound_cidrs` of the role or the `cidr_list` of the Secret-ID.
+    /// Logins rejected by the `token_bound_cidrs` can not be told apart from other
+    /// denied logins and are returned as Unauthorized
+    BoundCIDR(ResponseError),
+    /// InvalidWrapping is returned when a response-wrapping token was not created
+    /// at the expected path, which indicates that it was tampered with, the value
+    /// is the actual creation path of the token
+    InvalidWrapping(String),
+    /// InvalidTLSConfig is returned when the TLS settings could not be applied,
+    /// like when a CA or client certificate could not be parsed, the value
+    /// describes the problem
+    InvalidTLSConfig(String),
+    /// InvalidEnvVar is returned when an environment variable used to create the
+    /// configuration contains an invalid value, the value describes the problem
+    InvalidEnvVar(String),
+    /// MissingTransport is returned when no Transport was configured and
+    /// none of the features that provide one is enabled
+    MissingTransport,
+    /// InsideRuntime is returned when a blocking function, like `Client::new`, is
+    /// called from within an async runtime, where the async version has to be used
+    InsideRuntime,
     /// Other simply represents all other errors that could not be grouped into on the other
     /// categories listed above
     Other,
 }
 
+impl Error {
+    /// Creates the Error for an unsuccessful response, the error messages are
+    /// loaded from the body of the response
+    pub(crate) fn from_response(method: Method, response: Response) -> Error {
+        let status = response.status().as_u16();
+
+        Error::from(ResponseError::new(
+            status,
+            method,
+            response.path(),
+            response.body(),
+        ))
+    }
+
+    /// Returns the Details of the response, if this Error was caused by an
+    /// unsuccessful response from vault
+    pub fn response(&self) -> Option<&ResponseError> {
+        match *self {
+            Error::InvalidRequest(ref r)
+            | Error::IsSealed(ref r)
+            | Error::NotFound(ref r)
+            | Error::Unauthorized(ref r)
+            | Error::ConsistencyNotSatisfied(ref r)
+            | Error::RateLimited(ref r)
+            | Error::PerformanceStandby(ref r)
+            | Error::Internal(ref r)
+            | Error::UnexpectedStatus(ref r)
+            | Error::BoundCIDR(ref r) => Some(r),
+            _ => None,
+        }
+    }
+
+    /// Returns the HTTP-Status-Code of the response, if this Error was caused
+    /// by an unsuccessful response from vault
+    pub fn status(&self) -> Option<u16> {
+        self.response().map(|r| r.status)
+    }
+}
+
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             Error::ParseError(ref cause) => write!(f, "Parse Error: {}", cause),
-            Error::ReqwestError(ref cause) => write!(f, "Reqwest Error: {}", cause),
+            Error::TransportError(ref cause) => write!(f, "Transport Error: {}", cause),
+            Error::Timeout(ref cause) => write!(f, "Request timed out: {}", cause),
+            Error::JSONError(ref cause) => write!(f, "JSON Error: {}", cause),
+            Error::JWTError(ref cause) => write!(f, "JWT Error: {}", cause),
             Error::IOError(ref cause) => write!(f, "IO Error: {}", cause),
-            Error::InvalidRequest => write!(f, "Invalid Request: Invalid or Missing data"),
-            Error::IsSealed => write!(
+            Error::InvalidRequest(ref r) => {
+                write!(f, "Invalid Request: Invalid or Missing data: {}", r)
+            }
+            Error::IsSealed(ref r) => write!(
                 f,
-                "The Vault instance is still sealed and can't be used at the moment"
+                "The Vault instance is still sealed and can't be used at the moment: {}",
+                r
             ),
-            Error::NotFound => write!(f, "Not Found"),
-            Error::Unauthorized => write!(f, "Unauthorized"),
+            Error::NotFound(ref r) => write!(f, "Not Found: {}", r),
+            Error::Unauthorized(ref r) => write!(f, "Unauthorized: {}", r),
+            Error::ConsistencyNotSatisfied(ref r) => {
+                write!(f, "Consistency not satisfied: {}", r)
+            }
+            Error::RateLimited(ref r) => write!(f, "Rate limited: {}", r),
+            Error::PerformanceStandby(ref r) => {
+                write!(f, "Request not supported by performance standby: {}", r)
+            }
+            Error::Internal(ref r) => write!(f, "Internal Server Error: {}", r),
+            Error::UnexpectedStatus(ref r) => write!(f, "Unexpected Status: {}", r),
             Error::SessionExpired => write!(f, "Session has expired, no auto login"),
+            Error::MissingCredentials(ref what) => write!(f, "Missing Credentials: {}", what),
+            Error::BoundCIDR(ref r) => write!(f, "Login rejected by CIDR restrictions: {}", r),
+            Error::InvalidWrapping(ref path) => {
+                write!(f, "Wrapping Token was created at unexpected path: {}", path)
+            }
+            Error::InvalidTLSConfig(ref message) => write!(f, "Invalid TLS Config: {}", message),
+            Error::InvalidEnvVar(ref message) => {
+                write!(f, "Invalid Environment Variable: {}", message)
+            }
+            Error::MissingTransport => write!(f, "No Transport is configured or enabled"),
+            Error::InsideRuntime => {
+                write!(f, "Blocking function called from within an async runtime")
+            }
             Error::Other => write!(f, "Unknown error"),
         }
     }
 }
 
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match *self {
+            Error::ParseError(ref cause) => Some(cause),
+            Error::TransportError(ref cause) => Some(cause),
+            Error::Timeout(ref cause) => Some(cause),
+            Error::JSONError(ref cause) => Some(cause),
+            Error::JWTError(ref cause) => Some(cause),
+            Error::IOError(ref cause) => Some(cause),
+            _ => None,
+        }
+    }
+}
+
 impl From<url::ParseError> for Error {
     fn from(cause: url::ParseError) -> Error {
         Error::ParseError(cause)
     }
 }
-impl From<reqwest::Error> for Error {
-    fn from(cause: reqwest::Error) -> Error {
-        Error::ReqwestError(cause)
+/// Timeouts are mapped to their own Error, to tell them apart from other
+/// failed requests
+impl From<TransportError> for Error {
+    fn from(cause: TransportError) -> Error {
+        match cause.kind() {
+            TransportErrorKind::Timeout => Error::Timeout(cause),
+            _ => Error::TransportError(cause),
+        }
+    }
+}
+impl From<serde_json::Error> for Error {
+    fn from(cause: serde_json::Error) -> Error {
+        Error::JSONError(cause)
+    }
+}
+impl From<jsonwebtoken::errors::Error> for Error {
+    fn from(cause: jsonwebtoken::errors::Error) -> Error {
+        Error::JWTError(cause)
     }
 }
 impl From<std::io::Error> for Error {
@@ -68,21 +266,25 @@ impl From<std::io::Error> for Error {
         Error::IOError(cause)
     }
 }
-/// This is only meant for status codes and assumes that the
-/// given u16 is a status-code from an http-request
-impl From<u16> for Error {
-    fn from(cause: u16) -> Error {
-        match cause {
-            400 => Error::InvalidRequest,
-            403 => Error::Unauthorized,
-            404 => Error::NotFound,
-            503 => Error::IsSealed,
-            _ => Error::Other,
+/// Maps the response to the Error matching its status code
+impl From<ResponseError> for Error {
+    fn from(cause: ResponseError) -> Error {
+        match cause.status {
+            400 => Error::InvalidRequest(cause),
+            403 => Error::Unauthorized(cause),
+            404 => Error::NotFound(cause),
+            412 => Error::ConsistencyNotSatisfied(cause),
+            429 => Error::RateLimited(cause),
+            473 => Error::PerformanceStandby(cause),
+            500 | 502 => Error::Internal(cause),
+            503 => Error::IsSealed(cause),
+            _ => Error::UnexpectedStatus(cause),
         }
     }
 }
 
 /// The possible errors returned by the Renew part of the Client
+#[derive(Debug)]
 pub enum RenewError {
     /// Possible Errors returned by the Auth backend when you try to renew the
     /// current token/session
@@ -92,6 +294,9 @@ pub enum RenewError {
     NotEnabled,
     /// Returned when the current session can actually not be renewed
     NotRenewable,
+    /// Returned when a renewal task is already running for the session, which
+    /// is shared by all clones of a Client
+    AlreadyRunning,
 }
 
 impl fmt::Display for RenewError {
@@ -102,6 +307,18 @@ impl fmt::Display for RenewError {
             }
             RenewError::NotEnabled => write!(f, "The Renew Policy is not enabled"),
             RenewError::NotRenewable => write!(f, "The current session can not be renewed"),
+            RenewError::AlreadyRunning => {
+                write!(f, "A renewal task is already running for the session")
+            }
+        }
+    }
+}
+
+impl std::error::Error for RenewError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match *self {
+            RenewError::AuthError(ref cause) => Some(cause),
+            _ => None,
         }
     }
 }
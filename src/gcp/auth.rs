@@ -0,0 +1,189 @@
+use async_trait::async_trait;
+use serde::Serialize;
+use std::time::SystemTime;
+use url::Url;
+
+use super::ServiceAccountKey;
+use crate::internals;
+use crate::transport::{Method, Request};
+use crate::Auth as AuthTrait;
+use crate::Error;
+use crate::HttpClient;
+
+/// The Method used to obtain the JWT that is used to login
+pub enum GcpMethod {
+    /// Signs a JWT locally using the given Service-Account key
+    Iam(ServiceAccountKey),
+    /// Loads an Identity-Token for the default Service-Account of the instance
+    /// from the GCE metadata server
+    Gce,
+}
+
+/// The Config for the Login using the GCP Auth-Backend
+pub struct GcpConfig {
+    /// The Vault-Role to login as
+    pub role: String,
+    /// The path at which the GCP Auth-Backend is mounted
+    ///
+    /// Default: `gcp`
+    pub mount: String,
+    /// The URL of the GCE metadata server, only used by the gce method
+    ///
+    /// Default: `http://metadata.google.internal`
+    pub metadata_url: String,
+    /// The number of seconds for which the self-signed JWT is valid, vault
+    /// rejects JWTs that are valid for more than 15 minutes by default.
+    /// Only used by the iam method
+    ///
+    /// Default: 600
+    pub jwt_ttl: u64,
+}
+
+impl Default for GcpConfig {
+    fn default() -> GcpConfig {
+        GcpConfig {
+            role: "".to_string(),
+            mount: "gcp".to_string(),
+            metadata_url: "http://metadata.google.internal".to_string(),
+            jwt_ttl: 600,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct GcpLogin<'a> {
+    jwt: String,
+    role: &'a str,
+}
+
+#[derive(Serialize)]
+struct IamClaims<'a> {
+    sub: &'a str,
+    aud: String,
+    exp: u64,
+}
+
+/// The Auth session for the GCP Backend, used by the vault client
+/// to authenticate requests
+pub struct Session {
+    config: GcpConfig,
+    method: GcpMethod,
+    token: internals::TokenContainer,
+}
+
+#[async_trait]
+impl AuthTrait for Session {
+    fn is_expired(&self) -> bool {
+        self.token.is_expired()
+    }
+
+    fn get_token(&self) -> String {
+        self.token.get_token()
+    }
+
+    async fn auth(&self, http: &HttpClient) -> Result<(), Error> {
+        let jwt = match self.method {
+            GcpMethod::Iam(ref key) => self.iam_jwt(key)?,
+            GcpMethod::Gce => self.gce_jwt(http).await?,
+        };
+
+        let body = GcpLogin {
+            jwt,
+            role: &self.config.role,
+        };
+        let path = format!("auth/{}/login", self.config.mount);
+        let auth = internals::login(http, &path, &body).await?;
+        self.token.set_login(auth);
+
+        Ok(())
+    }
+
+    fn is_renewable(&self) -> bool {
+        self.token.get_renewable()
+    }
+
+    fn get_total_duration(&self) -> u64 {
+        self.token.get_duration()
+    }
+
+    async fn renew(&self, http: &HttpClient) -> Result<(), Error> {
+        self.renew_with(http, None).await
+    }
+
+    async fn renew_with(&self, http: &HttpClient, increment: Option<u64>) -> Result<(), Error> {
+        internals::renew_token(http, &self.token, increment).await
+    }
+
+    fn get_max_ttl_remaining(&self) -> Option<u64> {
+        self.token.get_max_ttl_remaining()
+    }
+}
+
+impl Session {
+    /// This is used to obtain a new Auth-Session for the GCP Auth-Backend
+    ///
+    /// # Arguments:
+    /// * `config`: The Configuration for the login
+    /// * `method`: The Method used to obtain the JWT for the login
+    pub fn new(config: GcpConfig, method: GcpMethod) -> Result<Session, Error> {
+        if let GcpMethod::Iam(ref key) = method {
+            // Make sure the key is valid right away
+            jsonwebtoken::EncodingKey::from_rsa_pem(key.private_key.as_bytes())?;
+        }
+
+        Ok(Session {
+            config,
+            method,
+            token: internals::TokenContainer::new(),
+        })
+    }
+
+    /// Signs a new JWT, for the configured role, using the Service-Account key
+    fn iam_jwt(&self, key: &ServiceAccountKey) -> Result<String, Error> {
+        let current_time = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+
+        let claims = IamClaims {
+            sub: &key.client_email,
+            aud: format!("vault/{}", self.config.role),
+            exp: current_time + self.config.jwt_ttl,
+        };
+
+        let mut header = jsonwebtoken::Header::new(jsonwebtoken::Algorithm::RS256);
+        header.kid = Some(key.private_key_id.clone());
+
+        let encoding_key = jsonwebtoken::EncodingKey::from_rsa_pem(key.private_key.as_bytes())?;
+        match jsonwebtoken::encode(&header, &claims, &encoding_key) {
+            Err(e) => Err(Error::from(e)),
+            Ok(jwt) => Ok(jwt),
+        }
+    }
+
+    /// Loads a new Identity-Token, for the configured role, from the
+    /// GCE metadata server
+    async fn gce_jwt(&self, http: &HttpClient) -> Result<String, Error> {
+        let mut url = match Url::parse(&self.config.metadata_url) {
+            Err(e) => return Err(Error::from(e)),
+            Ok(url) => url,
+        };
+        url = match url.join("computeMetadata/v1/instance/service-accounts/default/identity") {
+            Err(e) => return Err(Error::from(e)),
+            Ok(u) => u,
+        };
+        url.query_pairs_mut()
+            .append_pair("audience", &format!("vault/{}", self.config.role))
+            .append_pair("format", "full");
+
+        let req = Request::new(Method::GET, url).header("Metadata-Flavor", "Google");
+        let response = http.send(req, None).await?;
+
+        let status_code = response.status().as_u16();
+        if status_code != 200 {
+            return Err(Error::from_response(Method::GET, response));
+        }
+
+        Ok(response.text())
+    }
+}
@@ -0,0 +1,5 @@
+mod auth;
+mod service_account;
+
+pub use auth::*;
+pub use service_account::*;
@@ -0,0 +1,31 @@
+use serde::Deserialize;
+
+use crate::Error;
+
+/// The parts of a GCP Service-Account key, as found in the JSON-Key files
+/// generated by GCP, that are needed to sign a JWT for the iam method
+#[derive(Debug, Clone, Deserialize)]
+pub struct ServiceAccountKey {
+    /// The E-Mail of the Service-Account
+    pub client_email: String,
+    /// The ID of the private Key
+    pub private_key_id: String,
+    /// The PEM encoded private Key
+    pub private_key: String,
+}
+
+impl ServiceAccountKey {
+    /// Parses the given Content of a JSON-Key file
+    pub fn from_json(content: &str) -> Result<ServiceAccountKey, Error> {
+        match serde_json::from_str(content) {
+            Err(_) => Err(Error::MissingCredentials("invalid service account key")),
+            Ok(k) => Ok(k),
+        }
+    }
+
+    /// Loads and parses the JSON-Key file at the given path
+    pub fn from_file(path: &str) -> Result<ServiceAccountKey, Error> {
+        let content = std::fs::read_to_string(path)?;
+        ServiceAccountKey::from_json(&content)
+    }
+}
@@ -0,0 +1,90 @@
+use async_trait::async_trait;
+use serde::Serialize;
+
+use crate::internals;
+use crate::Auth as AuthTrait;
+use crate::Error;
+use crate::HttpClient;
+
+/// The Config for the Login using the GitHub Auth-Backend
+pub struct GithubConfig {
+    /// The GitHub personal access token to login with
+    pub token: String,
+    /// The path at which the GitHub Auth-Backend is mounted
+    ///
+    /// Default: `github`
+    pub mount: String,
+}
+
+impl Default for GithubConfig {
+    fn default() -> GithubConfig {
+        GithubConfig {
+            token: "".to_string(),
+            mount: "github".to_string(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct GithubLogin<'a> {
+    token: &'a str,
+}
+
+/// The Auth session for the GitHub Backend, used by the vault client
+/// to authenticate requests using a personal access token
+pub struct Session {
+    config: GithubConfig,
+    token: internals::TokenContainer,
+}
+
+#[async_trait]
+impl AuthTrait for Session {
+    fn is_expired(&self) -> bool {
+        self.token.is_expired()
+    }
+
+    fn get_token(&self) -> String {
+        self.token.get_token()
+    }
+
+    async fn auth(&self, http: &HttpClient) -> Result<(), Error> {
+        let body = GithubLogin {
+            token: &self.config.token,
+        };
+        let path = format!("auth/{}/login", self.config.mount);
+        let auth = internals::login(http, &path, &body).await?;
+        self.token.set_login(auth);
+
+        Ok(())
+    }
+
+    fn is_renewable(&self) -> bool {
+        self.token.get_renewable()
+    }
+
+    fn get_total_duration(&self) -> u64 {
+        self.token.get_duration()
+    }
+
+    async fn renew(&self, http: &HttpClient) -> Result<(), Error> {
+        self.renew_with(http, None).await
+    }
+
+    async fn renew_with(&self, http: &HttpClient, increment: Option<u64>) -> Result<(), Error> {
+        internals::renew_token(http, &self.token, increment).await
+    }
+
+    fn get_max_ttl_remaining(&self) -> Option<u64> {
+        self.token.get_max_ttl_remaining()
+    }
+}
+
+impl Session {
+    /// This is used to obtain a new Auth-Session for the GitHub Auth-Backend
+    pub fn new(config: GithubConfig) -> Result<Session, Error> {
+        Ok(Session {
+            config,
+            token: internals::TokenContainer::new(),
+        })
+    }
+}
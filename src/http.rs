@@ -0,0 +1,214 @@
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+use url::Url;
+
+use crate::transport::{self, Method, Request, Response, Transport};
+use crate::ClientCert;
+use crate::Config;
+use crate::Error;
+use crate::RetryPolicy;
+use crate::WarningHandler;
+
+/// The Header used to select the namespace of a request
+pub(crate) const NAMESPACE_HEADER: &str = "X-Vault-Namespace";
+
+/// Returns the namespace to send, the root namespace is represented by None
+pub(crate) fn normalize_namespace(namespace: Option<&str>) -> Option<String> {
+    match namespace {
+        Some(namespace) if !namespace.is_empty() => Some(namespace.to_string()),
+        _ => None,
+    }
+}
+
+/// Creates the clients that present a client certificate instead of the
+/// configured one, the Transport for the certificate is only created once and
+/// reused for all later logins, as long as the same HttpClient is used
+pub(crate) struct IdentityClient {
+    identity: ClientCert,
+    cached: Mutex<Option<CachedIdentity>>,
+}
+
+/// The Transport that presents the client certificate
+struct CachedIdentity {
+    /// The Transport the client certificate was added to
+    base: Arc<dyn Transport>,
+    transport: Arc<dyn Transport>,
+}
+
+impl IdentityClient {
+    pub(crate) fn new(identity: ClientCert) -> IdentityClient {
+        IdentityClient {
+            identity,
+            cached: Mutex::new(None),
+        }
+    }
+
+    /// Returns a client with the same settings as the given one, that presents
+    /// the client certificate
+    pub(crate) fn client(&self, http: &HttpClient) -> Result<HttpClient, Error> {
+        let mut cached = self.cached.lock().unwrap();
+        let transport = match *cached {
+            Some(ref c) if same_transport(&c.base, &http.transport) => c.transport.clone(),
+            _ => {
+                let transport = http.transport.with_identity(&self.identity)?;
+                *cached = Some(CachedIdentity {
+                    base: http.transport.clone(),
+                    transport: transport.clone(),
+                });
+                transport
+            }
+        };
+
+        Ok(HttpClient {
+            transport,
+            ..http.clone()
+        })
+    }
+}
+
+/// Checks if both point to the same Transport, only the data pointers are
+/// compared, as the vtables of the same type are not guaranteed to be unique
+fn same_transport(a: &Arc<dyn Transport>, b: &Arc<dyn Transport>) -> bool {
+    Arc::as_ptr(a) as *const () == Arc::as_ptr(b) as *const ()
+}
+
+/// The long-lived HTTP-Client used to talk to vault, this is shared by all
+/// requests made by a vault client and the login and renew operations of its
+/// Auth-Backend, so that connections can be reused
+#[derive(Clone)]
+pub struct HttpClient {
+    vault_url: String,
+    namespace: Option<String>,
+    retry: RetryPolicy,
+    warning_handler: Option<WarningHandler>,
+    transport: Arc<dyn Transport>,
+}
+
+impl HttpClient {
+    /// Creates the HTTP-Client using the transport of the given config, or
+    /// the transport of the enabled feature using the connection and TLS
+    /// settings of the config
+    pub fn new(config: &Config) -> Result<HttpClient, Error> {
+        // Make sure the url is valid right away
+        Url::parse(&config.vault_url)?;
+
+        let transport = match config.transport {
+            Some(ref transport) => transport.clone(),
+            None => transport::default_transport(config)?,
+        };
+
+        Ok(HttpClient {
+            vault_url: config.vault_url.clone(),
+            namespace: normalize_namespace(config.namespace.as_deref()),
+            retry: config.retry.clone(),
+            warning_handler: config.warning_handler.clone(),
+            transport,
+        })
+    }
+
+    /// Creates the HTTP-Client for the given vault url, using the default
+    /// values for all other settings
+    pub fn from_url(vault_url: &str) -> Result<HttpClient, Error> {
+        HttpClient::new(&Config {
+            vault_url: vault_url.to_string(),
+            ..Default::default()
+        })
+    }
+
+    /// The URL of the vault instance
+    pub fn vault_url(&self) -> &str {
+        &self.vault_url
+    }
+
+    /// The namespace used for the requests of the Auth-Backends, None for the
+    /// root namespace
+    pub fn namespace(&self) -> Option<&str> {
+        self.namespace.as_deref()
+    }
+
+    /// Returns the full URL for the given API path, which is relative to `v1/`
+    pub(crate) fn api_url(&self, path: &str) -> Result<Url, Error> {
+        let mut url = match Url::parse(&self.vault_url) {
+            Err(e) => return Err(Error::from(e)),
+            Ok(url) => url,
+        };
+        url = match url.join("v1/") {
+            Err(e) => return Err(Error::from(e)),
+            Ok(u) => u,
+        };
+        match url.join(path) {
+            Err(e) => Err(Error::from(e)),
+            Ok(u) => Ok(u),
+        }
+    }
+
+    /// Creates a request to the given API path, which is relative to `v1/`,
+    /// using the configured namespace
+    pub(crate) fn request(&self, method: Method, path: &str) -> Result<Request, Error> {
+        let mut req = Request::new(method, self.api_url(path)?);
+        if let Some(ref namespace) = self.namespace {
+            req = req.header(NAMESPACE_HEADER, namespace);
+        }
+
+        Ok(req)
+    }
+
+    /// Passes the given warnings of the response for the given path to the
+    /// configured `warning_handler`, if any
+    pub(crate) fn report_warnings(&self, path: &str, warnings: &[String]) {
+        if warnings.is_empty() {
+            return;
+        }
+
+        if let Some(ref handler) = self.warning_handler {
+            handler(path, warnings);
+        }
+    }
+
+    /// Sends the given request, retrying it according to the configured
+    /// retry policy. The last response is returned, even if its status
+    /// indicates an error
+    ///
+    /// If a deadline is given, every attempt is limited to the time that is
+    /// left until then and no further retry is made once the backoff would
+    /// exceed it
+    pub(crate) async fn send(
+        &self,
+        req: Request,
+        deadline: Option<Instant>,
+    ) -> Result<Response, Error> {
+        let path = req.url.path().to_string();
+
+        let mut retry = 0;
+        loop {
+            let mut attempt = req.clone();
+            if let Some(deadline) = deadline {
+                attempt.timeout = Some(deadline.saturating_duration_since(Instant::now()));
+            }
+
+            let result = self
+                .transport
+                .send(attempt)
+                .await
+                .map(|resp| resp.with_path(&path));
+            if retry >= self.retry.max_retries {
+                return result.map_err(Error::from);
+            }
+            match result {
+                Ok(resp) if !self.retry.retry_status(resp.status().as_u16()) => return Ok(resp),
+                Err(e) if !self.retry.retry_error(&e) => return Err(Error::from(e)),
+                _ => {}
+            };
+
+            let backoff = self.retry.backoff(retry);
+            if let Some(deadline) = deadline {
+                if Instant::now() + backoff >= deadline {
+                    return result.map_err(Error::from);
+                }
+            }
+
+            futures_timer::Delay::new(backoff).await;
+            retry += 1;
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use crate::transport::Method;
+use crate::AuthResponse;
+use crate::Error;
+use crate::HttpClient;
+use serde::{Deserialize, Serialize};
+
+/// The Auth-Information returned by vault for a successful login or renew,
+/// only contains the fields shared by all auth backends
+#[derive(Deserialize)]
+pub struct AuthInfo {
+    /// The Token for the new session
+    pub client_token: String,
+    /// The duration for which this session is valid in seconds
+    pub lease_duration: u64,
+    /// Whether or not the session is renewable
+    pub renewable: bool,
+}
+
+async fn send_auth_request<B: Serialize>(
+    http: &HttpClient,
+    path: &str,
+    token: Option<&str>,
+    body: Option<&B>,
+) -> Result<AuthInfo, Error> {
+    let mut req = http.request(Method::POST, path)?;
+    if let Some(token) = token {
+        req = req.header("X-Vault-Token", token);
+    }
+    if let Some(body) = body {
+        req = req.json(body)?;
+    }
+
+    let response = http.send(req, None).await?;
+
+    let status_code = response.status().as_u16();
+    if status_code != 200 && status_code != 204 {
+        return Err(Error::from_response(Method::POST, response));
+    }
+
+    let json = match response.json::<AuthResponse<AuthInfo>>() {
+        Err(e) => return Err(Error::from(e)),
+        Ok(json) => json,
+    };
+
+    http.report_warnings(path, &json.warnings);
+
+    Ok(json.auth)
+}
+
+/// Performs a login request against the given path, relative to `v1/`,
+/// using the given body
+pub async fn login<B: Serialize>(
+    http: &HttpClient,
+    path: &str,
+    body: &B,
+) -> Result<AuthInfo, Error> {
+    send_auth_request(http, path, None, Some(body)).await
+}
+
+#[derive(Serialize)]
+struct RenewRequest {
+    increment: u64,
+}
+
+/// Renews the given token using the `auth/token/renew-self` endpoint,
+/// optionally requesting the given increment in seconds
+pub async fn renew_self(
+    http: &HttpClient,
+    token: &str,
+    increment: Option<u64>,
+) -> Result<AuthInfo, Error> {
+    let body = increment.map(|increment| RenewRequest { increment });
+    send_auth_request(http, "auth/token/renew-self", Some(token), body.as_ref()).await
+}
+
+/// Renews the Token in the given container using `renew_self` and updates
+/// the container with the new lease. Without an explicit increment, the
+/// Token is expected to be renewed for at least its current duration
+pub async fn renew_token(
+    http: &HttpClient,
+    token: &super::TokenContainer,
+    increment: Option<u64>,
+) -> Result<(), Error> {
+    let requested = increment.unwrap_or_else(|| token.get_duration());
+    let auth = renew_self(http, &token.get_token(), increment).await?;
+    token.set_renew(auth, Some(requested));
+
+    Ok(())
+}
@@ -1,3 +1,9 @@
+mod login;
+mod runtime;
 mod token_container;
+mod wrapping;
 
+pub use login::*;
+pub use runtime::block_on;
 pub use token_container::TokenContainer;
+pub use wrapping::*;
@@ -0,0 +1,21 @@
+use std::future::Future;
+
+use crate::Error;
+
+/// Runs the given Future to completion on a new single-threaded runtime,
+/// which is used by the blocking versions of the async operations
+///
+/// A runtime can not be started from inside of another one, so this returns
+/// `Error::InsideRuntime` when called from within an async context, which
+/// includes the blocking threads of a tokio runtime
+pub fn block_on<F: Future>(future: F) -> Result<F::Output, Error> {
+    if tokio::runtime::Handle::try_current().is_ok() {
+        return Err(Error::InsideRuntime);
+    }
+
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()?;
+
+    Ok(runtime.block_on(future))
+}
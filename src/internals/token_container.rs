@@ -1,112 +1,128 @@
+use arc_swap::ArcSwap;
+use std::sync::Arc;
+
+/// A consistent Snapshot of the Session, the Token and its times are always
+/// replaced together, so they can never be read from different Sessions
+#[derive(Default)]
+struct TokenState {
+    token: String,
+    start: u64,
+    duration: u64,
+    renewable: bool,
+    /// The time at which the Token reaches its max TTL, if known
+    max_expire: Option<u64>,
+}
+
+fn current_time() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::SystemTime::UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+}
+
 /// The internal Container for a Token, which can be read and updated
 /// from multiple threads at a time
 ///
-/// Note: Certain access still need to be synchronized, like when updating
-/// the Token, you should not read from it at the same time as there is a
-/// very small window in which you could have a data race and therefor read
-/// incorrect memory or an incorrect pointer
+/// Reads are lock-free and never block an update. An update swaps in a new
+/// Snapshot, while the old one is only dropped once the last reader is done
+/// with it, so no external synchronization is needed
 pub struct TokenContainer {
-    token: std::sync::atomic::AtomicPtr<String>,
-    start: std::sync::atomic::AtomicU64,
-    duration: std::sync::atomic::AtomicU64,
-    renewable: std::sync::atomic::AtomicBool,
+    state: ArcSwap<TokenState>,
 }
 
 impl TokenContainer {
     /// Used to obtain a new TokenContainer with empty/null values
     pub fn new() -> TokenContainer {
-        let boxed_empty = Box::new(String::from(""));
-        let empty_ptr = Box::into_raw(boxed_empty);
-
         TokenContainer {
-            token: std::sync::atomic::AtomicPtr::new(empty_ptr),
-            start: std::sync::atomic::AtomicU64::new(0),
-            duration: std::sync::atomic::AtomicU64::new(0),
-            renewable: std::sync::atomic::AtomicBool::new(false),
+            state: ArcSwap::from_pointee(TokenState::default()),
         }
     }
 
-    /// Returns the start time for the Token
-    pub fn get_start(&self) -> u64 {
-        self.start.load(std::sync::atomic::Ordering::SeqCst)
-    }
+    /// Checks if the Token has already expired, based on the stored start
+    /// time and duration
+    pub fn is_expired(&self) -> bool {
+        let state = self.state.load();
 
-    /// Returns the duration for the Token
-    pub fn get_duration(&self) -> u64 {
-        self.duration.load(std::sync::atomic::Ordering::SeqCst)
+        let elapsed = current_time().saturating_sub(state.start);
+        elapsed >= state.duration
     }
 
-    /// Returns the renewable status for the Token
-    pub fn get_renewable(&self) -> bool {
-        self.renewable.load(std::sync::atomic::Ordering::SeqCst)
+    /// Replaces the whole Session using the Auth-Information returned by
+    /// vault after a login
+    pub fn set_login(&self, auth: super::AuthInfo) {
+        self.state.store(Arc::new(TokenState {
+            token: auth.client_token,
+            start: current_time(),
+            duration: auth.lease_duration,
+            renewable: auth.renewable,
+            max_expire: None,
+        }));
     }
 
-    /// Returns the Token itself
-    ///
-    /// Problem:
-    /// There could be a Data Race if this access is not synchronized in any
-    /// way, because there could be a Ptr-Swap + Drop in another thread after
-    /// this thread has read the Ptr itself and before it has actually cloned
-    /// the Data where the Ptr was pointing at.
+    /// Updates the renewable status and times using the Auth-Information
+    /// returned by vault after a renew, the Token itself stays the same.
+    /// If vault returned a shorter lease than the requested one, the Token
+    /// has reached its max TTL, which is therefore the end of the new lease
     ///
-    /// Safety:
-    /// This Operation relies on the fact that while the token is being loaded,
-    /// it is not going to be swapped out by another thread.
-    /// After this function is finished the String is still valid to use,
-    /// so only during it's execution is a data race present. However multiple
-    /// threads can still read this token at the same time with no problems as
-    /// long as it is not swapped out
-    pub fn get_token(&self) -> Option<String> {
-        let raw_token = self.token.load(std::sync::atomic::Ordering::SeqCst);
-        match unsafe { raw_token.as_ref() } {
-            None => None,
-            Some(s) => Some(s.clone()),
-        }
-    }
+    /// If the Session was replaced by a login in the meantime, the renew
+    /// belonged to the old Token and is therefore ignored
+    pub fn set_renew(&self, auth: super::AuthInfo, requested: Option<u64>) {
+        let start = current_time();
+        self.state.rcu(|current| {
+            if !auth.client_token.is_empty() && auth.client_token != current.token {
+                return current.clone();
+            }
+
+            let max_expire = match requested {
+                Some(requested) if auth.lease_duration < requested => {
+                    Some(start + auth.lease_duration)
+                }
+                _ => current.max_expire,
+            };
 
-    /// Updates the internal Start time for the Token
-    pub fn set_start(&self, new_start: u64) {
-        self.start
-            .store(new_start, std::sync::atomic::Ordering::SeqCst);
+            Arc::new(TokenState {
+                token: current.token.clone(),
+                start,
+                duration: auth.lease_duration,
+                renewable: auth.renewable,
+                max_expire,
+            })
+        });
     }
 
-    /// Updates the internal Duration for the Token
-    pub fn set_duration(&self, new_duration: u64) {
-        self.duration
-            .store(new_duration, std::sync::atomic::Ordering::SeqCst);
+    /// Sets the time, in seconds since the unix epoch, at which the Token
+    /// reaches its max TTL
+    pub fn set_max_expire(&self, max_expire: Option<u64>) {
+        self.state.rcu(|current| {
+            Arc::new(TokenState {
+                token: current.token.clone(),
+                max_expire,
+                ..**current
+            })
+        });
     }
 
-    /// Updates the internal renewable status for the Token
-    pub fn set_renewable(&self, new_renewable: bool) {
-        self.renewable
-            .store(new_renewable, std::sync::atomic::Ordering::SeqCst)
+    /// Returns the duration for the Token
+    pub fn get_duration(&self) -> u64 {
+        self.state.load().duration
     }
 
-    /// Updates the internal token itself
-    ///
-    /// Safety:
-    /// This access needs to be synchronized externally to make sure that
-    /// no other thread is trying to read the token while it is updated in
-    /// this function. Internally this is usually garantued by the fact that
-    /// the Session is blocking while the session is being updated and therefor
-    /// no other operations can take place and subsequentially also no reads
-    /// of the actual token
-    pub fn set_token(&self, token: String) {
-        let boxed = Box::new(token);
-        let new_ptr = Box::into_raw(boxed);
+    /// Returns the renewable status for the Token
+    pub fn get_renewable(&self) -> bool {
+        self.state.load().renewable
+    }
 
-        let old_ptr = self
-            .token
-            .swap(new_ptr, std::sync::atomic::Ordering::SeqCst);
+    /// Returns the time in seconds until the Token reaches its max TTL, if
+    /// this is known
+    pub fn get_max_ttl_remaining(&self) -> Option<u64> {
+        self.state
+            .load()
+            .max_expire
+            .map(|max_expire| max_expire.saturating_sub(current_time()))
+    }
 
-        // This actually drops the old token, which is an unsafe operation to do
-        // because there is no direct reference counting and theoretically there
-        // could be another thread that is currently trying to copy the old token.
-        // However this is mitigated by the fact, that this function call is
-        // synchronized by the caller and therefor no other thread can actually
-        // read the old token, while it is being dropped here.
-        unsafe {
-            drop(Box::from_raw(old_ptr));
-        }
+    /// Returns the Token itself, which is empty before the first login
+    pub fn get_token(&self) -> String {
+        self.state.load().token.clone()
     }
 }
@@ -0,0 +1,72 @@
+use crate::transport::Method;
+use crate::Error;
+use crate::HttpClient;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+/// The Information about a response-wrapping Token
+#[derive(Deserialize)]
+pub struct WrapInfo {
+    /// The path of the request that created the wrapped response
+    pub creation_path: String,
+}
+
+#[derive(Deserialize)]
+struct WrapInfoResponse {
+    data: WrapInfo,
+}
+
+#[derive(Deserialize)]
+struct UnwrapResponse<T> {
+    data: T,
+}
+
+#[derive(Serialize)]
+struct LookupRequest<'a> {
+    token: &'a str,
+}
+
+/// Looks up the Information about the given wrapping Token, without
+/// consuming the Token itself
+pub async fn lookup_wrapping(http: &HttpClient, wrapping_token: &str) -> Result<WrapInfo, Error> {
+    let req = http
+        .request(Method::POST, "sys/wrapping/lookup")?
+        .json(&LookupRequest {
+            token: wrapping_token,
+        })?;
+
+    let response = http.send(req, None).await?;
+
+    let status_code = response.status().as_u16();
+    if status_code != 200 {
+        return Err(Error::from_response(Method::POST, response));
+    }
+
+    match response.json::<WrapInfoResponse>() {
+        Err(e) => Err(Error::from(e)),
+        Ok(json) => Ok(json.data),
+    }
+}
+
+/// Unwraps the response wrapped by the given wrapping Token, which
+/// consumes the Token
+pub async fn unwrap<T: DeserializeOwned>(
+    http: &HttpClient,
+    wrapping_token: &str,
+) -> Result<T, Error> {
+    let req = http
+        .request(Method::POST, "sys/wrapping/unwrap")?
+        .header("X-Vault-Token", wrapping_token);
+
+    let response = http.send(req, None).await?;
+
+    let status_code = response.status().as_u16();
+    if status_code != 200 {
+        return Err(Error::from_response(Method::POST, response));
+    }
+
+    match response.json::<UnwrapResponse<T>>() {
+        Err(e) => Err(Error::from(e)),
+        Ok(json) => Ok(json.data),
+    }
+}
@@ -2,6 +2,7 @@ use crate::Auth;
 use crate::Client;
 use crate::Error;
 
+use serde::de::IgnoredAny;
 use serde::{Deserialize, Serialize};
 
 /// Configuration describes the configuration for a single kv2-mount
@@ -31,11 +32,6 @@ impl PartialEq for Configuration {
     }
 }
 
-#[derive(Deserialize)]
-struct ConfigurationResponse {
-    data: Configuration,
-}
-
 /// This function is used to configure the given kv2-mount with the provided
 /// configuration options
 ///
@@ -48,7 +44,7 @@ pub async fn configure(
     let path = format!("{}/config", mount);
 
     match client
-        .vault_request::<Configuration>(reqwest::Method::POST, &path, Some(config))
+        .write::<Configuration, IgnoredAny>(&path, config)
         .await
     {
         Err(e) => Err(e),
@@ -66,18 +62,8 @@ pub async fn get_configuration(
 ) -> Result<Configuration, Error> {
     let path = format!("{}/config", mount);
 
-    let resp = match client
-        .vault_request::<String>(reqwest::Method::GET, &path, None)
-        .await
-    {
-        Err(e) => return Err(e),
-        Ok(r) => r,
-    };
-
-    let resp_body = match resp.json::<ConfigurationResponse>().await {
-        Err(e) => return Err(Error::from(e)),
-        Ok(res) => res,
-    };
-
-    Ok(resp_body.data)
+    match client.read::<Configuration>(&path).await {
+        Err(e) => Err(e),
+        Ok(res) => Ok(res.data),
+    }
 }
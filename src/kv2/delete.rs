@@ -10,11 +10,5 @@ use crate::Error;
 pub async fn delete(client: &Client<impl Auth>, mount: &str, name: &str) -> Result<(), Error> {
     let path = format!("{}/data/{}", mount, name);
 
-    match client
-        .vault_request::<String>(reqwest::Method::DELETE, &path, None)
-        .await
-    {
-        Err(e) => Err(e),
-        Ok(_) => Ok(()),
-    }
+    client.delete(&path).await
 }
@@ -14,11 +14,5 @@ pub async fn delete_metadata_all_versions(
 ) -> Result<(), Error> {
     let path = format!("{}/metadata/{}", mount, name);
 
-    match client
-        .vault_request::<String>(reqwest::Method::DELETE, &path, None)
-        .await
-    {
-        Err(e) => Err(e),
-        Ok(_) => Ok(()),
-    }
+    client.delete(&path).await
 }
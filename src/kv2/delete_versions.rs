@@ -2,6 +2,7 @@ use crate::Auth;
 use crate::Client;
 use crate::Error;
 
+use serde::de::IgnoredAny;
 use serde::Serialize;
 
 #[derive(Serialize)]
@@ -24,7 +25,7 @@ pub async fn delete_versions(
     let req_body = DeleteVersionsBody { versions };
 
     match client
-        .vault_request::<DeleteVersionsBody>(reqwest::Method::POST, &path, Some(&req_body))
+        .write::<DeleteVersionsBody, IgnoredAny>(&path, &req_body)
         .await
     {
         Err(e) => Err(e),
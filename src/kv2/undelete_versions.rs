@@ -2,6 +2,7 @@ use crate::Auth;
 use crate::Client;
 use crate::Error;
 
+use serde::de::IgnoredAny;
 use serde::Serialize;
 
 #[derive(Serialize)]
@@ -25,7 +26,7 @@ pub async fn undelete_versions(
     let req_body = UndeleteVersionsBody { versions };
 
     match client
-        .vault_request::<UndeleteVersionsBody>(reqwest::Method::POST, &path, Some(&req_body))
+        .write::<UndeleteVersionsBody, IgnoredAny>(&path, &req_body)
         .await
     {
         Err(e) => Err(e),
@@ -2,6 +2,7 @@ use crate::Auth;
 use crate::Client;
 use crate::Error;
 
+use serde::de::IgnoredAny;
 use serde::Serialize;
 
 #[derive(Serialize, Debug)]
@@ -39,7 +40,7 @@ pub async fn update_set<T: Serialize>(
     }
 
     match client
-        .vault_request::<UpdatePayload<T>>(reqwest::Method::POST, &path, Some(&payload))
+        .write::<UpdatePayload<T>, IgnoredAny>(&path, &payload)
         .await
     {
         Err(e) => Err(e),
@@ -3,26 +3,70 @@
 
 /// The Approle Auth-Backend in vault
 pub mod approle;
+/// The AWS Auth-Backend in vault
+pub mod aws;
+/// The Azure Auth-Backend in vault
+pub mod azure;
+/// A blocking API for synchronous applications, which mirrors the async API,
+/// like `reqwest::blocking` does for reqwest. Needs the `blocking` feature
+#[cfg(feature = "blocking")]
+pub mod blocking;
+/// The TLS-Certificate Auth-Backend in vault
+pub mod cert;
 /// The Database module is used for all interactions with the database backend in vault
 pub mod database;
+/// The GCP Auth-Backend in vault
+pub mod gcp;
+/// The GitHub Auth-Backend in vault
+pub mod github;
 /// The Kubernetes Auth-Backend in vault
 pub mod kubernetes;
 /// The kv2 module is used for all interactions with the v2 key-value backend in vault
 pub mod kv2;
+/// The Okta Auth-Backend in vault
+pub mod okta;
+/// The RADIUS Auth-Backend in vault
+pub mod radius;
+/// The sys backend in vault, used to manage vault itself, like its namespaces
+pub mod sys;
 /// The token module is used for all basic interactions with a simple client-token and no other
-/// backend
+/// backend, as well as for managing tokens and token-roles
 pub mod token;
+/// The Transport used to send the requests to vault, which allows to use a
+/// different HTTP-Client or to fake vault in tests
+pub mod transport;
 
 mod client;
+mod env;
 mod errors;
+mod http;
 mod internals;
+mod renewal;
+mod response;
+mod retry;
+// The TLS settings are only applied by the transports of the enabled features
+#[cfg_attr(not(any(feature = "reqwest", feature = "ureq")), allow(dead_code))]
+mod tls;
 
 pub use client::*;
 pub use errors::*;
+pub use http::HttpClient;
+pub use renewal::{RenewalHandle, RenewalTask, SessionState};
+pub use response::*;
+pub use retry::RetryPolicy;
+pub use tls::{ClientCert, TlsConfig};
+
+/// Re-exported to implement the async methods of the [`Auth`] trait, without
+/// having to depend on the `async-trait` crate directly
+pub use async_trait::async_trait;
 
 /// This trait needs to be implemented by all auth backends to be used for
 /// authenticating using that backend
-pub trait Auth {
+///
+/// The login and renew operations are async, so implementations need to be
+/// annotated with [`macro@async_trait`]
+#[async_trait]
+pub trait Auth: Send + Sync {
     /// Checking if the current session is expired and needs to be renewed or dropped
     ///
     /// Safety:
@@ -34,19 +78,17 @@ pub trait Auth {
     /// that can be used for further requests to vault
     ///
     /// Safety:
-    /// This function is always called in a synchronized manner during which
-    /// no other Thread is readinh the Token. This allows for optimizations and
-    /// techniques to be used that rely on exclusive access to the Token when it
-    /// is being updated, but not while reading it. This helps to avoid any
-    /// Mutexes/Locks in the Auth-Backend.
-    fn auth(&self, vault_url: &str) -> Result<(), Error>;
+    /// The Client only runs a single login at a time, but other Threads may
+    /// read the Token or renew the session while it is running, so the new
+    /// session has to be swapped in atomically
+    async fn auth(&self, http: &HttpClient) -> Result<(), Error>;
     /// Returns the vault token that can be used to make requests to vault
     /// as the current session
     ///
     /// Safety:
     /// This function is expected to be called from mulitple Threads at the same
-    /// time in an unsychronized way, but not while the Backend is doing a single
-    /// Auth-Operation
+    /// time in an unsychronized way, even while the session is being renewed or
+    /// replaced by a login
     fn get_token(&self) -> String;
     /// Returns if the current token can be renewed using this auth-backend.
     /// This is used to decide whether or not to try to renew the session before
@@ -68,7 +110,62 @@ pub trait Auth {
     /// Safety:
     /// This function is only expected to be called by the background thread that
     /// renews the token
-    fn renew(&self, vault_url: &str) -> Result<(), Error>;
+    async fn renew(&self, http: &HttpClient) -> Result<(), Error>;
+    /// Renews the Tokens lease like `renew`, but requests the given increment
+    /// in seconds from vault, which may still cap it based on the max TTL of
+    /// the Token. By default the increment is ignored and `renew` is used
+    ///
+    /// Safety:
+    /// This function is only expected to be called by the background thread that
+    /// renews the token
+    async fn renew_with(&self, http: &HttpClient, increment: Option<u64>) -> Result<(), Error> {
+        let _ = increment;
+        self.renew(http).await
+    }
+    /// Returns the time in seconds until the current token reaches its max TTL,
+    /// after which it can not be renewed anymore, None if this is not known.
+    /// By default the max TTL is never known
+    ///
+    /// Safety:
+    /// This function is expected to be called from mulitple Threads at the same
+    /// time in an unsychronized way, even while the session is being renewed or
+    /// replaced by a login
+    fn get_max_ttl_remaining(&self) -> Option<u64> {
+        None
+    }
+}
+
+/// Any boxed auth backend can be used as an auth backend itself, which allows
+/// to use a [`DynClient`] without being generic over the auth backend
+#[async_trait]
+impl<A> Auth for Box<A>
+where
+    A: Auth + ?Sized,
+{
+    fn is_expired(&self) -> bool {
+        (**self).is_expired()
+    }
+    async fn auth(&self, http: &HttpClient) -> Result<(), Error> {
+        (**self).auth(http).await
+    }
+    fn get_token(&self) -> String {
+        (**self).get_token()
+    }
+    fn is_renewable(&self) -> bool {
+        (**self).is_renewable()
+    }
+    fn get_total_duration(&self) -> u64 {
+        (**self).get_total_duration()
+    }
+    async fn renew(&self, http: &HttpClient) -> Result<(), Error> {
+        (**self).renew(http).await
+    }
+    async fn renew_with(&self, http: &HttpClient, increment: Option<u64>) -> Result<(), Error> {
+        (**self).renew_with(http, increment).await
+    }
+    fn get_max_ttl_remaining(&self) -> Option<u64> {
+        (**self).get_max_ttl_remaining()
+    }
 }
 
 /// The RenewPolicy describes how the vault client should deal with expired
@@ -82,31 +179,164 @@ pub enum RenewPolicy {
     /// possible without ever letting it actually expire.
     /// The float should be a value between 0-1 and represents the percentage (0=0%, 1=100%)
     /// of time that should be remaining before a session/token is renewed.
+    /// Values outside of 0-1 are clamped to the closest bound, NaN uses the default of 0.25
     ///
     /// Example:
     /// With a threshold of 0.25 and a total Token Duration of 60m, the Token will be renewed
     /// after 45m/ when only 15min are left.
     Renew(f32),
+    /// RenewWith renews the token like Renew, but allows to configure how the
+    /// renewal is done, see [`RenewOptions`]
+    RenewWith(RenewOptions),
     /// Nothing does nothing when the session expires. This will cause the client to always
     /// return a SessionExpired error when trying to request anything from vault
     Nothing,
 }
 
+impl RenewPolicy {
+    /// The Options used for renewing the token, None if the policy does not
+    /// renew the token
+    pub(crate) fn renew_options(&self) -> Option<RenewOptions> {
+        match *self {
+            RenewPolicy::Renew(threshold) => Some(RenewOptions {
+                threshold: clamp_threshold(threshold),
+                ..Default::default()
+            }),
+            RenewPolicy::RenewWith(ref options) => Some(RenewOptions {
+                threshold: clamp_threshold(options.threshold),
+                ..options.clone()
+            }),
+            RenewPolicy::Reauth | RenewPolicy::Nothing => None,
+        }
+    }
+}
+
+/// Keeps the threshold between 0 and 1, as the time to wait before a renew
+/// would otherwise be negative or NaN
+fn clamp_threshold(threshold: f32) -> f32 {
+    match threshold.is_nan() {
+        true => RenewOptions::default().threshold,
+        false => threshold.clamp(0.0, 1.0),
+    }
+}
+
+/// The RenewOptions describe how the token is renewed, when using
+/// [`RenewPolicy::RenewWith`]
+#[derive(Debug, Clone)]
+pub struct RenewOptions {
+    /// The percentage of time that should be remaining before the token is
+    /// renewed, like the value of [`RenewPolicy::Renew`], and is clamped the
+    /// same way
+    ///
+    /// Default: 0.25
+    pub threshold: f32,
+    /// The increment in seconds that is requested from vault for every renew,
+    /// None lets vault use the default TTL of the token
+    ///
+    /// Default: None
+    pub increment: Option<u64>,
+    /// Once the token is closer to its max TTL than this, a new session is
+    /// obtained via the provided auth config, instead of renewing the token.
+    /// None obtains a new session once vault starts to cap the renewed lease,
+    /// because the token is about to reach its max TTL
+    ///
+    /// Default: None
+    pub relogin_threshold: Option<std::time::Duration>,
+}
+
+impl Default for RenewOptions {
+    fn default() -> Self {
+        RenewOptions {
+            threshold: 0.25,
+            increment: None,
+            relogin_threshold: None,
+        }
+    }
+}
+
+/// The Handler that is called with the path of the request and the warnings
+/// that vault attached to the response
+pub type WarningHandler = std::sync::Arc<dyn Fn(&str, &[String]) + Send + Sync>;
+
 /// The Configuration for the vault client
 pub struct Config {
     /// The URL the client should use to connect to the vault instance
     pub vault_url: String,
+    /// The Vault-Enterprise namespace used for all requests, including the
+    /// login and renew requests of the Auth-Backend. None or an empty
+    /// namespace use the root namespace
+    ///
+    /// Default: None
+    pub namespace: Option<String>,
     /// The Policy the client should use to handle sessions expiring
     ///
     /// Default: RenewPolicy::Reauth
     pub renew_policy: RenewPolicy,
+    /// If set, this is called for every response that contains warnings, like
+    /// when vault capped a requested TTL, instead of silently dropping them
+    ///
+    /// Default: None
+    pub warning_handler: Option<WarningHandler>,
+    /// The maximum number of idle connections per host that are kept in the
+    /// connection pool
+    ///
+    /// Default: usize::MAX
+    pub pool_max_idle_per_host: usize,
+    /// How long idle connections are kept in the connection pool, None keeps
+    /// them forever
+    ///
+    /// Default: 90 seconds
+    pub pool_idle_timeout: Option<std::time::Duration>,
+    /// The interval for TCP keep-alive probes on the connections, None
+    /// disables keep-alive probes
+    ///
+    /// Default: None
+    pub tcp_keepalive: Option<std::time::Duration>,
+    /// How long to wait for a connection to vault to be established, None
+    /// waits forever
+    ///
+    /// Default: 10 seconds
+    pub connect_timeout: Option<std::time::Duration>,
+    /// How long a single request to vault may take in total, from connecting
+    /// until the response body was read, None waits forever. This also applies
+    /// to the requests made by the Auth-Backends, a single request can use a
+    /// different deadline using [`Client::with_timeout`]
+    ///
+    /// Default: 60 seconds
+    pub timeout: Option<std::time::Duration>,
+    /// The TLS settings used for the connections to vault, this includes the
+    /// connections made by the Auth-Backends to login and renew
+    ///
+    /// Default: TlsConfig::default()
+    pub tls: TlsConfig,
+    /// How requests that failed because of a transient error are retried,
+    /// this includes the requests made by the Auth-Backends
+    ///
+    /// Default: RetryPolicy::default()
+    pub retry: RetryPolicy,
+    /// The Transport used to send all requests, including the ones made by
+    /// the Auth-Backends. If None, the transport of the enabled feature is
+    /// created using the connection and TLS settings above
+    ///
+    /// Default: None
+    pub transport: Option<std::sync::Arc<dyn transport::Transport>>,
 }
 
 impl Default for Config {
     fn default() -> Config {
         Config {
             vault_url: "http://localhost:8200".to_string(),
+            namespace: None,
             renew_policy: RenewPolicy::Reauth,
+            warning_handler: None,
+            pool_max_idle_per_host: usize::MAX,
+            pool_idle_timeout: Some(std::time::Duration::from_secs(90)),
+            tcp_keepalive: None,
+            connect_timeout: Some(std::time::Duration::from_secs(10)),
+            timeout: Some(std::time::Duration::from_secs(60)),
+            tls: TlsConfig::default(),
+            retry: RetryPolicy::default(),
+            transport: None,
         }
     }
 }
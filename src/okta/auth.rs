@@ -0,0 +1,121 @@
+use async_trait::async_trait;
+use serde::Serialize;
+
+use crate::internals;
+use crate::Auth as AuthTrait;
+use crate::Error;
+use crate::HttpClient;
+
+/// The Config for the Login using the Okta Auth-Backend
+pub struct OktaConfig {
+    /// The username to login as
+    pub username: String,
+    /// The password for the user
+    pub password: String,
+    /// The TOTP passcode, if the user is required to use TOTP based MFA
+    ///
+    /// Default: None
+    pub totp: Option<String>,
+    /// The MFA provider to use, if the user has multiple factors enrolled
+    ///
+    /// Default: None
+    pub provider: Option<String>,
+    /// The Nonce sent along with the login when Okta-Verify push notifications
+    /// are used. It can be passed to [`verify_number`](fn.verify_number.html),
+    /// while the login is pending, to obtain the number that needs to be
+    /// selected in the push notification
+    ///
+    /// Default: None
+    pub nonce: Option<String>,
+    /// The path at which the Okta Auth-Backend is mounted
+    ///
+    /// Default: `okta`
+    pub mount: String,
+}
+
+impl Default for OktaConfig {
+    fn default() -> OktaConfig {
+        OktaConfig {
+            username: "".to_string(),
+            password: "".to_string(),
+            totp: None,
+            provider: None,
+            nonce: None,
+            mount: "okta".to_string(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct OktaLogin<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    nonce: Option<&'a str>,
+    password: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    provider: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    totp: Option<&'a str>,
+}
+
+/// The Auth session for the Okta Backend, used by the vault client
+/// to authenticate requests using a username and password as well as
+/// an optional second factor
+pub struct Session {
+    config: OktaConfig,
+    token: internals::TokenContainer,
+}
+
+#[async_trait]
+impl AuthTrait for Session {
+    fn is_expired(&self) -> bool {
+        self.token.is_expired()
+    }
+
+    fn get_token(&self) -> String {
+        self.token.get_token()
+    }
+
+    async fn auth(&self, http: &HttpClient) -> Result<(), Error> {
+        let body = OktaLogin {
+            nonce: self.config.nonce.as_deref(),
+            password: &self.config.password,
+            provider: self.config.provider.as_deref(),
+            totp: self.config.totp.as_deref(),
+        };
+        let path = format!("auth/{}/login/{}", self.config.mount, self.config.username);
+        let auth = internals::login(http, &path, &body).await?;
+        self.token.set_login(auth);
+
+        Ok(())
+    }
+
+    fn is_renewable(&self) -> bool {
+        self.token.get_renewable()
+    }
+
+    fn get_total_duration(&self) -> u64 {
+        self.token.get_duration()
+    }
+
+    async fn renew(&self, http: &HttpClient) -> Result<(), Error> {
+        self.renew_with(http, None).await
+    }
+
+    async fn renew_with(&self, http: &HttpClient, increment: Option<u64>) -> Result<(), Error> {
+        internals::renew_token(http, &self.token, increment).await
+    }
+
+    fn get_max_ttl_remaining(&self) -> Option<u64> {
+        self.token.get_max_ttl_remaining()
+    }
+}
+
+impl Session {
+    /// This is used to obtain a new Auth-Session for the Okta Auth-Backend
+    pub fn new(config: OktaConfig) -> Result<Session, Error> {
+        Ok(Session {
+            config,
+            token: internals::TokenContainer::new(),
+        })
+    }
+}
@@ -0,0 +1,5 @@
+mod auth;
+mod verify_number;
+
+pub use auth::*;
+pub use verify_number::*;
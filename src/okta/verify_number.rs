@@ -0,0 +1,43 @@
+use serde::Deserialize;
+
+use crate::transport::Method;
+use crate::Error;
+use crate::HttpClient;
+
+#[derive(Deserialize)]
+struct VerifyData {
+    correct_answer: u64,
+}
+
+#[derive(Deserialize)]
+struct VerifyResponse {
+    data: VerifyData,
+}
+
+/// Loads the number that needs to be selected in the Okta-Verify push
+/// notification for the login that was started with the given nonce.
+///
+/// This is meant to be called from another task while the login of the
+/// Okta-Session is still waiting for the push notification to be answered,
+/// using an HttpClient with the same TLS and namespace settings as the login.
+///
+/// # Arguments:
+/// * `http`: The HttpClient used to reach the vault instance
+/// * `mount`: The path at which the Okta Auth-Backend is mounted
+/// * `nonce`: The Nonce that was configured for the Session
+///
+/// [Vault-Documentation](https://www.vaultproject.io/api-docs/auth/okta#verify)
+pub async fn verify_number(http: &HttpClient, mount: &str, nonce: &str) -> Result<u64, Error> {
+    let req = http.request(Method::GET, &format!("auth/{}/verify/{}", mount, nonce))?;
+    let response = http.send(req, None).await?;
+
+    let status_code = response.status().as_u16();
+    if status_code != 200 {
+        return Err(Error::from_response(Method::GET, response));
+    }
+
+    match response.json::<VerifyResponse>() {
+        Err(e) => Err(Error::from(e)),
+        Ok(json) => Ok(json.data.correct_answer),
+    }
+}
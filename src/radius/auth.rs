@@ -0,0 +1,93 @@
+use async_trait::async_trait;
+use serde::Serialize;
+
+use crate::internals;
+use crate::Auth as AuthTrait;
+use crate::Error;
+use crate::HttpClient;
+
+/// The Config for the Login using the RADIUS Auth-Backend
+pub struct RadiusConfig {
+    /// The username to login as
+    pub username: String,
+    /// The password for the user
+    pub password: String,
+    /// The path at which the RADIUS Auth-Backend is mounted
+    ///
+    /// Default: `radius`
+    pub mount: String,
+}
+
+impl Default for RadiusConfig {
+    fn default() -> RadiusConfig {
+        RadiusConfig {
+            username: "".to_string(),
+            password: "".to_string(),
+            mount: "radius".to_string(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct RadiusLogin<'a> {
+    password: &'a str,
+}
+
+/// The Auth session for the RADIUS Backend, used by the vault client
+/// to authenticate requests using a username and password
+pub struct Session {
+    config: RadiusConfig,
+    token: internals::TokenContainer,
+}
+
+#[async_trait]
+impl AuthTrait for Session {
+    fn is_expired(&self) -> bool {
+        self.token.is_expired()
+    }
+
+    fn get_token(&self) -> String {
+        self.token.get_token()
+    }
+
+    async fn auth(&self, http: &HttpClient) -> Result<(), Error> {
+        let body = RadiusLogin {
+            password: &self.config.password,
+        };
+        let path = format!("auth/{}/login/{}", self.config.mount, self.config.username);
+        let auth = internals::login(http, &path, &body).await?;
+        self.token.set_login(auth);
+
+        Ok(())
+    }
+
+    fn is_renewable(&self) -> bool {
+        self.token.get_renewable()
+    }
+
+    fn get_total_duration(&self) -> u64 {
+        self.token.get_duration()
+    }
+
+    async fn renew(&self, http: &HttpClient) -> Result<(), Error> {
+        self.renew_with(http, None).await
+    }
+
+    async fn renew_with(&self, http: &HttpClient, increment: Option<u64>) -> Result<(), Error> {
+        internals::renew_token(http, &self.token, increment).await
+    }
+
+    fn get_max_ttl_remaining(&self) -> Option<u64> {
+        self.token.get_max_ttl_remaining()
+    }
+}
+
+impl Session {
+    /// This is used to obtain a new Auth-Session for the RADIUS Auth-Backend
+    pub fn new(config: RadiusConfig) -> Result<Session, Error> {
+        Ok(Session {
+            config,
+            token: internals::TokenContainer::new(),
+        })
+    }
+}
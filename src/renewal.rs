@@ -0,0 +1,230 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+
+use tokio::sync::{watch, Notify};
+
+use crate::Auth;
+use crate::Client;
+use crate::Error;
+use crate::RenewOptions;
+
+/// The Future that renews the session in the background, until it is stopped
+/// using its [`RenewalHandle`]. It needs to be spawned on the executor of the
+/// application, like `tokio::spawn(task)`
+pub type RenewalTask = Pin<Box<dyn Future<Output = ()> + Send + 'static>>;
+
+/// The State of the session, as seen by the renewal task
+#[derive(Debug, Clone, Default)]
+pub struct SessionState {
+    /// The TTL of the token after its last renew or login
+    pub ttl: Duration,
+    /// The time until the token reaches its max TTL, None if this is not known
+    pub max_ttl_remaining: Option<Duration>,
+    /// When the session was last renewed or replaced by a login, None if
+    /// this has not happened since the task was started
+    pub last_renew: Option<SystemTime>,
+    /// The last Error returned by a renew or login, this is not cleared by
+    /// a later success
+    pub last_error: Option<Arc<Error>>,
+    /// Whether or not the renewal task has stopped
+    pub stopped: bool,
+}
+
+/// The Handle to a running renewal task, used to observe and stop it
+///
+/// Dropping the Handle does not stop the task
+#[derive(Clone)]
+pub struct RenewalHandle {
+    stop: Arc<Notify>,
+    state: watch::Receiver<SessionState>,
+}
+
+impl RenewalHandle {
+    /// Stops the renewal task, which exits the next time it would wait.
+    /// A renew or login that is currently running is finished first
+    pub fn stop(&self) {
+        self.stop.notify_one();
+    }
+
+    /// The current State of the session
+    pub fn state(&self) -> SessionState {
+        self.state.borrow().clone()
+    }
+
+    /// A Receiver, that is notified whenever the State of the session changes
+    pub fn watch(&self) -> watch::Receiver<SessionState> {
+        self.state.clone()
+    }
+}
+
+/// Creates the renewal task for the given client and the handle to control it
+pub(crate) fn start<T>(client: Client<T>, options: RenewOptions) -> (RenewalTask, RenewalHandle)
+where
+    T: Auth + 'static,
+{
+    let stop = Arc::new(Notify::new());
+    let (sender, receiver) = watch::channel(SessionState {
+        ttl: Duration::from_secs(client.auth_backend().get_total_duration()),
+        max_ttl_remaining: client
+            .auth_backend()
+            .get_max_ttl_remaining()
+            .map(Duration::from_secs),
+        ..Default::default()
+    });
+
+    // The guard is moved into the task right away, so the task is reported
+    // as stopped even if it is dropped before it ever ran
+    let task = run(client, options, stop.clone(), StopGuard(sender));
+    let handle = RenewalHandle {
+        stop,
+        state: receiver,
+    };
+
+    (Box::pin(task), handle)
+}
+
+/// Marks the renewal task as stopped once it is dropped, which happens when it
+/// finishes, but also when it is dropped without being spawned or aborted
+struct StopGuard(watch::Sender<SessionState>);
+
+impl Drop for StopGuard {
+    fn drop(&mut self) {
+        self.0.send_modify(|state| state.stopped = true);
+    }
+}
+
+/// Checks if the token of the session never expires, like a root token, in
+/// which case there is nothing to renew
+fn is_non_expiring<T>(auth: &T, refreshed: bool) -> bool
+where
+    T: Auth,
+{
+    // An auth backend without a token has not logged in yet
+    if auth.get_total_duration() != 0 || auth.get_token().is_empty() {
+        return false;
+    }
+    if auth.get_max_ttl_remaining().is_some() {
+        return false;
+    }
+
+    // A renewable token without a TTL is only trusted to never expire, once
+    // vault confirmed it by a renew or login
+    !auth.is_renewable() || refreshed
+}
+
+/// Waits for the given duration, returns false if the task was stopped in
+/// the meantime
+async fn wait(duration: Duration, stop: &Notify) -> bool {
+    tokio::select! {
+        _ = futures_timer::Delay::new(duration) => true,
+        _ = stop.notified() => false,
+    }
+}
+
+async fn run<T>(client: Client<T>, options: RenewOptions, stop: Arc<Notify>, guard: StopGuard)
+where
+    T: Auth,
+{
+    let state = &guard.0;
+    let auth = client.auth_backend();
+    let mut failures: u32 = 0;
+    let mut refreshed = false;
+
+    loop {
+        let total_duration = auth.get_total_duration();
+        if failures == 0 && is_non_expiring(auth, refreshed) {
+            stop.notified().await;
+            break;
+        }
+
+        let wait_duration = match failures {
+            0 => Duration::from_secs(total_duration).mul_f32(1.0 - options.threshold),
+            _ => client.retry_policy().backoff(failures - 1),
+        };
+        if !wait(wait_duration, &stop).await {
+            break;
+        }
+
+        let attempt = renew_or_reauth(&client, &options).await;
+        state.send_modify(|state| {
+            if attempt.refreshed {
+                state.last_renew = Some(SystemTime::now());
+            }
+            if let Some(ref e) = attempt.error {
+                state.last_error = Some(e.clone());
+            }
+            state.ttl = Duration::from_secs(auth.get_total_duration());
+            state.max_ttl_remaining = auth.get_max_ttl_remaining().map(Duration::from_secs);
+        });
+
+        refreshed = attempt.refreshed;
+        failures = match attempt.refreshed {
+            true => 0,
+            false => failures.saturating_add(1),
+        };
+    }
+}
+
+/// The Outcome of a single renewal attempt
+struct Attempt {
+    /// Whether or not the session was renewed or replaced by a login
+    refreshed: bool,
+    /// The Error of the renew or login, also set if the renew failed but
+    /// the login afterwards succeeded
+    error: Option<Arc<Error>>,
+}
+
+/// Checks if the token is close enough to its max TTL, that a new login is
+/// needed instead of renewing it
+pub(crate) fn needs_relogin<T>(auth: &T, options: &RenewOptions) -> bool
+where
+    T: Auth,
+{
+    let remaining = match auth.get_max_ttl_remaining() {
+        Some(remaining) => Duration::from_secs(remaining),
+        None => return false,
+    };
+
+    match options.relogin_threshold {
+        Some(threshold) => remaining <= threshold,
+        // Vault caps the lease of a renewed token at its max TTL
+        None => remaining <= Duration::from_secs(auth.get_total_duration()),
+    }
+}
+
+/// Renews the session if possible and falls back to a new login if the
+/// renew failed, the session can not be renewed or the token is close to
+/// its max TTL
+async fn renew_or_reauth<T>(client: &Client<T>, options: &RenewOptions) -> Attempt
+where
+    T: Auth,
+{
+    let auth = client.auth_backend();
+
+    let mut renew_error = None;
+    if auth.is_renewable() && !needs_relogin(auth, options) {
+        match auth.renew_with(client.http(), options.increment).await {
+            Ok(_) if !needs_relogin(auth, options) => {
+                return Attempt {
+                    refreshed: true,
+                    error: None,
+                };
+            }
+            Ok(_) => {}
+            Err(e) => renew_error = Some(Arc::new(e)),
+        };
+    }
+
+    match client.reauth().await {
+        Ok(_) => Attempt {
+            refreshed: true,
+            error: renew_error,
+        },
+        Err(e) => Attempt {
+            refreshed: false,
+            error: Some(Arc::new(e)),
+        },
+    }
+}
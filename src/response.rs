@@ -0,0 +1,74 @@
+use serde::{Deserialize, Deserializer};
+
+/// The Information about a response that was wrapped by vault, instead of
+/// being returned directly
+#[derive(Debug, Default, Deserialize, Clone, PartialEq)]
+#[serde(default)]
+pub struct WrapInfo {
+    /// The wrapping Token, which can be used to unwrap the actual response
+    pub token: String,
+    /// The accessor of the wrapping Token
+    pub accessor: String,
+    /// The TTL of the wrapping Token in seconds
+    pub ttl: u64,
+    /// The time at which the response was wrapped
+    pub creation_time: String,
+    /// The path of the request that created the wrapped response
+    pub creation_path: String,
+}
+
+/// The standard envelope vault wraps the data of every response in
+///
+/// [Vault-Documentation](https://www.vaultproject.io/api-docs#http-status-codes)
+#[derive(Debug, Deserialize)]
+pub struct VaultResponse<T> {
+    /// The ID of the request, can be used to find the request in the audit logs
+    #[serde(default)]
+    pub request_id: String,
+    /// The ID of the lease, if the data is leased
+    #[serde(default)]
+    pub lease_id: String,
+    /// The duration of the lease in seconds
+    #[serde(default)]
+    pub lease_duration: u64,
+    /// Whether or not the lease can be renewed
+    #[serde(default)]
+    pub renewable: bool,
+    /// The actual data of the response
+    pub data: T,
+    /// The warnings vault attached to the response, like when a requested TTL
+    /// was capped to the max TTL
+    #[serde(default, deserialize_with = "null_as_empty")]
+    pub warnings: Vec<String>,
+    /// The Information about the wrapped response, if the response was wrapped
+    #[serde(default)]
+    pub wrap_info: Option<WrapInfo>,
+    /// The type of the mount that handled the request, like `kv`
+    #[serde(default)]
+    pub mount_type: String,
+}
+
+/// The envelope vault wraps the response of a login or a token operation in,
+/// like [`VaultResponse`], but the result is in `auth` instead of `data`
+#[derive(Debug, Deserialize)]
+pub(crate) struct AuthResponse<A> {
+    /// The Information about the new or renewed token
+    pub(crate) auth: A,
+    /// The warnings vault attached to the response, like when a requested TTL
+    /// was capped to the max TTL
+    #[serde(default, deserialize_with = "null_as_empty")]
+    pub(crate) warnings: Vec<String>,
+}
+
+/// Vault returns `null` instead of an empty list if there are no warnings
+fn null_as_empty<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<String>, D::Error> {
+    let warnings: Option<Vec<String>> = Option::deserialize(deserializer)?;
+    Ok(warnings.unwrap_or_default())
+}
+
+/// The data returned by vault when listing keys
+#[derive(Debug, Default, Deserialize, Clone, PartialEq)]
+pub struct KeyList {
+    /// The listed keys, keys that end with `/` are folders
+    pub keys: Vec<String>,
+}
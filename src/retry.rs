@@ -0,0 +1,85 @@
+use rand::Rng;
+use std::time::Duration;
+
+use crate::transport::{TransportError, TransportErrorKind};
+
+/// Describes how failed requests to vault are retried, the default follows the
+/// vault CLI, which retries a request up to 2 times if it failed with a
+/// 412 or any 5xx status, other than 501, or if the connection failed
+#[derive(Clone, Debug, PartialEq)]
+pub struct RetryPolicy {
+    /// The maximum number of retries for a single request, 0 disables retries
+    ///
+    /// Default: 2
+    pub max_retries: u32,
+    /// The backoff before the first retry, which is doubled for every
+    /// following retry
+    ///
+    /// Default: 1000ms
+    pub min_backoff: Duration,
+    /// The upper limit for the backoff between two retries
+    ///
+    /// Default: 1500ms
+    pub max_backoff: Duration,
+    /// Whether or not the backoff is randomly reduced by up to half, to avoid
+    /// many clients retrying at the same time
+    ///
+    /// Default: true
+    pub jitter: bool,
+    /// The HTTP-Status-Codes that cause a request to be retried
+    ///
+    /// Default: 412 and 500-599, except 501
+    pub retry_statuses: Vec<u16>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> RetryPolicy {
+        RetryPolicy {
+            max_retries: 2,
+            min_backoff: Duration::from_millis(1000),
+            max_backoff: Duration::from_millis(1500),
+            jitter: true,
+            retry_statuses: std::iter::once(412)
+                .chain((500..600).filter(|status| *status != 501))
+                .collect(),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// A policy that never retries any request
+    pub fn disabled() -> RetryPolicy {
+        RetryPolicy {
+            max_retries: 0,
+            ..Default::default()
+        }
+    }
+
+    /// Whether or not a response with the given status should be retried
+    pub(crate) fn retry_status(&self, status: u16) -> bool {
+        self.retry_statuses.contains(&status)
+    }
+
+    /// Whether or not a request that failed with the given error should be
+    /// retried, this is the case if the request could not be sent or no
+    /// response was received
+    pub(crate) fn retry_error(&self, error: &TransportError) -> bool {
+        error.kind() != TransportErrorKind::Other
+    }
+
+    /// The backoff before the given retry, starting at 0 for the first retry
+    pub(crate) fn backoff(&self, retry: u32) -> Duration {
+        let factor = 2u32.saturating_pow(retry);
+        let backoff = match self.min_backoff.checked_mul(factor) {
+            Some(backoff) if backoff < self.max_backoff => backoff,
+            _ => self.max_backoff,
+        };
+
+        if !self.jitter {
+            return backoff;
+        }
+
+        let half = backoff / 2;
+        half + half.mul_f64(rand::thread_rng().gen::<f64>())
+    }
+}
@@ -0,0 +1,3 @@
+mod namespaces;
+
+pub use namespaces::*;
@@ -0,0 +1,112 @@
+use crate::transport::Method;
+use crate::Auth;
+use crate::Client;
+use crate::Error;
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// A Vault-Enterprise namespace
+#[derive(Deserialize, Debug, PartialEq)]
+pub struct Namespace {
+    /// The ID of the namespace
+    pub id: String,
+    /// The full path of the namespace, ending with a `/`
+    pub path: String,
+    /// The custom metadata attached to the namespace
+    #[serde(default)]
+    pub custom_metadata: HashMap<String, String>,
+}
+
+#[derive(Serialize)]
+struct CreateRequest<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    custom_metadata: Option<&'a HashMap<String, String>>,
+}
+
+/// Creates the namespace at the given path, relative to the namespace of the
+/// client, optionally with the given custom metadata
+///
+/// [Vault-Documentation](https://www.vaultproject.io/api-docs/system/namespaces#create-namespace)
+pub async fn create_namespace(
+    client: &Client<impl Auth>,
+    path: &str,
+    custom_metadata: Option<&HashMap<String, String>>,
+) -> Result<Namespace, Error> {
+    let path = format!("sys/namespaces/{}", path);
+
+    match client
+        .request::<_, Namespace>(
+            Method::POST,
+            &path,
+            Some(&CreateRequest { custom_metadata }),
+        )
+        .await
+    {
+        Err(e) => Err(e),
+        Ok(body) => Ok(body.data),
+    }
+}
+
+#[derive(Serialize)]
+struct UpdateRequest<'a> {
+    custom_metadata: &'a HashMap<String, String>,
+}
+
+/// Updates the custom metadata of the namespace at the given path, relative
+/// to the namespace of the client. The given entries are merged into the
+/// existing metadata
+///
+/// [Vault-Documentation](https://www.vaultproject.io/api-docs/system/namespaces#patch-namespace)
+pub async fn update_namespace(
+    client: &Client<impl Auth>,
+    path: &str,
+    custom_metadata: &HashMap<String, String>,
+) -> Result<Namespace, Error> {
+    let path = format!("sys/namespaces/{}", path);
+
+    match client
+        .request::<_, Namespace>(
+            Method::PATCH,
+            &path,
+            Some(&UpdateRequest { custom_metadata }),
+        )
+        .await
+    {
+        Err(e) => Err(e),
+        Ok(body) => Ok(body.data),
+    }
+}
+
+/// Reads the namespace at the given path, relative to the namespace of the
+/// client
+///
+/// [Vault-Documentation](https://www.vaultproject.io/api-docs/system/namespaces#read-namespace-information)
+pub async fn read_namespace(client: &Client<impl Auth>, path: &str) -> Result<Namespace, Error> {
+    let path = format!("sys/namespaces/{}", path);
+
+    match client.read::<Namespace>(&path).await {
+        Err(e) => Err(e),
+        Ok(body) => Ok(body.data),
+    }
+}
+
+/// Lists the paths of all namespaces directly below the namespace of the
+/// client
+///
+/// [Vault-Documentation](https://www.vaultproject.io/api-docs/system/namespaces#list-namespaces)
+pub async fn list_namespaces(client: &Client<impl Auth>) -> Result<Vec<String>, Error> {
+    match client.list("sys/namespaces").await {
+        Err(e) => Err(e),
+        Ok(body) => Ok(body.data.keys),
+    }
+}
+
+/// Deletes the namespace at the given path, relative to the namespace of the
+/// client
+///
+/// [Vault-Documentation](https://www.vaultproject.io/api-docs/system/namespaces#delete-namespace)
+pub async fn delete_namespace(client: &Client<impl Auth>, path: &str) -> Result<(), Error> {
+    let path = format!("sys/namespaces/{}", path);
+    client.delete(&path).await
+}
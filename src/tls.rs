@@ -0,0 +1,207 @@
+use std::convert::TryFrom;
+use std::io::BufReader;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::SystemTime;
+
+use rustls::client::{ServerCertVerified, ServerCertVerifier};
+use rustls::{Certificate, ClientConfig, OwnedTrustAnchor, RootCertStore, ServerName};
+
+use crate::Error;
+
+/// A client certificate together with its private key, which is presented to
+/// vault when establishing a TLS connection
+#[derive(Clone)]
+pub struct ClientCert {
+    /// The PEM encoded certificate (chain)
+    pub cert_pem: Vec<u8>,
+    /// The PEM encoded private key belonging to the certificate, either in
+    /// the PKCS8 or the RSA format
+    pub key_pem: Vec<u8>,
+}
+
+impl ClientCert {
+    /// Loads the PEM encoded certificate and private key from the given files
+    pub fn from_files<P: AsRef<Path>>(cert_path: P, key_path: P) -> Result<ClientCert, Error> {
+        Ok(ClientCert {
+            cert_pem: std::fs::read(cert_path)?,
+            key_pem: std::fs::read(key_path)?,
+        })
+    }
+
+    /// Parses the certificate chain and private key, to make sure both are
+    /// usable
+    pub(crate) fn parse(&self) -> Result<(Vec<Certificate>, rustls::PrivateKey), Error> {
+        let certs = match rustls_pemfile::certs(&mut BufReader::new(self.cert_pem.as_slice())) {
+            Ok(certs) if !certs.is_empty() => certs.into_iter().map(Certificate).collect(),
+            _ => {
+                return Err(Error::InvalidTLSConfig(
+                    "No certificate found in the client certificate".to_string(),
+                ))
+            }
+        };
+
+        let mut keys =
+            rustls_pemfile::pkcs8_private_keys(&mut BufReader::new(self.key_pem.as_slice()))
+                .unwrap_or_default();
+        if keys.is_empty() {
+            keys = rustls_pemfile::rsa_private_keys(&mut BufReader::new(self.key_pem.as_slice()))
+                .unwrap_or_default();
+        }
+        if keys.is_empty() {
+            return Err(Error::InvalidTLSConfig(
+                "No private key found for the client certificate".to_string(),
+            ));
+        }
+
+        Ok((certs, rustls::PrivateKey(keys.remove(0))))
+    }
+}
+
+/// The TLS settings used for all connections to vault, by default the
+/// server certificate is verified against the webpki root certificates and
+/// no client certificate is presented
+#[derive(Clone, Default)]
+pub struct TlsConfig {
+    /// A PEM encoded file containing additional CA certificates that are
+    /// trusted to sign the certificate of vault
+    ///
+    /// Default: None
+    pub ca_cert_file: Option<PathBuf>,
+    /// A directory of PEM encoded files containing additional CA certificates
+    /// that are trusted to sign the certificate of vault
+    ///
+    /// Default: None
+    pub ca_cert_dir: Option<PathBuf>,
+    /// PEM encoded CA certificates that are trusted to sign the certificate
+    /// of vault
+    ///
+    /// Default: None
+    pub ca_cert_pem: Option<Vec<u8>>,
+    /// The client certificate that is presented to vault for every connection
+    ///
+    /// Default: None
+    pub client_cert: Option<ClientCert>,
+    /// The name that is sent as SNI and that the certificate of vault is
+    /// verified against, instead of the host of the vault url. This is useful
+    /// when vault is reached using an IP address or an alias that is not part
+    /// of its certificate, like behind a load balancer that routes by SNI.
+    ///
+    /// The connection is still made to the host of the vault url, which is
+    /// resolved once when the Transport is created, and is also sent as the
+    /// Host header
+    ///
+    /// Default: None
+    pub server_name: Option<String>,
+    /// Disables all verification of the certificate of vault.
+    /// This should never be used outside of development, as it makes the
+    /// connection vulnerable to man-in-the-middle attacks
+    ///
+    /// Default: false
+    pub insecure_skip_verify: bool,
+}
+
+fn add_ca_pem(store: &mut RootCertStore, pem: &[u8], source: &str) -> Result<(), Error> {
+    let certs = rustls_pemfile::certs(&mut BufReader::new(pem)).unwrap_or_default();
+    match store.add_parsable_certificates(&certs) {
+        (valid, 0) if valid > 0 => Ok(()),
+        _ => Err(Error::InvalidTLSConfig(format!(
+            "Invalid CA certificate in {}",
+            source
+        ))),
+    }
+}
+
+/// Accepts every certificate presented by vault
+struct NoVerifier;
+
+impl ServerCertVerifier for NoVerifier {
+    fn verify_server_cert(
+        &self,
+        _end_entity: &Certificate,
+        _intermediates: &[Certificate],
+        _server_name: &ServerName,
+        _scts: &mut dyn Iterator<Item = &[u8]>,
+        _ocsp_response: &[u8],
+        _now: SystemTime,
+    ) -> Result<ServerCertVerified, rustls::Error> {
+        Ok(ServerCertVerified::assertion())
+    }
+}
+
+impl TlsConfig {
+    /// Creates the rustls config for these settings, the given identity is
+    /// presented instead of the configured client certificate if set
+    pub(crate) fn client_config(
+        &self,
+        identity: Option<&ClientCert>,
+    ) -> Result<ClientConfig, Error> {
+        let mut roots = RootCertStore::empty();
+        roots.add_trust_anchors(webpki_roots::TLS_SERVER_ROOTS.iter().map(|anchor| {
+            OwnedTrustAnchor::from_subject_spki_name_constraints(
+                anchor.subject,
+                anchor.spki,
+                anchor.name_constraints,
+            )
+        }));
+
+        if let Some(ref path) = self.ca_cert_file {
+            let pem = std::fs::read(path)?;
+            add_ca_pem(&mut roots, &pem, &path.display().to_string())?;
+        }
+        if let Some(ref dir) = self.ca_cert_dir {
+            for entry in std::fs::read_dir(dir)? {
+                let path = entry?.path();
+                if !path.is_file() {
+                    continue;
+                }
+
+                let pem = std::fs::read(&path)?;
+                add_ca_pem(&mut roots, &pem, &path.display().to_string())?;
+            }
+        }
+        if let Some(ref pem) = self.ca_cert_pem {
+            add_ca_pem(&mut roots, pem, "ca_cert_pem")?;
+        }
+
+        // The server name replaces the host of the url, so rustls uses it for
+        // the SNI and the verification on its own
+        if let Some(ref server_name) = self.server_name {
+            if ServerName::try_from(server_name.as_str()).is_err() {
+                return Err(Error::InvalidTLSConfig(format!(
+                    "Invalid server name: {}",
+                    server_name
+                )));
+            }
+        }
+
+        let builder = ClientConfig::builder()
+            .with_safe_defaults()
+            .with_root_certificates(roots);
+
+        let mut config = match identity.or(self.client_cert.as_ref()) {
+            None => builder.with_no_client_auth(),
+            Some(cert) => {
+                let (certs, key) = cert.parse()?;
+                match builder.with_client_auth_cert(certs, key) {
+                    Err(e) => {
+                        return Err(Error::InvalidTLSConfig(format!(
+                            "Invalid client certificate: {}",
+                            e
+                        )))
+                    }
+                    Ok(config) => config,
+                }
+            }
+        };
+        config.alpn_protocols = vec![b"h2".to_vec(), b"http/1.1".to_vec()];
+
+        if self.insecure_skip_verify {
+            config
+                .dangerous()
+                .set_certificate_verifier(Arc::new(NoVerifier));
+        }
+
+        Ok(config)
+    }
+}
@@ -1,45 +1,165 @@
-use std::time::{Duration, Instant};
+use async_trait::async_trait;
+use serde::Deserialize;
+use std::path::Path;
 
+use crate::env;
+use crate::internals;
+use crate::transport::Method;
 use crate::Auth as AuthTrait;
 use crate::Error;
+use crate::HttpClient;
+
+#[derive(Deserialize)]
+struct LookupData {
+    ttl: u64,
+    renewable: bool,
+    #[serde(default)]
+    policies: Vec<String>,
+    #[serde(default)]
+    creation_time: u64,
+    #[serde(default)]
+    explicit_max_ttl: u64,
+}
+
+#[derive(Deserialize)]
+struct LookupResponse {
+    data: LookupData,
+}
 
 /// The actual token-auth session that can be used to
 /// authenticate with vault
+///
+/// The TTL, max TTL, renewable status and policies of the token are loaded
+/// from vault using `auth/token/lookup-self` every time the session authenticates
 pub struct Session {
-    token: String,
-    token_start: Instant,
-    token_duration: Duration,
+    increment: Option<u64>,
+    policies: std::sync::RwLock<Vec<String>>,
+    token: internals::TokenContainer,
 }
 
+#[async_trait]
 impl AuthTrait for Session {
     fn is_expired(&self) -> bool {
-        self.token_start.elapsed() >= self.token_duration
+        // Tokens without a TTL, like root tokens, never expire
+        if self.token.get_duration() == 0 {
+            return false;
+        }
+
+        self.token.is_expired()
     }
     fn get_token(&self) -> String {
-        self.token.clone()
+        self.token.get_token()
     }
-    fn auth(&self, _vault_url: &str) -> Result<(), Error> {
+    async fn auth(&self, http: &HttpClient) -> Result<(), Error> {
+        let response = http
+            .send(
+                http.request(Method::GET, "auth/token/lookup-self")?
+                    .header("X-Vault-Token", self.get_token()),
+                None,
+            )
+            .await?;
+
+        let status_code = response.status().as_u16();
+        if status_code != 200 {
+            return Err(Error::from_response(Method::GET, response));
+        }
+
+        let data = match response.json::<LookupResponse>() {
+            Err(e) => return Err(Error::from(e)),
+            Ok(json) => json.data,
+        };
+
+        *self.policies.write().unwrap() = data.policies;
+
+        // The token itself never changes, so the lookup only has to update the
+        // remaining information
+        self.token.set_renew(
+            internals::AuthInfo {
+                client_token: self.get_token(),
+                lease_duration: data.ttl,
+                renewable: data.renewable,
+            },
+            None,
+        );
+        self.token.set_max_expire(match data.explicit_max_ttl {
+            0 => None,
+            max_ttl => Some(data.creation_time + max_ttl),
+        });
+
         Ok(())
     }
     fn is_renewable(&self) -> bool {
-        true
+        self.token.get_renewable()
     }
     fn get_total_duration(&self) -> u64 {
-        0
+        self.token.get_duration()
     }
-    fn renew(&self, _vault_url: &str) -> Result<(), Error> {
-        Ok(())
+    async fn renew(&self, http: &HttpClient) -> Result<(), Error> {
+        self.renew_with(http, None).await
+    }
+    async fn renew_with(&self, http: &HttpClient, increment: Option<u64>) -> Result<(), Error> {
+        internals::renew_token(http, &self.token, increment.or(self.increment)).await
+    }
+    fn get_max_ttl_remaining(&self) -> Option<u64> {
+        self.token.get_max_ttl_remaining()
     }
 }
 
 impl Session {
-    /// Used to obtain a new valid auth session that can
+    /// Used to obtain a new auth session for the given token that can
     /// be used with the vault client to authenticate
-    pub fn new(token: String, token_duration: Duration) -> Result<Session, Error> {
+    pub fn new(token: String) -> Result<Session, Error> {
+        Session::with_increment(token, None)
+    }
+
+    /// Used to obtain a new auth session for the given token, that requests
+    /// the given increment, in seconds, whenever the token is renewed.
+    /// Vault may still cap the new TTL based on the max TTL of the token
+    pub fn with_increment(token: String, increment: Option<u64>) -> Result<Session, Error> {
+        let container = internals::TokenContainer::new();
+        container.set_login(internals::AuthInfo {
+            client_token: token,
+            lease_duration: 0,
+            renewable: false,
+        });
+
         Ok(Session {
-            token,
-            token_start: Instant::now(),
-            token_duration,
+            increment,
+            policies: std::sync::RwLock::new(Vec::new()),
+            token: container,
         })
     }
+
+    /// Used to obtain a new auth session for the token configured in the
+    /// environment, like the vault CLI does. The token is loaded from the
+    /// `VAULT_TOKEN` environment variable or, if that is not set, from the
+    /// `~/.vault-token` file
+    pub fn from_env() -> Result<Session, Error> {
+        if let Some(token) = env::var("VAULT_TOKEN") {
+            return Session::new(token);
+        }
+
+        let home = match env::var("HOME").or_else(|| env::var("USERPROFILE")) {
+            Some(home) => home,
+            None => return Err(Error::MissingCredentials("VAULT_TOKEN")),
+        };
+        let token = match std::fs::read_to_string(Path::new(&home).join(".vault-token")) {
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                return Err(Error::MissingCredentials("VAULT_TOKEN"))
+            }
+            Err(e) => return Err(Error::from(e)),
+            Ok(token) => token.trim().to_string(),
+        };
+        if token.is_empty() {
+            return Err(Error::MissingCredentials("VAULT_TOKEN"));
+        }
+
+        Session::new(token)
+    }
+
+    /// Returns the policies attached to the token, as reported by vault
+    /// during the last lookup
+    pub fn policies(&self) -> Vec<String> {
+        self.policies.read().unwrap().clone()
+    }
 }
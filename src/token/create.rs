@@ -0,0 +1,171 @@
+use crate::transport::Method;
+use crate::Auth;
+use crate::AuthResponse;
+use crate::Client;
+use crate::Error;
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// The Type of a Token
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum TokenType {
+    /// A regular persisted Token that can be renewed, revoked and
+    /// create child tokens
+    Service,
+    /// A lightweight Token that is not persisted by vault and can not
+    /// be renewed or create child tokens
+    Batch,
+    /// Only valid for roles, creates service Tokens unless the client
+    /// requests a batch Token. This is the default of vault for roles
+    #[serde(rename = "default-service")]
+    DefaultService,
+    /// Only valid for roles, creates batch Tokens unless the client
+    /// requests a service Token
+    #[serde(rename = "default-batch")]
+    DefaultBatch,
+}
+
+/// The Options for creating a new Token, all options that are not set
+/// will use the defaults of vault
+#[derive(Serialize, Debug, Default)]
+pub struct TokenCreateRequest {
+    /// The display name of the Token
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+
+    /// The name of the entity alias to associate with the Token,
+    /// only usable together with a role
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entity_alias: Option<String>,
+
+    /// The hard upper limit for the lifetime of the Token, like `24h`,
+    /// the Token can not be renewed past this point
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub explicit_max_ttl: Option<String>,
+
+    /// Metadata that is attached to the Token and shown in the audit logs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub meta: Option<HashMap<String, String>>,
+
+    /// If set, the default policy will not be attached to the Token
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_default_policy: Option<bool>,
+
+    /// If set, the Token will not have a parent, this requires a root
+    /// or sudo Token
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_parent: Option<bool>,
+
+    /// The number of times the Token can be used, 0 means unlimited
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub num_uses: Option<u64>,
+
+    /// If set, the Token is a periodic Token that never expires as
+    /// long as it is renewed within the given period, like `1h`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub period: Option<String>,
+
+    /// The policies attached to the Token, these need to be a subset of
+    /// the policies of the calling Token unless it is a root Token
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub policies: Option<Vec<String>>,
+
+    /// Whether or not the Token can be renewed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub renewable: Option<bool>,
+
+    /// The initial TTL of the Token, like `1h`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ttl: Option<String>,
+
+    /// The Type of Token to create
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub token_type: Option<TokenType>,
+}
+
+/// The Auth-Information returned by vault when creating or renewing a Token
+#[derive(Deserialize, Debug, Default)]
+#[serde(default)]
+pub struct TokenAuth {
+    /// The actual Token
+    pub client_token: String,
+    /// The accessor of the Token, which can be used to manage the Token
+    /// without knowing the Token itself
+    pub accessor: String,
+    /// All the policies attached to the Token
+    pub policies: Vec<String>,
+    /// The policies that were attached to the Token directly
+    pub token_policies: Vec<String>,
+    /// The metadata attached to the Token
+    pub metadata: Option<HashMap<String, String>>,
+    /// The duration for which the Token is valid in seconds
+    pub lease_duration: u64,
+    /// Whether or not the Token can be renewed
+    pub renewable: bool,
+    /// The ID of the entity associated with the Token
+    pub entity_id: String,
+    /// The Type of the Token, either `service` or `batch`
+    pub token_type: String,
+    /// Whether or not the Token is an orphan
+    pub orphan: bool,
+}
+
+async fn send_create(
+    client: &Client<impl Auth>,
+    path: &str,
+    request: &TokenCreateRequest,
+) -> Result<TokenAuth, Error> {
+    let response = match client
+        .vault_request::<TokenCreateRequest>(Method::POST, path, Some(request))
+        .await
+    {
+        Err(e) => return Err(e),
+        Ok(r) => r,
+    };
+
+    let body = match response.json::<AuthResponse<TokenAuth>>() {
+        Err(e) => return Err(Error::from(e)),
+        Ok(body) => body,
+    };
+
+    client.http().report_warnings(path, &body.warnings);
+
+    Ok(body.auth)
+}
+
+/// Creates a new Token, which is a child of the Token used by the client
+/// unless `no_parent` is set
+///
+/// [Vault-Documentation](https://www.vaultproject.io/api-docs/auth/token#create-token)
+pub async fn create(
+    client: &Client<impl Auth>,
+    request: &TokenCreateRequest,
+) -> Result<TokenAuth, Error> {
+    send_create(client, "auth/token/create", request).await
+}
+
+/// Creates a new orphan Token, which has no parent and is therefore not
+/// revoked together with the Token used by the client
+///
+/// [Vault-Documentation](https://www.vaultproject.io/api-docs/auth/token#create-token)
+pub async fn create_orphan(
+    client: &Client<impl Auth>,
+    request: &TokenCreateRequest,
+) -> Result<TokenAuth, Error> {
+    send_create(client, "auth/token/create-orphan", request).await
+}
+
+/// Creates a new Token using the given Token-Role, the settings of the
+/// role take precedence over the given request
+///
+/// [Vault-Documentation](https://www.vaultproject.io/api-docs/auth/token#create-token)
+pub async fn create_with_role(
+    client: &Client<impl Auth>,
+    role: &str,
+    request: &TokenCreateRequest,
+) -> Result<TokenAuth, Error> {
+    let path = format!("auth/token/create/{}", role);
+    send_create(client, &path, request).await
+}
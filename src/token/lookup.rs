@@ -0,0 +1,95 @@
+use crate::transport::Method;
+use crate::Auth;
+use crate::Client;
+use crate::Error;
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// The Information about a Token as returned by a lookup
+#[derive(Deserialize, Debug, Default)]
+#[serde(default)]
+pub struct TokenInfo {
+    /// The accessor of the Token
+    pub accessor: String,
+    /// The unix timestamp at which the Token was created
+    pub creation_time: u64,
+    /// The TTL the Token was created with in seconds
+    pub creation_ttl: u64,
+    /// The display name of the Token
+    pub display_name: String,
+    /// The ID of the entity associated with the Token
+    pub entity_id: String,
+    /// The time at which the Token expires, if it expires at all
+    pub expire_time: Option<String>,
+    /// The hard upper limit for the lifetime of the Token in seconds
+    pub explicit_max_ttl: u64,
+    /// The Token itself, this is empty when looking up a Token by
+    /// its accessor
+    pub id: String,
+    /// The time at which the Token was issued
+    pub issue_time: Option<String>,
+    /// The metadata attached to the Token
+    pub meta: Option<HashMap<String, String>>,
+    /// The remaining number of uses, 0 means unlimited
+    pub num_uses: u64,
+    /// Whether or not the Token is an orphan
+    pub orphan: bool,
+    /// The path at which the Token was created
+    pub path: String,
+    /// The policies attached to the Token
+    pub policies: Vec<String>,
+    /// Whether or not the Token can be renewed
+    pub renewable: bool,
+    /// The remaining TTL of the Token in seconds
+    pub ttl: u64,
+    /// The Type of the Token, either `service` or `batch`
+    #[serde(rename = "type")]
+    pub token_type: String,
+}
+
+#[derive(Serialize)]
+struct TokenBody<'a> {
+    token: &'a str,
+}
+
+#[derive(Serialize)]
+struct AccessorBody<'a> {
+    accessor: &'a str,
+}
+
+async fn send_lookup<B: Serialize>(
+    client: &Client<impl Auth>,
+    path: &str,
+    body: &B,
+) -> Result<TokenInfo, Error> {
+    match client
+        .request::<B, TokenInfo>(Method::POST, path, Some(body))
+        .await
+    {
+        Err(e) => Err(e),
+        Ok(body) => Ok(body.data),
+    }
+}
+
+/// Looks up the Information about the given Token
+///
+/// [Vault-Documentation](https://www.vaultproject.io/api-docs/auth/token#lookup-a-token)
+pub async fn lookup(client: &Client<impl Auth>, token: &str) -> Result<TokenInfo, Error> {
+    send_lookup(client, "auth/token/lookup", &TokenBody { token }).await
+}
+
+/// Looks up the Information about the Token with the given accessor
+///
+/// [Vault-Documentation](https://www.vaultproject.io/api-docs/auth/token#lookup-a-token-accessor)
+pub async fn lookup_accessor(
+    client: &Client<impl Auth>,
+    accessor: &str,
+) -> Result<TokenInfo, Error> {
+    send_lookup(
+        client,
+        "auth/token/lookup-accessor",
+        &AccessorBody { accessor },
+    )
+    .await
+}
@@ -1,3 +1,13 @@
 mod auth;
+mod create;
+mod lookup;
+mod renew;
+mod revoke;
+mod roles;
 
 pub use auth::*;
+pub use create::*;
+pub use lookup::*;
+pub use renew::*;
+pub use revoke::*;
+pub use roles::*;
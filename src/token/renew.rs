@@ -0,0 +1,75 @@
+use super::TokenAuth;
+use crate::transport::Method;
+use crate::Auth;
+use crate::AuthResponse;
+use crate::Client;
+use crate::Error;
+
+use serde::Serialize;
+
+#[derive(Serialize)]
+struct RenewTokenBody<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    increment: Option<u64>,
+    token: &'a str,
+}
+
+#[derive(Serialize)]
+struct RenewAccessorBody<'a> {
+    accessor: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    increment: Option<u64>,
+}
+
+async fn send_renew<B: Serialize>(
+    client: &Client<impl Auth>,
+    path: &str,
+    body: &B,
+) -> Result<TokenAuth, Error> {
+    let response = match client
+        .vault_request::<B>(Method::POST, path, Some(body))
+        .await
+    {
+        Err(e) => return Err(e),
+        Ok(r) => r,
+    };
+
+    let body = match response.json::<AuthResponse<TokenAuth>>() {
+        Err(e) => return Err(Error::from(e)),
+        Ok(body) => body,
+    };
+
+    client.http().report_warnings(path, &body.warnings);
+
+    Ok(body.auth)
+}
+
+/// Renews the given Token, optionally requesting the given increment
+/// in seconds
+///
+/// [Vault-Documentation](https://www.vaultproject.io/api-docs/auth/token#renew-a-token)
+pub async fn renew(
+    client: &Client<impl Auth>,
+    token: &str,
+    increment: Option<u64>,
+) -> Result<TokenAuth, Error> {
+    let body = RenewTokenBody { increment, token };
+    send_renew(client, "auth/token/renew", &body).await
+}
+
+/// Renews the Token with the given accessor, optionally requesting the
+/// given increment in seconds. The returned `client_token` is empty, as
+/// vault does not reveal the Token itself
+///
+/// [Vault-Documentation](https://www.vaultproject.io/api-docs/auth/token#renew-a-token-accessor)
+pub async fn renew_accessor(
+    client: &Client<impl Auth>,
+    accessor: &str,
+    increment: Option<u64>,
+) -> Result<TokenAuth, Error> {
+    let body = RenewAccessorBody {
+        accessor,
+        increment,
+    };
+    send_renew(client, "auth/token/renew-accessor", &body).await
+}
@@ -0,0 +1,68 @@
+use crate::transport::Method;
+use crate::Auth;
+use crate::Client;
+use crate::Error;
+
+use serde::Serialize;
+
+#[derive(Serialize)]
+struct TokenBody<'a> {
+    token: &'a str,
+}
+
+#[derive(Serialize)]
+struct AccessorBody<'a> {
+    accessor: &'a str,
+}
+
+/// Revokes the given Token and all of its children
+///
+/// [Vault-Documentation](https://www.vaultproject.io/api-docs/auth/token#revoke-a-token)
+pub async fn revoke(client: &Client<impl Auth>, token: &str) -> Result<(), Error> {
+    match client
+        .vault_request(
+            Method::POST,
+            "auth/token/revoke",
+            Some(&TokenBody { token }),
+        )
+        .await
+    {
+        Err(e) => Err(e),
+        Ok(_) => Ok(()),
+    }
+}
+
+/// Revokes the given Token but not its children, which become orphans
+/// instead. This requires a root or sudo Token
+///
+/// [Vault-Documentation](https://www.vaultproject.io/api-docs/auth/token#revoke-token-and-orphan-children)
+pub async fn revoke_orphan(client: &Client<impl Auth>, token: &str) -> Result<(), Error> {
+    match client
+        .vault_request(
+            Method::POST,
+            "auth/token/revoke-orphan",
+            Some(&TokenBody { token }),
+        )
+        .await
+    {
+        Err(e) => Err(e),
+        Ok(_) => Ok(()),
+    }
+}
+
+/// Revokes the Token with the given accessor and all of its children
+///
+/// [Vault-Documentation](https://www.vaultproject.io/api-docs/auth/token#revoke-a-token-accessor)
+pub async fn revoke_accessor(client: &Client<impl Auth>, accessor: &str) -> Result<(), Error> {
+    match client
+        .vault_request(
+            Method::POST,
+            "auth/token/revoke-accessor",
+            Some(&AccessorBody { accessor }),
+        )
+        .await
+    {
+        Err(e) => Err(e),
+        Ok(_) => Ok(()),
+    }
+}
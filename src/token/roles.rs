@@ -0,0 +1,105 @@
+use super::TokenType;
+use crate::Auth;
+use crate::Client;
+use crate::Error;
+
+use serde::de::IgnoredAny;
+use serde::{Deserialize, Serialize};
+
+/// A Token-Role, which can be used to create Tokens with a predefined
+/// set of options. All TTLs are in seconds
+#[derive(Serialize, Deserialize, Debug, Default, PartialEq)]
+#[serde(default)]
+pub struct TokenRole {
+    /// The policies that can be attached to Tokens created with this role
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_policies: Option<Vec<String>>,
+
+    /// The policies that can never be attached to Tokens created with
+    /// this role
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disallowed_policies: Option<Vec<String>>,
+
+    /// Whether or not Tokens created with this role are orphans
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub orphan: Option<bool>,
+
+    /// A suffix appended to the path at which Tokens are created
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path_suffix: Option<String>,
+
+    /// Whether or not Tokens created with this role can be renewed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub renewable: Option<bool>,
+
+    /// The hard upper limit for the lifetime of the Tokens
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_explicit_max_ttl: Option<u64>,
+
+    /// The maximum TTL of the Tokens
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_max_ttl: Option<u64>,
+
+    /// The number of times Tokens can be used, 0 means unlimited
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_num_uses: Option<u64>,
+
+    /// If set, Tokens are periodic Tokens with the given period
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_period: Option<u64>,
+
+    /// The initial TTL of the Tokens
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_ttl: Option<u64>,
+
+    /// The Type of Tokens created with this role
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_type: Option<TokenType>,
+}
+
+/// Creates or updates the Token-Role with the given name
+///
+/// [Vault-Documentation](https://www.vaultproject.io/api-docs/auth/token#create-update-token-role)
+pub async fn write_role(
+    client: &Client<impl Auth>,
+    name: &str,
+    role: &TokenRole,
+) -> Result<(), Error> {
+    let path = format!("auth/token/roles/{}", name);
+
+    match client.write::<TokenRole, IgnoredAny>(&path, role).await {
+        Err(e) => Err(e),
+        Ok(_) => Ok(()),
+    }
+}
+
+/// Reads the Token-Role with the given name
+///
+/// [Vault-Documentation](https://www.vaultproject.io/api-docs/auth/token#read-token-role)
+pub async fn read_role(client: &Client<impl Auth>, name: &str) -> Result<TokenRole, Error> {
+    let path = format!("auth/token/roles/{}", name);
+
+    match client.read::<TokenRole>(&path).await {
+        Err(e) => Err(e),
+        Ok(body) => Ok(body.data),
+    }
+}
+
+/// Lists the names of all the Token-Roles
+///
+/// [Vault-Documentation](https://www.vaultproject.io/api-docs/auth/token#list-token-roles)
+pub async fn list_roles(client: &Client<impl Auth>) -> Result<Vec<String>, Error> {
+    match client.list("auth/token/roles").await {
+        Err(e) => Err(e),
+        Ok(body) => Ok(body.data.keys),
+    }
+}
+
+/// Deletes the Token-Role with the given name
+///
+/// [Vault-Documentation](https://www.vaultproject.io/api-docs/auth/token#delete-token-role)
+pub async fn delete_role(client: &Client<impl Auth>, name: &str) -> Result<(), Error> {
+    let path = format!("auth/token/roles/{}", name);
+
+    client.delete(&path).await
+}
@@ -0,0 +1,49 @@
+use std::sync::Arc;
+
+use super::{Request, Response, Transport, TransportError};
+use crate::ClientCert;
+use crate::Error;
+
+type Handler = dyn Fn(Request) -> Result<Response, TransportError> + Send + Sync;
+
+/// A Transport that does not make any network requests, but passes every
+/// request to the given handler instead. This is meant to be used in tests,
+/// to run code against a fake vault instance
+///
+/// # Example:
+/// ```rust
+/// use hc_vault::transport::{MemoryTransport, Response, StatusCode};
+///
+/// let transport = MemoryTransport::new(|req| {
+///     Ok(Response::new(StatusCode::NOT_FOUND, ""))
+/// });
+/// ```
+#[derive(Clone)]
+pub struct MemoryTransport {
+    handler: Arc<Handler>,
+}
+
+impl MemoryTransport {
+    /// Creates a new MemoryTransport, that responds to every request with
+    /// the result of the given handler
+    pub fn new<F>(handler: F) -> MemoryTransport
+    where
+        F: Fn(Request) -> Result<Response, TransportError> + Send + Sync + 'static,
+    {
+        MemoryTransport {
+            handler: Arc::new(handler),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Transport for MemoryTransport {
+    async fn send(&self, req: Request) -> Result<Response, TransportError> {
+        (self.handler)(req)
+    }
+
+    /// Client certificates are ignored, so the same handler is used
+    fn with_identity(&self, _identity: &ClientCert) -> Result<Arc<dyn Transport>, Error> {
+        Ok(Arc::new(self.clone()))
+    }
+}
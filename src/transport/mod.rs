@@ -0,0 +1,231 @@
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+use url::Url;
+
+use crate::ClientCert;
+use crate::Config;
+use crate::Error;
+
+mod memory;
+#[cfg(feature = "reqwest")]
+mod reqwest_transport;
+#[cfg(any(feature = "reqwest", feature = "ureq"))]
+mod server_name;
+#[cfg(feature = "ureq")]
+mod ureq_transport;
+
+pub use memory::MemoryTransport;
+#[cfg(feature = "reqwest")]
+pub use reqwest_transport::ReqwestTransport;
+#[cfg(feature = "ureq")]
+pub use ureq_transport::UreqTransport;
+
+pub use ::http::{Method, StatusCode};
+
+/// The Transport is used to actually send the requests to vault, all the
+/// retries, deadlines and error handling are done by the client on top of it
+///
+/// The crate provides the [`ReqwestTransport`] and [`UreqTransport`], behind
+/// the features of the same name, as well as the [`MemoryTransport`] to
+/// test code without a vault instance
+#[async_trait::async_trait]
+pub trait Transport: Send + Sync {
+    /// Sends the given request and returns the response, regardless of its
+    /// status. An Error is only returned if no response was received
+    async fn send(&self, req: Request) -> Result<Response, TransportError>;
+
+    /// Creates a Transport with the same settings, that presents the given
+    /// client certificate instead of the configured one, this is used by the
+    /// TLS-Certificate Auth-Backend
+    ///
+    /// By default client certificates are not supported
+    fn with_identity(&self, identity: &ClientCert) -> Result<Arc<dyn Transport>, Error> {
+        let _ = identity;
+        Err(Error::InvalidTLSConfig(
+            "The transport does not support client certificates".to_string(),
+        ))
+    }
+}
+
+/// Creates the Transport of the enabled feature, using the connection and
+/// TLS settings of the given config
+#[allow(unreachable_code)]
+pub(crate) fn default_transport(config: &Config) -> Result<Arc<dyn Transport>, Error> {
+    let _ = config;
+
+    #[cfg(feature = "reqwest")]
+    return Ok(Arc::new(ReqwestTransport::new(config)?));
+    #[cfg(feature = "ureq")]
+    return Ok(Arc::new(UreqTransport::new(config)?));
+
+    Err(Error::MissingTransport)
+}
+
+/// A single request to vault
+#[derive(Debug, Clone)]
+pub struct Request {
+    /// The HTTP-Method of the request
+    pub method: Method,
+    /// The full URL of the request, including the query
+    pub url: Url,
+    /// The headers of the request
+    pub headers: Vec<(String, String)>,
+    /// The body of the request, if any
+    pub body: Option<Vec<u8>>,
+    /// How long the request may take in total, this overrides the timeout
+    /// of the Transport
+    pub timeout: Option<Duration>,
+}
+
+impl Request {
+    /// Creates a new request without any headers or body
+    pub fn new(method: Method, url: Url) -> Request {
+        Request {
+            method,
+            url,
+            headers: Vec::new(),
+            body: None,
+            timeout: None,
+        }
+    }
+
+    /// Adds the given header to the request
+    pub fn header<V: AsRef<str>>(mut self, name: &str, value: V) -> Request {
+        self.headers
+            .push((name.to_string(), value.as_ref().to_string()));
+        self
+    }
+
+    /// Sets the body of the request to the given value, serialized as JSON
+    pub fn json<T: Serialize + ?Sized>(mut self, body: &T) -> Result<Request, Error> {
+        self.body = Some(serde_json::to_vec(body)?);
+        Ok(self.header("Content-Type", "application/json"))
+    }
+
+    /// Sets the body of the request to the given value, serialized as a
+    /// JSON merge patch, which vault expects for PATCH requests
+    pub fn merge_patch<T: Serialize + ?Sized>(mut self, body: &T) -> Result<Request, Error> {
+        self.body = Some(serde_json::to_vec(body)?);
+        Ok(self.header("Content-Type", "application/merge-patch+json"))
+    }
+
+    /// Returns the value of the given header, the name is compared case
+    /// insensitive
+    pub fn get_header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// The response to a request, the body is always fully loaded
+#[derive(Debug, Clone)]
+pub struct Response {
+    status: StatusCode,
+    body: Vec<u8>,
+    path: String,
+}
+
+impl Response {
+    /// Creates a new response with the given status and body
+    pub fn new<B: Into<Vec<u8>>>(status: StatusCode, body: B) -> Response {
+        Response {
+            status,
+            body: body.into(),
+            path: String::new(),
+        }
+    }
+
+    /// Creates a new response with the given status and the given value,
+    /// serialized as JSON, as its body
+    pub fn from_json<T: Serialize + ?Sized>(status: StatusCode, body: &T) -> Response {
+        Response::new(status, serde_json::to_vec(body).unwrap_or_default())
+    }
+
+    /// The HTTP-Status of the response
+    pub fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    /// The raw body of the response
+    pub fn body(&self) -> &[u8] {
+        &self.body
+    }
+
+    /// The body of the response as text, invalid UTF-8 is replaced
+    pub fn text(&self) -> String {
+        String::from_utf8_lossy(&self.body).into_owned()
+    }
+
+    /// Deserializes the JSON body of the response
+    pub fn json<T: DeserializeOwned>(&self) -> Result<T, serde_json::Error> {
+        serde_json::from_slice(&self.body)
+    }
+
+    /// The path of the request this response belongs to, like
+    /// `/v1/auth/token/lookup-self`
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    pub(crate) fn with_path(mut self, path: &str) -> Response {
+        self.path = path.to_string();
+        self
+    }
+}
+
+/// The kind of a TransportError, which decides whether or not the request
+/// is retried
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransportErrorKind {
+    /// No connection to vault could be established
+    Connect,
+    /// Vault did not respond in time
+    Timeout,
+    /// The request failed while it was sent or while the response was read
+    Request,
+    /// Any other error, like an invalid request, these are not retried
+    Other,
+}
+
+/// The Error returned by a Transport, when no response was received
+#[derive(Debug)]
+pub struct TransportError {
+    kind: TransportErrorKind,
+    source: Box<dyn std::error::Error + Send + Sync>,
+}
+
+impl TransportError {
+    /// Creates a new TransportError of the given kind, caused by the given
+    /// error
+    pub fn new<E>(kind: TransportErrorKind, source: E) -> TransportError
+    where
+        E: Into<Box<dyn std::error::Error + Send + Sync>>,
+    {
+        TransportError {
+            kind,
+            source: source.into(),
+        }
+    }
+
+    /// The kind of the error
+    pub fn kind(&self) -> TransportErrorKind {
+        self.kind
+    }
+}
+
+impl fmt::Display for TransportError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.source)
+    }
+}
+
+impl std::error::Error for TransportError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(self.source.as_ref())
+    }
+}
@@ -0,0 +1,125 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use super::server_name::ServerNameOverride;
+use super::{Request, Response, Transport, TransportError, TransportErrorKind};
+use crate::ClientCert;
+use crate::Config;
+use crate::Error;
+use crate::TlsConfig;
+
+/// The settings needed to create further clients for the same vault instance
+#[derive(Clone)]
+struct Settings {
+    pool_max_idle_per_host: usize,
+    pool_idle_timeout: Option<Duration>,
+    tcp_keepalive: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    timeout: Option<Duration>,
+    tls: TlsConfig,
+    server_name: Option<ServerNameOverride>,
+}
+
+impl Settings {
+    fn build(&self, identity: Option<&ClientCert>) -> Result<reqwest::Client, Error> {
+        let mut builder = reqwest::Client::builder()
+            .pool_max_idle_per_host(self.pool_max_idle_per_host)
+            .pool_idle_timeout(self.pool_idle_timeout)
+            .tcp_keepalive(self.tcp_keepalive)
+            .use_preconfigured_tls(self.tls.client_config(identity)?);
+        if let Some(connect_timeout) = self.connect_timeout {
+            builder = builder.connect_timeout(connect_timeout);
+        }
+        if let Some(timeout) = self.timeout {
+            builder = builder.timeout(timeout);
+        }
+        if let Some(ref server_name) = self.server_name {
+            builder = builder.resolve_to_addrs(&server_name.server_name, &server_name.addrs);
+        }
+
+        match builder.build() {
+            Err(e) => Err(Error::from(transport_error(e))),
+            Ok(c) => Ok(c),
+        }
+    }
+}
+
+/// The Transport based on reqwest, which needs to be used from within a
+/// Tokio runtime. Connections are pooled and reused for all requests
+///
+/// Needs the `reqwest` feature, which is enabled by default
+#[derive(Clone)]
+pub struct ReqwestTransport {
+    settings: Settings,
+    client: reqwest::Client,
+}
+
+impl ReqwestTransport {
+    /// Creates the Transport using the connection and TLS settings of the
+    /// given config
+    pub fn new(config: &Config) -> Result<ReqwestTransport, Error> {
+        let settings = Settings {
+            pool_max_idle_per_host: config.pool_max_idle_per_host,
+            pool_idle_timeout: config.pool_idle_timeout,
+            tcp_keepalive: config.tcp_keepalive,
+            connect_timeout: config.connect_timeout,
+            timeout: config.timeout,
+            tls: config.tls.clone(),
+            server_name: ServerNameOverride::new(config)?,
+        };
+
+        let client = settings.build(None)?;
+
+        Ok(ReqwestTransport { settings, client })
+    }
+}
+
+/// Maps the error to its kind, so that only errors that happened while
+/// talking to vault are retried
+fn transport_error(cause: reqwest::Error) -> TransportError {
+    let kind = if cause.is_timeout() {
+        TransportErrorKind::Timeout
+    } else if cause.is_connect() {
+        TransportErrorKind::Connect
+    } else if cause.is_request() || cause.is_body() {
+        TransportErrorKind::Request
+    } else {
+        TransportErrorKind::Other
+    };
+
+    TransportError::new(kind, cause)
+}
+
+#[async_trait::async_trait]
+impl Transport for ReqwestTransport {
+    async fn send(&self, req: Request) -> Result<Response, TransportError> {
+        let req = match self.settings.server_name {
+            Some(ref server_name) => server_name.apply(req),
+            None => req,
+        };
+
+        let mut builder = self.client.request(req.method, req.url);
+        for (name, value) in req.headers.iter() {
+            builder = builder.header(name.as_str(), value.as_str());
+        }
+        if let Some(body) = req.body {
+            builder = builder.body(body);
+        }
+        if let Some(timeout) = req.timeout {
+            builder = builder.timeout(timeout);
+        }
+
+        let response = builder.send().await.map_err(transport_error)?;
+        let status = response.status();
+        let body = response.bytes().await.map_err(transport_error)?;
+
+        Ok(Response::new(status, body.to_vec()))
+    }
+
+    fn with_identity(&self, identity: &ClientCert) -> Result<Arc<dyn Transport>, Error> {
+        Ok(Arc::new(ReqwestTransport {
+            settings: self.settings.clone(),
+            client: self.settings.build(Some(identity))?,
+        }))
+    }
+}
@@ -0,0 +1,84 @@
+use std::net::SocketAddr;
+use url::Url;
+
+use super::Request;
+use crate::Config;
+use crate::Error;
+
+/// Sends the requests using the TLS server name of the config, by replacing
+/// the host of every request with it, while the connection is still made to
+/// the addresses of the host of the vault url
+#[derive(Clone)]
+pub(crate) struct ServerNameOverride {
+    /// The name that replaces the host of the requests
+    pub server_name: String,
+    /// The host of the vault url, including its port, which is sent as the
+    /// Host header
+    host: String,
+    /// The addresses the host of the vault url resolved to
+    pub addrs: Vec<SocketAddr>,
+}
+
+impl ServerNameOverride {
+    /// Creates the override for the given config, None if no server name is
+    /// configured
+    pub fn new(config: &Config) -> Result<Option<ServerNameOverride>, Error> {
+        let server_name = match config.tls.server_name {
+            None => return Ok(None),
+            Some(ref name) => name,
+        };
+
+        let mut url = Url::parse(&config.vault_url)?;
+        let host = match (url.host_str(), url.port()) {
+            (None, _) => return Err(Error::ParseError(url::ParseError::EmptyHost)),
+            (Some(host), None) => host.to_string(),
+            (Some(host), Some(port)) => format!("{}:{}", host, port),
+        };
+        let addrs = url.socket_addrs(|| None)?;
+
+        // Makes sure the server name can be used as the host of the requests
+        if url.set_host(Some(server_name)).is_err() {
+            return Err(Error::InvalidTLSConfig(format!(
+                "Invalid server name: {}",
+                server_name
+            )));
+        }
+
+        Ok(Some(ServerNameOverride {
+            server_name: server_name.clone(),
+            host,
+            addrs,
+        }))
+    }
+
+    /// Replaces the host of the request with the server name, the original
+    /// host is kept as the Host header
+    pub fn apply(&self, mut req: Request) -> Request {
+        // The server name was already checked to be a valid host
+        let _ = req.url.set_host(Some(&self.server_name));
+        req.header("Host", &self.host)
+    }
+
+    /// Resolves the given `host:port`, the server name resolves to the
+    /// addresses of the host of the vault url
+    #[cfg(feature = "ureq")]
+    pub fn resolve(&self, netloc: &str) -> std::io::Result<Vec<SocketAddr>> {
+        let port = match netloc.rsplit_once(':') {
+            Some((host, port)) if host == self.server_name => port,
+            _ => {
+                return std::net::ToSocketAddrs::to_socket_addrs(netloc)
+                    .map(|addrs| addrs.collect())
+            }
+        };
+
+        let port = match port.parse::<u16>() {
+            Err(e) => return Err(std::io::Error::new(std::io::ErrorKind::InvalidInput, e)),
+            Ok(port) => port,
+        };
+        Ok(self
+            .addrs
+            .iter()
+            .map(|addr| SocketAddr::new(addr.ip(), port))
+            .collect())
+    }
+}
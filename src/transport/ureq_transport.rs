@@ -0,0 +1,172 @@
+use std::io::Read;
+use std::sync::Arc;
+use std::time::Duration;
+
+use super::server_name::ServerNameOverride;
+use super::{Request, Response, StatusCode, Transport, TransportError, TransportErrorKind};
+use crate::ClientCert;
+use crate::Config;
+use crate::Error;
+use crate::TlsConfig;
+
+/// The settings needed to create further agents for the same vault instance
+#[derive(Clone)]
+struct Settings {
+    pool_max_idle_per_host: usize,
+    connect_timeout: Option<Duration>,
+    timeout: Option<Duration>,
+    tls: TlsConfig,
+    server_name: Option<ServerNameOverride>,
+}
+
+impl Settings {
+    fn build(&self, identity: Option<&ClientCert>) -> Result<ureq::Agent, Error> {
+        let mut tls = self.tls.client_config(identity)?;
+        // ureq only speaks HTTP/1.1
+        tls.alpn_protocols = vec![b"http/1.1".to_vec()];
+
+        let mut builder = ureq::AgentBuilder::new()
+            .max_idle_connections_per_host(self.pool_max_idle_per_host)
+            .tls_config(Arc::new(tls));
+        if let Some(connect_timeout) = self.connect_timeout {
+            builder = builder.timeout_connect(connect_timeout);
+        }
+        if let Some(timeout) = self.timeout {
+            builder = builder.timeout(timeout);
+        }
+        if let Some(ref server_name) = self.server_name {
+            let server_name = server_name.clone();
+            builder = builder.resolver(move |netloc: &str| server_name.resolve(netloc));
+        }
+
+        Ok(builder.build())
+    }
+}
+
+/// The Transport based on ureq, which does not depend on any async runtime.
+/// Every request is sent on its own thread, so this can be used with any
+/// executor, but is less efficient than the [`ReqwestTransport`](super::ReqwestTransport)
+/// under load. The `pool_idle_timeout` and `tcp_keepalive` settings of the
+/// config are not supported
+///
+/// Needs the `ureq` feature
+#[derive(Clone)]
+pub struct UreqTransport {
+    settings: Settings,
+    agent: ureq::Agent,
+}
+
+impl UreqTransport {
+    /// Creates the Transport using the connection and TLS settings of the
+    /// given config
+    pub fn new(config: &Config) -> Result<UreqTransport, Error> {
+        let settings = Settings {
+            pool_max_idle_per_host: config.pool_max_idle_per_host,
+            connect_timeout: config.connect_timeout,
+            timeout: config.timeout,
+            tls: config.tls.clone(),
+            server_name: ServerNameOverride::new(config)?,
+        };
+
+        let agent = settings.build(None)?;
+
+        Ok(UreqTransport { settings, agent })
+    }
+}
+
+/// Maps the io Error to its kind, ureq reports timeouts as io Errors
+fn io_error(cause: std::io::Error) -> TransportError {
+    let kind = match cause.kind() {
+        std::io::ErrorKind::TimedOut | std::io::ErrorKind::WouldBlock => {
+            TransportErrorKind::Timeout
+        }
+        _ => TransportErrorKind::Request,
+    };
+
+    TransportError::new(kind, cause)
+}
+
+/// Maps the error to its kind, so that only errors that happened while
+/// talking to vault are retried
+fn transport_error(cause: ureq::Transport) -> TransportError {
+    let kind = match cause.kind() {
+        ureq::ErrorKind::Dns | ureq::ErrorKind::ConnectionFailed => TransportErrorKind::Connect,
+        ureq::ErrorKind::Io => {
+            let timed_out = std::error::Error::source(&cause)
+                .and_then(|source| source.downcast_ref::<std::io::Error>())
+                .map(|e| {
+                    e.kind() == std::io::ErrorKind::TimedOut
+                        || e.kind() == std::io::ErrorKind::WouldBlock
+                })
+                .unwrap_or(false);
+            match timed_out {
+                true => TransportErrorKind::Timeout,
+                false => TransportErrorKind::Request,
+            }
+        }
+        _ => TransportErrorKind::Other,
+    };
+
+    TransportError::new(kind, cause)
+}
+
+fn send_blocking(agent: &ureq::Agent, req: Request) -> Result<Response, TransportError> {
+    let mut request = agent.request_url(req.method.as_str(), &req.url);
+    for (name, value) in req.headers.iter() {
+        request = request.set(name, value);
+    }
+    if let Some(timeout) = req.timeout {
+        request = request.timeout(timeout);
+    }
+
+    let result = match req.body {
+        Some(ref body) => request.send_bytes(body),
+        None => request.call(),
+    };
+    let response = match result {
+        Ok(response) => response,
+        // Responses with an error status are handled by the client
+        Err(ureq::Error::Status(_, response)) => response,
+        Err(ureq::Error::Transport(e)) => return Err(transport_error(e)),
+    };
+
+    let status = match StatusCode::from_u16(response.status()) {
+        Err(e) => return Err(TransportError::new(TransportErrorKind::Other, e)),
+        Ok(status) => status,
+    };
+    let mut body = Vec::new();
+    if let Err(e) = response.into_reader().read_to_end(&mut body) {
+        return Err(io_error(e));
+    }
+
+    Ok(Response::new(status, body))
+}
+
+#[async_trait::async_trait]
+impl Transport for UreqTransport {
+    async fn send(&self, req: Request) -> Result<Response, TransportError> {
+        let req = match self.settings.server_name {
+            Some(ref server_name) => server_name.apply(req),
+            None => req,
+        };
+
+        let agent = self.agent.clone();
+        let (sender, receiver) = tokio::sync::oneshot::channel();
+        std::thread::spawn(move || {
+            // The receiver is only gone if the request was cancelled
+            let _ = sender.send(send_blocking(&agent, req));
+        });
+
+        match receiver.await {
+            Err(e) => Err(TransportError::new(TransportErrorKind::Other, e)),
+            Ok(result) => result,
+        }
+    }
+
+    fn with_identity(&self, identity: &ClientCert) -> Result<Arc<dyn Transport>, Error> {
+        Ok(Arc::new(UreqTransport {
+            settings: self.settings.clone(),
+            agent: self.settings.build(Some(identity))?,
+        }))
+    }
+}
@@ -1,8 +1,6 @@
 extern crate hc_vault;
 
-use async_std::task;
-
-use wiremock::matchers::{body_json, method, path};
+use wiremock::matchers::{body_json, header, method, path};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
 use serde::Serialize;
@@ -27,9 +25,9 @@ struct ApproleResponse {
     lease_id: String,
 }
 
-#[test]
-fn valid_new_approle() {
-    let mock_server = task::block_on(MockServer::start());
+#[tokio::test]
+async fn valid_new_approle() {
+    let mock_server = MockServer::start().await;
 
     let test_role_id = "testID".to_string();
     let test_secret_id = "testSecret".to_string();
@@ -54,13 +52,12 @@ fn valid_new_approle() {
     let mut response = ResponseTemplate::new(200);
     response = response.set_body_json(response_body);
 
-    task::block_on(
-        Mock::given(method("POST"))
-            .and(path("/v1/auth/approle/login"))
-            .and(body_json(&expected_body))
-            .respond_with(response)
-            .mount(&mock_server),
-    );
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/approle/login"))
+        .and(body_json(&expected_body))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
 
     let tmp_auth = match hc_vault::approle::Session::new(test_role_id, test_secret_id) {
         Err(e) => {
@@ -70,15 +67,18 @@ fn valid_new_approle() {
         Ok(s) => s,
     };
 
-    match tmp_auth.auth(&mock_server.uri()) {
+    match tmp_auth
+        .auth(&hc_vault::HttpClient::from_url(&mock_server.uri()).unwrap())
+        .await
+    {
         Err(e) => assert!(false, "Should not return error: '{}'", e),
         Ok(_) => assert!(true),
     };
 }
 
-#[test]
-fn invalid_new_approle_not_found() {
-    let mock_server = task::block_on(MockServer::start());
+#[tokio::test]
+async fn invalid_new_approle_not_found() {
+    let mock_server = MockServer::start().await;
 
     let test_role_id = "testID".to_string();
     let test_secret_id = "testSecret".to_string();
@@ -90,13 +90,12 @@ fn invalid_new_approle_not_found() {
 
     let response = ResponseTemplate::new(404);
 
-    task::block_on(
-        Mock::given(method("POST"))
-            .and(path("/v1/auth/approle/login"))
-            .and(body_json(&expected_body))
-            .respond_with(response)
-            .mount(&mock_server),
-    );
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/approle/login"))
+        .and(body_json(&expected_body))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
 
     let tmp_auth = match hc_vault::approle::Session::new(test_role_id, test_secret_id) {
         Err(e) => {
@@ -106,15 +105,18 @@ fn invalid_new_approle_not_found() {
         Ok(s) => s,
     };
 
-    match tmp_auth.auth(&mock_server.uri()) {
+    match tmp_auth
+        .auth(&hc_vault::HttpClient::from_url(&mock_server.uri()).unwrap())
+        .await
+    {
         Err(_) => assert!(true),
         Ok(_) => assert!(false, "Should return error"),
     };
 }
 
-#[test]
-fn invalid_new_approle_not_valid_403() {
-    let mock_server = task::block_on(MockServer::start());
+#[tokio::test]
+async fn invalid_new_approle_not_valid_403() {
+    let mock_server = MockServer::start().await;
 
     let test_role_id = "testID".to_string();
     let test_secret_id = "testSecret".to_string();
@@ -126,13 +128,12 @@ fn invalid_new_approle_not_valid_403() {
 
     let response = ResponseTemplate::new(403);
 
-    task::block_on(
-        Mock::given(method("POST"))
-            .and(path("/v1/auth/approle/login"))
-            .and(body_json(&expected_body))
-            .respond_with(response)
-            .mount(&mock_server),
-    );
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/approle/login"))
+        .and(body_json(&expected_body))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
 
     let tmp_auth = match hc_vault::approle::Session::new(test_role_id, test_secret_id) {
         Err(e) => {
@@ -142,8 +143,289 @@ fn invalid_new_approle_not_valid_403() {
         Ok(s) => s,
     };
 
-    match tmp_auth.auth(&mock_server.uri()) {
+    match tmp_auth
+        .auth(&hc_vault::HttpClient::from_url(&mock_server.uri()).unwrap())
+        .await
+    {
         Err(_) => assert!(true),
         Ok(_) => assert!(false, "Should return error"),
     };
 }
+
+fn login_response() -> ResponseTemplate {
+    ResponseTemplate::new(200).set_body_json(ApproleResponse {
+        auth: ApproleAuthResponse {
+            renewable: true,
+            lease_duration: 120,
+            token_policies: vec!["test".to_string()],
+            accessor: "testAccessor".to_string(),
+            client_token: "testToken".to_string(),
+        },
+        lease_duration: 0,
+        renewable: false,
+        lease_id: "".to_string(),
+    })
+}
+
+#[tokio::test]
+async fn valid_wrapped_secret_id() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/sys/wrapping/lookup"))
+        .and(body_json(json!({
+            "token": "wrappingToken",
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": {
+                "creation_path": "auth/approle/role/deploy/secret-id",
+                "creation_time": "2021-01-01T00:00:00Z",
+                "creation_ttl": 60,
+            },
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/sys/wrapping/unwrap"))
+        .and(header("X-Vault-Token", "wrappingToken"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": {
+                "secret_id": "unwrappedSecret",
+                "secret_id_accessor": "testAccessor",
+            },
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/approle/login"))
+        .and(body_json(json!({
+            "role_id": "testID",
+            "secret_id": "unwrappedSecret",
+        })))
+        .respond_with(login_response())
+        .expect(2)
+        .mount(&mock_server)
+        .await;
+
+    let tmp_auth = match hc_vault::approle::Session::new_wrapped(
+        "testID".to_string(),
+        "deploy".to_string(),
+        "wrappingToken".to_string(),
+    ) {
+        Err(e) => {
+            assert!(false, "Should not return error: '{}'", e);
+            return;
+        }
+        Ok(s) => s,
+    };
+
+    // The second login has to reuse the already unwrapped Secret-ID
+    for _ in 0..2 {
+        match tmp_auth
+            .auth(&hc_vault::HttpClient::from_url(&mock_server.uri()).unwrap())
+            .await
+        {
+            Err(e) => assert!(false, "Should not return error: '{}'", e),
+            Ok(_) => assert!(true),
+        };
+    }
+
+    assert_eq!(tmp_auth.get_token(), "testToken".to_string());
+}
+
+#[tokio::test]
+async fn invalid_wrapped_secret_id_creation_path() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/sys/wrapping/lookup"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": {
+                "creation_path": "sys/wrapping/wrap",
+                "creation_time": "2021-01-01T00:00:00Z",
+                "creation_ttl": 60,
+            },
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/sys/wrapping/unwrap"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/approle/login"))
+        .respond_with(login_response())
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let tmp_auth = hc_vault::approle::Session::new_wrapped(
+        "testID".to_string(),
+        "deploy".to_string(),
+        "wrappingToken".to_string(),
+    )
+    .unwrap();
+
+    match tmp_auth
+        .auth(&hc_vault::HttpClient::from_url(&mock_server.uri()).unwrap())
+        .await
+    {
+        Err(hc_vault::Error::InvalidWrapping(path)) => {
+            assert_eq!(path, "sys/wrapping/wrap".to_string())
+        }
+        Err(e) => assert!(false, "Should return invalid wrapping error: '{}'", e),
+        Ok(_) => assert!(false, "Should return error"),
+    };
+}
+
+#[tokio::test]
+async fn valid_role_id_only() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/approle/login"))
+        .and(body_json(json!({
+            "role_id": "testID",
+        })))
+        .respond_with(login_response())
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let tmp_auth = hc_vault::approle::Session::new_role_id_only("testID".to_string()).unwrap();
+
+    match tmp_auth
+        .auth(&hc_vault::HttpClient::from_url(&mock_server.uri()).unwrap())
+        .await
+    {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(_) => assert!(true),
+    };
+
+    assert_eq!(tmp_auth.get_token(), "testToken".to_string());
+}
+
+#[tokio::test]
+async fn invalid_bound_cidr() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/approle/login"))
+        .respond_with(ResponseTemplate::new(400).set_body_json(json!({
+            "errors": [
+                "source address \"10.1.2.3\" unauthorized by CIDR restrictions on the role",
+            ],
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let tmp_auth =
+        hc_vault::approle::Session::new("testID".to_string(), "testSecret".to_string()).unwrap();
+
+    match tmp_auth
+        .auth(&hc_vault::HttpClient::from_url(&mock_server.uri()).unwrap())
+        .await
+    {
+        Err(hc_vault::Error::BoundCIDR(r)) => {
+            assert_eq!(r.status, 400);
+            assert_eq!(
+                r.errors,
+                vec![
+                    "source address \"10.1.2.3\" unauthorized by CIDR restrictions on the role"
+                        .to_string()
+                ]
+            );
+        }
+        Err(e) => assert!(false, "Should return bound cidr error: '{}'", e),
+        Ok(_) => assert!(false, "Should return error"),
+    };
+}
+
+#[tokio::test]
+async fn invalid_token_bound_cidr_403() {
+    let mock_server = MockServer::start().await;
+
+    // Logins outside of the token_bound_cidrs are only rejected as denied
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/approle/login"))
+        .respond_with(ResponseTemplate::new(403).set_body_json(json!({
+            "errors": ["permission denied"],
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let tmp_auth =
+        hc_vault::approle::Session::new("testID".to_string(), "testSecret".to_string()).unwrap();
+
+    match tmp_auth
+        .auth(&hc_vault::HttpClient::from_url(&mock_server.uri()).unwrap())
+        .await
+    {
+        Err(hc_vault::Error::Unauthorized(r)) => assert_eq!(r.status, 403),
+        Err(e) => assert!(false, "Should return unauthorized error: '{}'", e),
+        Ok(_) => assert!(false, "Should return error"),
+    };
+}
+
+#[tokio::test]
+async fn invalid_cidr_message_not_bound_cidr() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/approle/login"))
+        .respond_with(ResponseTemplate::new(400).set_body_json(json!({
+            "errors": ["failed to parse the CIDR blocks of the secret ID"],
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let tmp_auth =
+        hc_vault::approle::Session::new("testID".to_string(), "testSecret".to_string()).unwrap();
+
+    match tmp_auth
+        .auth(&hc_vault::HttpClient::from_url(&mock_server.uri()).unwrap())
+        .await
+    {
+        Err(hc_vault::Error::InvalidRequest(r)) => assert_eq!(r.status, 400),
+        Err(e) => assert!(false, "Should return invalid request error: '{}'", e),
+        Ok(_) => assert!(false, "Should return error"),
+    };
+}
+
+#[tokio::test]
+async fn invalid_secret_id_400() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/approle/login"))
+        .respond_with(ResponseTemplate::new(400).set_body_json(json!({
+            "errors": ["invalid secret id"],
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let tmp_auth =
+        hc_vault::approle::Session::new("testID".to_string(), "testSecret".to_string()).unwrap();
+
+    match tmp_auth
+        .auth(&hc_vault::HttpClient::from_url(&mock_server.uri()).unwrap())
+        .await
+    {
+        Err(hc_vault::Error::InvalidRequest(_)) => assert!(true),
+        Err(e) => assert!(false, "Should return invalid request error: '{}'", e),
+        Ok(_) => assert!(false, "Should return error"),
+    };
+}
@@ -1,7 +1,5 @@
 extern crate hc_vault;
 
-use async_std::task;
-
 use wiremock::matchers::{method, path};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
@@ -26,9 +24,9 @@ struct ApproleResponse {
     lease_id: String,
 }
 
-#[test]
-fn is_expired_true() {
-    let mock_server = task::block_on(MockServer::start());
+#[tokio::test]
+async fn is_expired_true() {
+    let mock_server = MockServer::start().await;
 
     let test_role_id = "testID".to_string();
     let test_secret_id = "testSecret".to_string();
@@ -48,12 +46,11 @@ fn is_expired_true() {
     let mut response = ResponseTemplate::new(200);
     response = response.set_body_json(response_body);
 
-    task::block_on(
-        Mock::given(method("POST"))
-            .and(path("/v1/auth/approle/login"))
-            .respond_with(response)
-            .mount(&mock_server),
-    );
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/approle/login"))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
 
     let tmp_auth = match hc_vault::approle::Session::new(test_role_id, test_secret_id) {
         Err(e) => {
@@ -63,7 +60,10 @@ fn is_expired_true() {
         Ok(s) => s,
     };
 
-    match tmp_auth.auth(&mock_server.uri()) {
+    match tmp_auth
+        .auth(&hc_vault::HttpClient::from_url(&mock_server.uri()).unwrap())
+        .await
+    {
         Err(e) => assert!(false, "Should not return error: '{}'", e),
         Ok(_) => assert!(true),
     };
@@ -73,9 +73,9 @@ fn is_expired_true() {
     assert_eq!(true, tmp_auth.is_expired())
 }
 
-#[test]
-fn is_expired_false() {
-    let mock_server = task::block_on(MockServer::start());
+#[tokio::test]
+async fn is_expired_false() {
+    let mock_server = MockServer::start().await;
 
     let test_role_id = "testID".to_string();
     let test_secret_id = "testSecret".to_string();
@@ -95,12 +95,11 @@ fn is_expired_false() {
     let mut response = ResponseTemplate::new(200);
     response = response.set_body_json(response_body);
 
-    task::block_on(
-        Mock::given(method("POST"))
-            .and(path("/v1/auth/approle/login"))
-            .respond_with(response)
-            .mount(&mock_server),
-    );
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/approle/login"))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
 
     let tmp_auth = match hc_vault::approle::Session::new(test_role_id, test_secret_id) {
         Err(e) => {
@@ -110,7 +109,10 @@ fn is_expired_false() {
         Ok(s) => s,
     };
 
-    match tmp_auth.auth(&mock_server.uri()) {
+    match tmp_auth
+        .auth(&hc_vault::HttpClient::from_url(&mock_server.uri()).unwrap())
+        .await
+    {
         Err(e) => assert!(false, "Should not return error: '{}'", e),
         Ok(_) => assert!(true),
     };
@@ -1,7 +1,5 @@
 extern crate hc_vault;
 
-use async_std::task;
-
 use wiremock::matchers::{body_json, header, method, path};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
@@ -9,9 +7,9 @@ use serde_json::json;
 
 use hc_vault::Auth as AuthTrait;
 
-#[test]
-fn valid_renew() {
-    let mock_server = task::block_on(MockServer::start());
+#[tokio::test]
+async fn valid_renew() {
+    let mock_server = MockServer::start().await;
 
     let test_role_id = "testID".to_string();
     let test_secret_id = "testSecret".to_string();
@@ -36,30 +34,28 @@ fn valid_renew() {
     let mut response = ResponseTemplate::new(200);
     response = response.set_body_json(response_body);
 
-    task::block_on(
-        Mock::given(method("POST"))
-            .and(path("/v1/auth/approle/login"))
-            .and(body_json(&expected_body))
-            .respond_with(response)
-            .expect(1)
-            .mount(&mock_server),
-    );
-
-    task::block_on(
-        Mock::given(method("POST"))
-            .and(path("/v1/auth/token/renew-self"))
-            .and(header("X-Vault-Token", "testToken"))
-            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
-                "auth": {
-                    "renewable": true,
-                    "lease_duration": 120,
-                    "policies": vec!["test".to_string()],
-                    "client_token": "testToken".to_string(),
-                },
-            })))
-            .expect(1)
-            .mount(&mock_server),
-    );
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/approle/login"))
+        .and(body_json(&expected_body))
+        .respond_with(response)
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/token/renew-self"))
+        .and(header("X-Vault-Token", "testToken"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "auth": {
+                "renewable": true,
+                "lease_duration": 120,
+                "policies": vec!["test".to_string()],
+                "client_token": "testToken".to_string(),
+            },
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
 
     let tmp_auth = match hc_vault::approle::Session::new(test_role_id, test_secret_id) {
         Err(e) => {
@@ -69,15 +65,89 @@ fn valid_renew() {
         Ok(s) => s,
     };
 
-    match tmp_auth.auth(&mock_server.uri()) {
+    match tmp_auth
+        .auth(&hc_vault::HttpClient::from_url(&mock_server.uri()).unwrap())
+        .await
+    {
         Err(e) => assert!(false, "Should not return error: '{}'", e),
         Ok(_) => assert!(true),
     };
 
-    match tmp_auth.renew(&mock_server.uri()) {
+    match tmp_auth
+        .renew(&hc_vault::HttpClient::from_url(&mock_server.uri()).unwrap())
+        .await
+    {
         Err(e) => assert!(false, "Should not return error: '{}'", e),
         Ok(_) => assert!(true),
     }
 
     assert_eq!(tmp_auth.get_total_duration(), 120);
 }
+
+#[tokio::test]
+async fn valid_renew_with_increment_capped() {
+    let mock_server = MockServer::start().await;
+
+    let login_response = ResponseTemplate::new(200).set_body_json(json!({
+        "auth": {
+            "renewable": true,
+            "lease_duration": 600,
+            "token_policies": vec!["test".to_string()],
+            "accessor": "testAccessor".to_string(),
+            "client_token": "testToken".to_string(),
+        },
+        "lease_duration": 0,
+        "renewable": false,
+        "lease_id": "".to_string(),
+    }));
+
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/approle/login"))
+        .respond_with(login_response)
+        .expect(2)
+        .mount(&mock_server)
+        .await;
+
+    // Vault only renews the token until its max TTL, instead of the
+    // requested increment
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/token/renew-self"))
+        .and(header("X-Vault-Token", "testToken"))
+        .and(body_json(json!({"increment": 3600})))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "auth": {
+                "renewable": true,
+                "lease_duration": 120,
+                "policies": vec!["test".to_string()],
+                "client_token": "testToken".to_string(),
+            },
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let http = hc_vault::HttpClient::from_url(&mock_server.uri()).unwrap();
+    let tmp_auth =
+        hc_vault::approle::Session::new("testID".to_string(), "testSecret".to_string()).unwrap();
+
+    match tmp_auth.auth(&http).await {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(_) => assert_eq!(tmp_auth.get_max_ttl_remaining(), None),
+    };
+
+    match tmp_auth.renew_with(&http, Some(3600)).await {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(_) => assert!(true),
+    };
+    assert_eq!(tmp_auth.get_total_duration(), 120);
+    match tmp_auth.get_max_ttl_remaining() {
+        Some(remaining) => assert!((119..=120).contains(&remaining)),
+        None => assert!(false, "Should know the max TTL after a capped renew"),
+    };
+
+    // A new login starts a new token, whose max TTL is not known yet
+    match tmp_auth.auth(&http).await {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(_) => assert_eq!(tmp_auth.get_max_ttl_remaining(), None),
+    };
+}
@@ -0,0 +1,206 @@
+extern crate hc_vault;
+
+use wiremock::matchers::{body_json, header, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use serde_json::json;
+
+use crate::common::new_client;
+
+#[tokio::test]
+async fn valid_create_update() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/approle/role/deploy"))
+        .and(header("X-Vault-Token", "testToken"))
+        .and(body_json(json!({
+            "bind_secret_id": true,
+            "secret_id_num_uses": 1,
+            "token_policies": ["deploy"],
+        })))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = new_client(&mock_server).await;
+
+    let opts = hc_vault::approle::ApproleOptions {
+        bind_secret_id: Some(true),
+        secret_id_num_uses: Some(1),
+        token_policies: Some(vec!["deploy".to_string()]),
+        ..Default::default()
+    };
+
+    match hc_vault::approle::create_update(&client, "deploy", opts).await {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(_) => assert!(true),
+    };
+}
+
+#[tokio::test]
+async fn valid_read_role() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/auth/approle/role/deploy"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": {
+                "bind_secret_id": true,
+                "local_secret_ids": false,
+                "secret_id_bound_cidrs": null,
+                "secret_id_num_uses": 1,
+                "secret_id_ttl": 600,
+                "token_bound_cidrs": [],
+                "token_explicit_max_ttl": 0,
+                "token_max_ttl": 0,
+                "token_no_default_policy": false,
+                "token_num_uses": 0,
+                "token_period": 0,
+                "token_policies": ["deploy"],
+                "token_ttl": 1200,
+                "token_type": "default",
+            },
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = new_client(&mock_server).await;
+
+    let role = match hc_vault::approle::read_role(&client, "deploy").await {
+        Err(e) => {
+            assert!(false, "Should not return error: '{}'", e);
+            return;
+        }
+        Ok(r) => r,
+    };
+
+    assert_eq!(role.bind_secret_id, Some(true));
+    assert_eq!(role.secret_id_bound_cidrs, None);
+    assert_eq!(role.secret_id_ttl, Some(600));
+    assert_eq!(role.token_policies, Some(vec!["deploy".to_string()]));
+    assert_eq!(role.token_ttl, Some(1200));
+    assert_eq!(role.token_type, Some("default".to_string()));
+}
+
+#[tokio::test]
+async fn invalid_read_role_404() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/auth/approle/role/unknown"))
+        .respond_with(ResponseTemplate::new(404))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = new_client(&mock_server).await;
+
+    match hc_vault::approle::read_role(&client, "unknown").await {
+        Err(hc_vault::Error::NotFound(_)) => assert!(true),
+        Err(e) => assert!(false, "Should return not found error: '{}'", e),
+        Ok(_) => assert!(false, "Should return error"),
+    };
+}
+
+#[tokio::test]
+async fn valid_list_roles() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/auth/approle/role"))
+        .and(query_param("list", "true"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": {
+                "keys": ["build", "deploy"],
+            },
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = new_client(&mock_server).await;
+
+    match hc_vault::approle::list_roles(&client).await {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(roles) => assert_eq!(roles, vec!["build".to_string(), "deploy".to_string()]),
+    };
+}
+
+#[tokio::test]
+async fn valid_delete_role() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/v1/auth/approle/role/deploy"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = new_client(&mock_server).await;
+
+    match hc_vault::approle::delete_role(&client, "deploy").await {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(_) => assert!(true),
+    };
+}
+
+#[tokio::test]
+async fn valid_read_set_role_id() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/auth/approle/role/deploy/role-id"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": {
+                "role_id": "testRoleID",
+            },
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/approle/role/deploy/role-id"))
+        .and(body_json(json!({
+            "role_id": "customRoleID",
+        })))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = new_client(&mock_server).await;
+
+    match hc_vault::approle::read_role_id(&client, "deploy").await {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(role_id) => assert_eq!(role_id, "testRoleID".to_string()),
+    };
+
+    match hc_vault::approle::set_role_id(&client, "deploy", "customRoleID").await {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(_) => assert!(true),
+    };
+}
+
+#[tokio::test]
+async fn valid_tidy() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/approle/tidy/secret-id"))
+        .respond_with(ResponseTemplate::new(202))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = new_client(&mock_server).await;
+
+    match hc_vault::approle::tidy(&client).await {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(_) => assert!(true),
+    };
+}
@@ -0,0 +1,241 @@
+extern crate hc_vault;
+
+use wiremock::matchers::{body_json, header, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use serde_json::json;
+
+use crate::common::new_client;
+
+fn secret_id_response() -> ResponseTemplate {
+    ResponseTemplate::new(200).set_body_json(json!({
+        "data": {
+            "secret_id": "testSecretID",
+            "secret_id_accessor": "testAccessor",
+            "secret_id_ttl": 600,
+            "secret_id_num_uses": 1,
+        },
+    }))
+}
+
+fn lookup_response() -> ResponseTemplate {
+    ResponseTemplate::new(200).set_body_json(json!({
+        "data": {
+            "cidr_list": ["10.0.0.0/8"],
+            "creation_time": "2021-01-01T00:00:00Z",
+            "expiration_time": "2021-01-01T00:10:00Z",
+            "last_updated_time": "2021-01-01T00:00:00Z",
+            "metadata": {
+                "deploy": "42",
+            },
+            "secret_id_accessor": "testAccessor",
+            "secret_id_num_uses": 1,
+            "secret_id_ttl": 600,
+            "token_bound_cidrs": [],
+        },
+    }))
+}
+
+#[tokio::test]
+async fn valid_generate_secret_id() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/approle/role/deploy/secret-id"))
+        .and(header("X-Vault-Token", "testToken"))
+        .and(body_json(json!({
+            "cidr_list": ["10.0.0.0/8"],
+            "metadata": "{\"deploy\":\"42\"}",
+        })))
+        .respond_with(secret_id_response())
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = new_client(&mock_server).await;
+
+    let mut metadata = std::collections::HashMap::new();
+    metadata.insert("deploy".to_string(), "42".to_string());
+    let opts = hc_vault::approle::SecretIDOptions {
+        cidr_list: Some(vec!["10.0.0.0/8".to_string()]),
+        metadata: Some(metadata),
+        ..Default::default()
+    };
+
+    let secret_id = match hc_vault::approle::generate_secret_id(&client, "deploy", &opts).await {
+        Err(e) => {
+            assert!(false, "Should not return error: '{}'", e);
+            return;
+        }
+        Ok(s) => s,
+    };
+
+    assert_eq!(secret_id.secret_id, "testSecretID".to_string());
+    assert_eq!(secret_id.secret_id_accessor, "testAccessor".to_string());
+    assert_eq!(secret_id.secret_id_ttl, 600);
+    assert_eq!(secret_id.secret_id_num_uses, 1);
+}
+
+#[tokio::test]
+async fn valid_custom_secret_id() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/approle/role/deploy/custom-secret-id"))
+        .and(body_json(json!({
+            "secret_id": "testSecretID",
+        })))
+        .respond_with(secret_id_response())
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = new_client(&mock_server).await;
+
+    match hc_vault::approle::custom_secret_id(
+        &client,
+        "deploy",
+        "testSecretID",
+        &Default::default(),
+    )
+    .await
+    {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(s) => assert_eq!(s.secret_id_accessor, "testAccessor".to_string()),
+    };
+}
+
+#[tokio::test]
+async fn valid_lookup_secret_id() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/approle/role/deploy/secret-id/lookup"))
+        .and(body_json(json!({
+            "secret_id": "testSecretID",
+        })))
+        .respond_with(lookup_response())
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path(
+            "/v1/auth/approle/role/deploy/secret-id-accessor/lookup",
+        ))
+        .and(body_json(json!({
+            "secret_id_accessor": "testAccessor",
+        })))
+        .respond_with(lookup_response())
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = new_client(&mock_server).await;
+
+    let info = match hc_vault::approle::lookup_secret_id(&client, "deploy", "testSecretID").await {
+        Err(e) => {
+            assert!(false, "Should not return error: '{}'", e);
+            return;
+        }
+        Ok(i) => i,
+    };
+
+    assert_eq!(info.secret_id_accessor, "testAccessor".to_string());
+    assert_eq!(info.cidr_list, Some(vec!["10.0.0.0/8".to_string()]));
+    assert_eq!(
+        info.metadata.unwrap().get("deploy"),
+        Some(&"42".to_string())
+    );
+
+    match hc_vault::approle::lookup_secret_id_accessor(&client, "deploy", "testAccessor").await {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(info) => assert_eq!(info.secret_id_ttl, 600),
+    };
+}
+
+#[tokio::test]
+async fn invalid_lookup_secret_id_not_found() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/approle/role/deploy/secret-id/lookup"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = new_client(&mock_server).await;
+
+    match hc_vault::approle::lookup_secret_id(&client, "deploy", "unknownSecretID").await {
+        Err(hc_vault::Error::NotFound(_)) => assert!(true),
+        Err(e) => assert!(false, "Should return not found error: '{}'", e),
+        Ok(_) => assert!(false, "Should return error"),
+    };
+}
+
+#[tokio::test]
+async fn valid_destroy_secret_id() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/approle/role/deploy/secret-id/destroy"))
+        .and(body_json(json!({
+            "secret_id": "testSecretID",
+        })))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path(
+            "/v1/auth/approle/role/deploy/secret-id-accessor/destroy",
+        ))
+        .and(body_json(json!({
+            "secret_id_accessor": "testAccessor",
+        })))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = new_client(&mock_server).await;
+
+    match hc_vault::approle::destroy_secret_id(&client, "deploy", "testSecretID").await {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(_) => assert!(true),
+    };
+
+    match hc_vault::approle::destroy_secret_id_accessor(&client, "deploy", "testAccessor").await {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(_) => assert!(true),
+    };
+}
+
+#[tokio::test]
+async fn valid_list_secret_id_accessors() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/auth/approle/role/deploy/secret-id"))
+        .and(query_param("list", "true"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": {
+                "keys": ["firstAccessor", "secondAccessor"],
+            },
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = new_client(&mock_server).await;
+
+    match hc_vault::approle::list_secret_id_accessors(&client, "deploy").await {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(keys) => assert_eq!(
+            keys,
+            vec!["firstAccessor".to_string(), "secondAccessor".to_string()]
+        ),
+    };
+}
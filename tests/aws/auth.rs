@@ -0,0 +1,215 @@
+extern crate hc_vault;
+
+use wiremock::matchers::{method, path};
+use wiremock::{Match, Mock, MockServer, Request, ResponseTemplate};
+
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+
+use hc_vault::Auth as AuthTrait;
+
+/// Checks that the body contains a correctly encoded and signed
+/// sts:GetCallerIdentity request
+struct IamLoginMatcher {
+    role: String,
+    server_id: Option<String>,
+    session_token: Option<String>,
+}
+
+impl Match for IamLoginMatcher {
+    fn matches(&self, request: &Request) -> bool {
+        let body: Value = match serde_json::from_slice(&request.body) {
+            Err(_) => return false,
+            Ok(b) => b,
+        };
+
+        let decode = |key: &str| -> Vec<u8> {
+            base64::decode(body[key].as_str().unwrap_or_default()).unwrap_or_default()
+        };
+
+        let headers: BTreeMap<String, Vec<String>> =
+            match serde_json::from_slice(&decode("iam_request_headers")) {
+                Err(_) => return false,
+                Ok(h) => h,
+            };
+        let header = |key: &str| headers.get(key).map(|v| v[0].clone());
+
+        let authorization = header("Authorization").unwrap_or_default();
+        let mut expected_signed = "content-type;host;x-amz-date".to_string();
+        if self.session_token.is_some() {
+            expected_signed.push_str(";x-amz-security-token");
+        }
+        if self.server_id.is_some() {
+            expected_signed.push_str(";x-vault-aws-iam-server-id");
+        }
+
+        body["role"] == self.role.as_str()
+            && body["iam_http_request_method"] == "POST"
+            && decode("iam_request_url") == b"https://sts.amazonaws.com/"
+            && decode("iam_request_body") == b"Action=GetCallerIdentity&Version=2011-06-15"
+            && header("Host") == Some("sts.amazonaws.com".to_string())
+            && header("X-Vault-AWS-IAM-Server-ID") == self.server_id
+            && header("X-Amz-Security-Token") == self.session_token
+            && authorization.starts_with("AWS4-HMAC-SHA256 Credential=testKeyID/")
+            && authorization.contains("/us-east-1/sts/aws4_request")
+            && authorization.contains(&format!("SignedHeaders={},", expected_signed))
+    }
+}
+
+fn login_response() -> ResponseTemplate {
+    ResponseTemplate::new(200).set_body_json(json!({
+        "auth": {
+            "client_token": "testToken",
+            "accessor": "testAccessor",
+            "policies": ["test"],
+            "metadata": {
+                "account_id": "123456789012",
+            },
+            "lease_duration": 120,
+            "renewable": true,
+        },
+    }))
+}
+
+#[tokio::test]
+async fn valid_iam_login() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/aws/login"))
+        .and(IamLoginMatcher {
+            role: "testRole".to_string(),
+            server_id: None,
+            session_token: None,
+        })
+        .respond_with(login_response())
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let credentials = hc_vault::aws::Credentials {
+        access_key_id: "testKeyID".to_string(),
+        secret_access_key: "testSecret".to_string(),
+        session_token: None,
+    };
+    let config = hc_vault::aws::IamConfig {
+        role: "testRole".to_string(),
+        ..Default::default()
+    };
+    let tmp_auth = match hc_vault::aws::Session::new(config, credentials) {
+        Err(e) => {
+            assert!(false, "Should not return error: '{}'", e);
+            return;
+        }
+        Ok(s) => s,
+    };
+
+    match tmp_auth
+        .auth(&hc_vault::HttpClient::from_url(&mock_server.uri()).unwrap())
+        .await
+    {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(_) => assert!(true),
+    };
+
+    assert_eq!(tmp_auth.get_token(), "testToken".to_string());
+    assert_eq!(tmp_auth.get_total_duration(), 120);
+}
+
+#[tokio::test]
+async fn valid_iam_login_server_id_custom_mount() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/aws-prod/login"))
+        .and(IamLoginMatcher {
+            role: "testRole".to_string(),
+            server_id: Some("vault.example.com".to_string()),
+            session_token: Some("testSessionToken".to_string()),
+        })
+        .respond_with(login_response())
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let provider = || {
+        Ok(hc_vault::aws::Credentials {
+            access_key_id: "testKeyID".to_string(),
+            secret_access_key: "testSecret".to_string(),
+            session_token: Some("testSessionToken".to_string()),
+        })
+    };
+    let config = hc_vault::aws::IamConfig {
+        role: "testRole".to_string(),
+        server_id: Some("vault.example.com".to_string()),
+        mount: "aws-prod".to_string(),
+        ..Default::default()
+    };
+    let tmp_auth = hc_vault::aws::Session::new(config, provider).unwrap();
+
+    match tmp_auth
+        .auth(&hc_vault::HttpClient::from_url(&mock_server.uri()).unwrap())
+        .await
+    {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(_) => assert!(true),
+    };
+}
+
+#[tokio::test]
+async fn invalid_iam_login_provider_error() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/aws/login"))
+        .respond_with(login_response())
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let provider = || Err(hc_vault::Error::MissingCredentials("test"));
+    let config = hc_vault::aws::IamConfig {
+        role: "testRole".to_string(),
+        ..Default::default()
+    };
+    let tmp_auth = hc_vault::aws::Session::new(config, provider).unwrap();
+
+    match tmp_auth
+        .auth(&hc_vault::HttpClient::from_url(&mock_server.uri()).unwrap())
+        .await
+    {
+        Err(hc_vault::Error::MissingCredentials(_)) => assert!(true),
+        Err(e) => assert!(false, "Wrong error returned: '{}'", e),
+        Ok(_) => assert!(false, "Should return error"),
+    };
+}
+
+#[tokio::test]
+async fn invalid_iam_login_403() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/aws/login"))
+        .respond_with(ResponseTemplate::new(403))
+        .mount(&mock_server)
+        .await;
+
+    let credentials = hc_vault::aws::Credentials {
+        access_key_id: "testKeyID".to_string(),
+        secret_access_key: "testSecret".to_string(),
+        session_token: None,
+    };
+    let config = hc_vault::aws::IamConfig {
+        role: "testRole".to_string(),
+        ..Default::default()
+    };
+    let tmp_auth = hc_vault::aws::Session::new(config, credentials).unwrap();
+
+    match tmp_auth
+        .auth(&hc_vault::HttpClient::from_url(&mock_server.uri()).unwrap())
+        .await
+    {
+        Err(_) => assert!(true),
+        Ok(_) => assert!(false, "Should return error"),
+    };
+}
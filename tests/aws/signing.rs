@@ -0,0 +1,122 @@
+extern crate hc_vault;
+
+use std::collections::BTreeMap;
+use url::Url;
+
+// The Test-Vectors are taken from the official AWS SigV4 test-suite and
+// signing examples
+// https://docs.aws.amazon.com/general/latest/gr/signature-v4-test-suite.html
+
+fn test_credentials() -> hc_vault::aws::Credentials {
+    hc_vault::aws::Credentials {
+        access_key_id: "AKIDEXAMPLE".to_string(),
+        secret_access_key: "wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY".to_string(),
+        session_token: None,
+    }
+}
+
+#[test]
+fn signing_key() {
+    let key = hc_vault::aws::signing_key(
+        "wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY",
+        "20150830",
+        "us-east-1",
+        "iam",
+    );
+
+    assert_eq!(
+        hex::encode(key),
+        "c4afb1cc5771d871763a393e44b703571b55cc28424d1a5e86da6ed3c154a4b9"
+    );
+}
+
+#[test]
+fn amz_date() {
+    let (date, timestamp) = hc_vault::aws::amz_date(1440938160);
+
+    assert_eq!(date, "20150830");
+    assert_eq!(timestamp, "20150830T123600Z");
+}
+
+#[test]
+fn get_vanilla() {
+    let url = Url::parse("https://example.amazonaws.com/").unwrap();
+
+    let mut headers = BTreeMap::new();
+    headers.insert("Host".to_string(), "example.amazonaws.com".to_string());
+    headers.insert("X-Amz-Date".to_string(), "20150830T123600Z".to_string());
+
+    let authorization = hc_vault::aws::sign_v4(
+        "GET",
+        &url,
+        &headers,
+        b"",
+        &test_credentials(),
+        "us-east-1",
+        "service",
+        "20150830T123600Z",
+    );
+
+    assert_eq!(
+        authorization,
+        "AWS4-HMAC-SHA256 Credential=AKIDEXAMPLE/20150830/us-east-1/service/aws4_request, SignedHeaders=host;x-amz-date, Signature=5fa00fa31553b73ebf1942676e86291e8372ff2a2260956d9b8aae1d763fbf31"
+    );
+}
+
+#[test]
+fn post_x_www_form_urlencoded() {
+    let url = Url::parse("https://example.amazonaws.com/").unwrap();
+
+    let mut headers = BTreeMap::new();
+    headers.insert(
+        "Content-Type".to_string(),
+        "application/x-www-form-urlencoded".to_string(),
+    );
+    headers.insert("Host".to_string(), "example.amazonaws.com".to_string());
+    headers.insert("X-Amz-Date".to_string(), "20150830T123600Z".to_string());
+
+    let authorization = hc_vault::aws::sign_v4(
+        "POST",
+        &url,
+        &headers,
+        b"Param1=value1",
+        &test_credentials(),
+        "us-east-1",
+        "service",
+        "20150830T123600Z",
+    );
+
+    assert_eq!(
+        authorization,
+        "AWS4-HMAC-SHA256 Credential=AKIDEXAMPLE/20150830/us-east-1/service/aws4_request, SignedHeaders=content-type;host;x-amz-date, Signature=ff11897932ad3f4e8b18135d722051e5ac45fc38421b1da7b9d196a0fe09473a"
+    );
+}
+
+#[test]
+fn get_with_query() {
+    let url = Url::parse("https://iam.amazonaws.com/?Version=2010-05-08&Action=ListUsers").unwrap();
+
+    let mut headers = BTreeMap::new();
+    headers.insert(
+        "Content-Type".to_string(),
+        "application/x-www-form-urlencoded; charset=utf-8".to_string(),
+    );
+    headers.insert("Host".to_string(), "iam.amazonaws.com".to_string());
+    headers.insert("X-Amz-Date".to_string(), "20150830T123600Z".to_string());
+
+    let authorization = hc_vault::aws::sign_v4(
+        "GET",
+        &url,
+        &headers,
+        b"",
+        &test_credentials(),
+        "us-east-1",
+        "iam",
+        "20150830T123600Z",
+    );
+
+    assert_eq!(
+        authorization,
+        "AWS4-HMAC-SHA256 Credential=AKIDEXAMPLE/20150830/us-east-1/iam/aws4_request, SignedHeaders=content-type;host;x-amz-date, Signature=5d672d79c15b13162d9279b0855cfba6789a8edb4c82c400e06b5924a6f2b5d7"
+    );
+}
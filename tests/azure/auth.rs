@@ -0,0 +1,182 @@
+extern crate hc_vault;
+
+use wiremock::matchers::{body_json, header, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use serde_json::json;
+
+use hc_vault::Auth as AuthTrait;
+
+async fn mount_msi_token(mock_server: &MockServer) {
+    Mock::given(method("GET"))
+        .and(path("/metadata/identity/oauth2/token"))
+        .and(header("Metadata", "true"))
+        .and(query_param("resource", "https://management.azure.com/"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "access_token": "testMSIToken",
+            "expires_in": "3599",
+            "resource": "https://management.azure.com/",
+            "token_type": "Bearer",
+        })))
+        .expect(1)
+        .mount(mock_server)
+        .await;
+}
+
+fn login_response() -> ResponseTemplate {
+    ResponseTemplate::new(200).set_body_json(json!({
+        "auth": {
+            "client_token": "testToken",
+            "accessor": "testAccessor",
+            "policies": ["test"],
+            "lease_duration": 120,
+            "renewable": true,
+        },
+    }))
+}
+
+#[tokio::test]
+async fn valid_login_vm() {
+    let mock_server = MockServer::start().await;
+
+    mount_msi_token(&mock_server).await;
+    Mock::given(method("GET"))
+        .and(path("/metadata/instance"))
+        .and(header("Metadata", "true"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "compute": {
+                "subscriptionId": "testSubscription",
+                "resourceGroupName": "testGroup",
+                "name": "testVM",
+                "vmScaleSetName": "",
+            },
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/azure/login"))
+        .and(body_json(json!({
+            "role": "testRole",
+            "jwt": "testMSIToken",
+            "subscription_id": "testSubscription",
+            "resource_group_name": "testGroup",
+            "vm_name": "testVM",
+        })))
+        .respond_with(login_response())
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let config = hc_vault::azure::AzureConfig {
+        role: "testRole".to_string(),
+        metadata_url: mock_server.uri(),
+        ..Default::default()
+    };
+    let tmp_auth = match hc_vault::azure::Session::new(config) {
+        Err(e) => {
+            assert!(false, "Should not return error: '{}'", e);
+            return;
+        }
+        Ok(s) => s,
+    };
+
+    match tmp_auth
+        .auth(&hc_vault::HttpClient::from_url(&mock_server.uri()).unwrap())
+        .await
+    {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(_) => assert!(true),
+    };
+
+    assert_eq!(tmp_auth.get_token(), "testToken".to_string());
+    assert_eq!(tmp_auth.get_total_duration(), 120);
+}
+
+#[tokio::test]
+async fn valid_login_scale_set() {
+    let mock_server = MockServer::start().await;
+
+    mount_msi_token(&mock_server).await;
+    Mock::given(method("GET"))
+        .and(path("/metadata/instance"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "compute": {
+                "subscriptionId": "testSubscription",
+                "resourceGroupName": "testGroup",
+                "name": "testVM_0",
+                "vmScaleSetName": "testVMSS",
+            },
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/azure/login"))
+        .and(body_json(json!({
+            "role": "testRole",
+            "jwt": "testMSIToken",
+            "subscription_id": "testSubscription",
+            "resource_group_name": "testGroup",
+            "vmss_name": "testVMSS",
+        })))
+        .respond_with(login_response())
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let config = hc_vault::azure::AzureConfig {
+        role: "testRole".to_string(),
+        metadata_url: mock_server.uri(),
+        ..Default::default()
+    };
+    let tmp_auth = hc_vault::azure::Session::new(config).unwrap();
+
+    match tmp_auth
+        .auth(&hc_vault::HttpClient::from_url(&mock_server.uri()).unwrap())
+        .await
+    {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(_) => assert!(true),
+    };
+}
+
+#[tokio::test]
+async fn invalid_login_403() {
+    let mock_server = MockServer::start().await;
+
+    mount_msi_token(&mock_server).await;
+    Mock::given(method("GET"))
+        .and(path("/metadata/instance"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "compute": {
+                "subscriptionId": "testSubscription",
+                "resourceGroupName": "testGroup",
+                "name": "testVM",
+            },
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/azure/login"))
+        .respond_with(ResponseTemplate::new(403))
+        .mount(&mock_server)
+        .await;
+
+    let config = hc_vault::azure::AzureConfig {
+        role: "testRole".to_string(),
+        metadata_url: mock_server.uri(),
+        ..Default::default()
+    };
+    let tmp_auth = hc_vault::azure::Session::new(config).unwrap();
+
+    match tmp_auth
+        .auth(&hc_vault::HttpClient::from_url(&mock_server.uri()).unwrap())
+        .await
+    {
+        Err(_) => assert!(true),
+        Ok(_) => assert!(false, "Should return error"),
+    };
+}
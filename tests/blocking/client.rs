@@ -0,0 +1,221 @@
+extern crate hc_vault;
+
+use async_std::task;
+
+use std::time::Duration;
+
+use wiremock::matchers::{header, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use serde_json::json;
+
+use crate::common;
+
+fn new_client(mock_server: &MockServer) -> hc_vault::blocking::Client<hc_vault::token::Session> {
+    task::block_on(common::mock_root_lookup_self(mock_server));
+
+    let auth = hc_vault::token::Session::new("testToken".to_string()).unwrap();
+    let conf = hc_vault::Config {
+        vault_url: mock_server.uri(),
+        retry: hc_vault::RetryPolicy::disabled(),
+        ..Default::default()
+    };
+    hc_vault::blocking::Client::new(conf, auth).unwrap()
+}
+
+#[test]
+fn valid_read() {
+    let mock_server = task::block_on(MockServer::start());
+
+    task::block_on(
+        Mock::given(method("GET"))
+            .and(path("/v1/secret/app"))
+            .and(header("X-Vault-Token", "testToken"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "data": {
+                    "password": "hunter2",
+                },
+            })))
+            .expect(1)
+            .mount(&mock_server),
+    );
+
+    let client = new_client(&mock_server);
+    assert_eq!(client.get_token(), "testToken".to_string());
+
+    match client.read::<serde_json::Value>("secret/app") {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(resp) => assert_eq!(resp.data, json!({"password": "hunter2"})),
+    };
+}
+
+#[test]
+fn invalid_read_not_found() {
+    let mock_server = task::block_on(MockServer::start());
+
+    task::block_on(
+        Mock::given(method("GET"))
+            .and(path("/v1/secret/app"))
+            .respond_with(ResponseTemplate::new(404))
+            .expect(1)
+            .mount(&mock_server),
+    );
+
+    let client = new_client(&mock_server);
+
+    match client.read::<serde_json::Value>("secret/app") {
+        Err(hc_vault::Error::NotFound(_)) => assert!(true),
+        Err(e) => assert!(false, "Should return NotFound, got: '{}'", e),
+        Ok(_) => assert!(false, "Should return error"),
+    };
+}
+
+#[test]
+fn valid_write_list_delete() {
+    let mock_server = task::block_on(MockServer::start());
+
+    task::block_on(
+        Mock::given(method("POST"))
+            .and(path("/v1/secret/app"))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&mock_server),
+    );
+    task::block_on(
+        Mock::given(method("GET"))
+            .and(path("/v1/secret"))
+            .and(query_param("list", "true"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "data": {
+                    "keys": ["app"],
+                },
+            })))
+            .expect(1)
+            .mount(&mock_server),
+    );
+    task::block_on(
+        Mock::given(method("DELETE"))
+            .and(path("/v1/secret/app"))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&mock_server),
+    );
+
+    let client = new_client(&mock_server);
+
+    match client.write::<_, serde::de::IgnoredAny>("secret/app", &json!({"password": "hunter2"})) {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(resp) => assert!(resp.is_none()),
+    };
+    match client.list("secret") {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(resp) => assert_eq!(resp.data.keys, vec!["app".to_string()]),
+    };
+    match client.delete("secret/app") {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(_) => assert!(true),
+    };
+}
+
+#[test]
+fn valid_with_namespace_and_timeout() {
+    let mock_server = task::block_on(MockServer::start());
+
+    task::block_on(
+        Mock::given(method("GET"))
+            .and(path("/v1/secret/app"))
+            .and(header("X-Vault-Namespace", "tenant-a"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(json!({"data": {}}))
+                    .set_delay(Duration::from_millis(300)),
+            )
+            .expect(1)
+            .mount(&mock_server),
+    );
+
+    let client = new_client(&mock_server);
+    let tenant = client
+        .with_namespace("tenant-a")
+        .with_timeout(Duration::from_millis(100));
+    assert_eq!(tenant.namespace(), Some("tenant-a"));
+    assert_eq!(tenant.timeout(), Some(Duration::from_millis(100)));
+
+    match tenant.read::<serde::de::IgnoredAny>("secret/app") {
+        Err(hc_vault::Error::Timeout(_)) => assert!(true),
+        Err(e) => assert!(false, "Should return Timeout, got: '{}'", e),
+        Ok(_) => assert!(false, "Should return error"),
+    };
+}
+
+#[test]
+fn valid_from_multiple_threads() {
+    let mock_server = task::block_on(MockServer::start());
+
+    task::block_on(
+        Mock::given(method("GET"))
+            .and(path("/v1/secret/app"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({"data": {}})))
+            .expect(4)
+            .mount(&mock_server),
+    );
+
+    let client = new_client(&mock_server);
+
+    let handles: Vec<_> = (0..4)
+        .map(|_| {
+            let client = client.clone();
+            std::thread::spawn(move || client.read::<serde::de::IgnoredAny>("secret/app"))
+        })
+        .collect();
+
+    for handle in handles {
+        match handle.join().unwrap() {
+            Err(e) => assert!(false, "Should not return error: '{}'", e),
+            Ok(_) => assert!(true),
+        };
+    }
+}
+
+#[test]
+fn valid_dyn_client() {
+    let mock_server = task::block_on(MockServer::start());
+
+    task::block_on(
+        Mock::given(method("GET"))
+            .and(path("/v1/auth/token/lookup-self"))
+            .and(header("X-Vault-Token", "testToken"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "data": {
+                    "ttl": 0,
+                    "renewable": false,
+                    "policies": ["root"],
+                },
+            })))
+            .expect(1)
+            .mount(&mock_server),
+    );
+    task::block_on(
+        Mock::given(method("GET"))
+            .and(path("/v1/secret/app"))
+            .and(header("X-Vault-Token", "testToken"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({"data": {}})))
+            .expect(1)
+            .mount(&mock_server),
+    );
+
+    let auth: Box<dyn hc_vault::Auth> =
+        Box::new(hc_vault::token::Session::new("testToken".to_string()).unwrap());
+    let conf = hc_vault::Config {
+        vault_url: mock_server.uri(),
+        retry: hc_vault::RetryPolicy::disabled(),
+        ..Default::default()
+    };
+    let client: hc_vault::blocking::DynClient =
+        hc_vault::blocking::Client::new(conf, auth).unwrap();
+
+    match client.clone().read::<serde::de::IgnoredAny>("secret/app") {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(_) => assert!(true),
+    };
+}
@@ -0,0 +1,143 @@
+extern crate hc_vault;
+
+use async_std::task;
+
+use std::time::Duration;
+
+use wiremock::matchers::{header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::common;
+
+fn new_client(mock_server: &MockServer) -> hc_vault::blocking::Client<hc_vault::token::Session> {
+    task::block_on(common::mock_root_lookup_self(mock_server));
+
+    let auth = hc_vault::token::Session::new("testToken".to_string()).unwrap();
+    let conf = hc_vault::Config {
+        vault_url: mock_server.uri(),
+        ..Default::default()
+    };
+    hc_vault::blocking::Client::new(conf, auth).unwrap()
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+struct KV2Data {
+    field1: String,
+}
+
+#[test]
+fn valid_kv2_get() {
+    let mock_server = task::block_on(MockServer::start());
+
+    task::block_on(
+        Mock::given(method("GET"))
+            .and(path("/v1/kv/data/test"))
+            .and(header("X-Vault-Token", "testToken"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "data": {
+                    "data": {
+                        "field1": "testData",
+                    },
+                },
+            })))
+            .expect(1)
+            .mount(&mock_server),
+    );
+
+    let client = new_client(&mock_server);
+
+    match hc_vault::blocking::kv2::get::<KV2Data>(&client, "kv", "test", None) {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(data) => assert_eq!(
+            data,
+            KV2Data {
+                field1: "testData".to_string()
+            }
+        ),
+    };
+}
+
+#[test]
+fn valid_database_get_credentials() {
+    let mock_server = task::block_on(MockServer::start());
+
+    task::block_on(
+        Mock::given(method("GET"))
+            .and(path("/v1/database/creds/test_db"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "lease_id": "test_id",
+                "lease_duration": 120,
+                "renewable": true,
+                "data": {
+                    "username": "test_username",
+                    "password": "test_password",
+                },
+            })))
+            .expect(1)
+            .mount(&mock_server),
+    );
+
+    let client = new_client(&mock_server);
+
+    match hc_vault::blocking::database::get_credentials(&client, "test_db") {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(creds) => assert_eq!(
+            creds,
+            hc_vault::database::DatabaseCreds {
+                username: "test_username".to_string(),
+                password: "test_password".to_string(),
+                duration: Duration::from_secs(120),
+            }
+        ),
+    };
+}
+
+#[test]
+fn valid_approle_create_update() {
+    let mock_server = task::block_on(MockServer::start());
+
+    task::block_on(
+        Mock::given(method("POST"))
+            .and(path("/v1/auth/approle/role/test"))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&mock_server),
+    );
+
+    let client = new_client(&mock_server);
+
+    match hc_vault::blocking::approle::create_update(
+        &client,
+        "test",
+        hc_vault::approle::ApproleOptions::default(),
+    ) {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(_) => assert!(true),
+    };
+}
+
+#[test]
+fn valid_okta_verify_number() {
+    let mock_server = task::block_on(MockServer::start());
+
+    task::block_on(
+        Mock::given(method("GET"))
+            .and(path("/v1/auth/okta/verify/testNonce"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "data": {
+                    "correct_answer": 42,
+                },
+            })))
+            .expect(1)
+            .mount(&mock_server),
+    );
+
+    let http = hc_vault::HttpClient::from_url(&mock_server.uri()).unwrap();
+    match hc_vault::blocking::okta::verify_number(&http, "okta", "testNonce") {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(number) => assert_eq!(number, 42),
+    };
+}
@@ -0,0 +1,239 @@
+extern crate hc_vault;
+
+use wiremock::matchers::{body_json, header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use serde_json::json;
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use hc_vault::transport::{MemoryTransport, Request, Response, StatusCode, TransportError};
+use hc_vault::Auth as AuthTrait;
+
+const TEST_CERT: &[u8] = include_bytes!("test_cert.pem");
+const TEST_KEY: &[u8] = include_bytes!("test_key.pem");
+
+#[tokio::test]
+async fn valid_new_cert() {
+    let mock_server = MockServer::start().await;
+
+    let expected_body = json!({
+        "name": "testRole",
+    });
+
+    let response_body = json!({
+        "auth": {
+            "client_token": "testToken",
+            "accessor": "testAccessor",
+            "policies": ["test"],
+            "lease_duration": 120,
+            "renewable": true,
+        },
+    });
+
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/cert/login"))
+        .and(body_json(&expected_body))
+        .respond_with(ResponseTemplate::new(200).set_body_json(response_body))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let tmp_auth =
+        match hc_vault::cert::Session::new(Some("testRole".to_string()), TEST_CERT, TEST_KEY) {
+            Err(e) => {
+                assert!(false, "Should not return error: '{}'", e);
+                return;
+            }
+            Ok(s) => s,
+        };
+
+    match tmp_auth
+        .auth(&hc_vault::HttpClient::from_url(&mock_server.uri()).unwrap())
+        .await
+    {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(_) => assert!(true),
+    };
+
+    assert_eq!(tmp_auth.get_token(), "testToken".to_string());
+    assert_eq!(tmp_auth.get_total_duration(), 120);
+}
+
+#[tokio::test]
+async fn valid_new_cert_no_name() {
+    let mock_server = MockServer::start().await;
+
+    let response_body = json!({
+        "auth": {
+            "client_token": "testToken",
+            "accessor": "testAccessor",
+            "policies": ["test"],
+            "lease_duration": 120,
+            "renewable": true,
+        },
+    });
+
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/cert/login"))
+        .and(body_json(json!({})))
+        .respond_with(ResponseTemplate::new(200).set_body_json(response_body))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let tmp_auth = hc_vault::cert::Session::new(None, TEST_CERT, TEST_KEY).unwrap();
+
+    match tmp_auth
+        .auth(&hc_vault::HttpClient::from_url(&mock_server.uri()).unwrap())
+        .await
+    {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(_) => assert!(true),
+    };
+}
+
+#[tokio::test]
+async fn invalid_new_cert_not_valid_403() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/cert/login"))
+        .respond_with(ResponseTemplate::new(403))
+        .mount(&mock_server)
+        .await;
+
+    let tmp_auth = hc_vault::cert::Session::new(None, TEST_CERT, TEST_KEY).unwrap();
+
+    match tmp_auth
+        .auth(&hc_vault::HttpClient::from_url(&mock_server.uri()).unwrap())
+        .await
+    {
+        Err(_) => assert!(true),
+        Ok(_) => assert!(false, "Should return error"),
+    };
+}
+
+#[test]
+fn invalid_identity() {
+    match hc_vault::cert::Session::new(None, b"not a cert", b"not a key") {
+        Err(_) => assert!(true),
+        Ok(_) => assert!(false, "Should return error"),
+    };
+}
+
+#[tokio::test]
+async fn valid_renew() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/cert/login"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "auth": {
+                "client_token": "testToken",
+                "accessor": "testAccessor",
+                "policies": ["test"],
+                "lease_duration": 10,
+                "renewable": true,
+            },
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/token/renew-self"))
+        .and(header("X-Vault-Token", "testToken"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "auth": {
+                "client_token": "testToken",
+                "policies": ["test"],
+                "lease_duration": 120,
+                "renewable": true,
+            },
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let tmp_auth = hc_vault::cert::Session::new(None, TEST_CERT, TEST_KEY).unwrap();
+
+    match tmp_auth
+        .auth(&hc_vault::HttpClient::from_url(&mock_server.uri()).unwrap())
+        .await
+    {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(_) => assert!(true),
+    };
+
+    match tmp_auth
+        .renew(&hc_vault::HttpClient::from_url(&mock_server.uri()).unwrap())
+        .await
+    {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(_) => assert!(true),
+    };
+
+    assert_eq!(tmp_auth.get_total_duration(), 120);
+}
+
+/// Answers every request with a login response and counts how often a
+/// Transport for a client certificate was created
+#[derive(Clone)]
+struct IdentityTransport {
+    inner: MemoryTransport,
+    identities: Arc<AtomicUsize>,
+}
+
+#[async_trait::async_trait]
+impl hc_vault::transport::Transport for IdentityTransport {
+    async fn send(&self, req: Request) -> Result<Response, TransportError> {
+        self.inner.send(req).await
+    }
+
+    fn with_identity(
+        &self,
+        _identity: &hc_vault::ClientCert,
+    ) -> Result<Arc<dyn hc_vault::transport::Transport>, hc_vault::Error> {
+        self.identities.fetch_add(1, Ordering::SeqCst);
+        Ok(Arc::new(self.clone()))
+    }
+}
+
+#[tokio::test]
+async fn valid_identity_reused() {
+    let transport = IdentityTransport {
+        inner: MemoryTransport::new(|_| {
+            Ok(Response::from_json(
+                StatusCode::OK,
+                &json!({
+                    "auth": {
+                        "client_token": "testToken",
+                        "accessor": "testAccessor",
+                        "policies": ["test"],
+                        "lease_duration": 120,
+                        "renewable": true,
+                    },
+                }),
+            ))
+        }),
+        identities: Arc::new(AtomicUsize::new(0)),
+    };
+    let identities = transport.identities.clone();
+
+    let http = hc_vault::HttpClient::new(&hc_vault::Config {
+        transport: Some(Arc::new(transport)),
+        ..Default::default()
+    })
+    .unwrap();
+    let tmp_auth = hc_vault::cert::Session::new(None, TEST_CERT, TEST_KEY).unwrap();
+
+    for _ in 0..3 {
+        if let Err(e) = tmp_auth.auth(&http).await {
+            assert!(false, "Should not return error: '{}'", e);
+        }
+    }
+
+    assert_eq!(identities.load(Ordering::SeqCst), 1);
+}
@@ -0,0 +1,60 @@
+use wiremock::matchers::{header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use serde_json::json;
+
+/// Mocks the lookup of the given token as a renewable token, with a TTL of
+/// 120 seconds and only the default policy
+pub async fn mock_lookup_self(mock_server: &MockServer, token: &str) {
+    Mock::given(method("GET"))
+        .and(path("/v1/auth/token/lookup-self"))
+        .and(header("X-Vault-Token", token))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": {
+                "ttl": 120,
+                "renewable": true,
+                "policies": ["default"],
+            },
+        })))
+        .mount(mock_server)
+        .await;
+}
+
+/// Mocks the lookup of the token `testToken` as a root token, that never
+/// expires and can not be renewed
+pub async fn mock_root_lookup_self(mock_server: &MockServer) {
+    Mock::given(method("GET"))
+        .and(path("/v1/auth/token/lookup-self"))
+        .and(header("X-Vault-Token", "testToken"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": {
+                "ttl": 0,
+                "renewable": false,
+                "policies": ["root"],
+            },
+        })))
+        .mount(mock_server)
+        .await;
+}
+
+/// Creates a new client for the mock server, that uses the root token
+/// `testToken`
+pub async fn new_client(mock_server: &MockServer) -> hc_vault::Client<hc_vault::token::Session> {
+    let conf = hc_vault::Config {
+        vault_url: mock_server.uri(),
+        ..Default::default()
+    };
+    new_client_with_config(mock_server, conf).await
+}
+
+/// Creates a new client with the given config, that uses the root token
+/// `testToken`, the vault url of the config has to point to the mock server
+pub async fn new_client_with_config(
+    mock_server: &MockServer,
+    conf: hc_vault::Config,
+) -> hc_vault::Client<hc_vault::token::Session> {
+    mock_root_lookup_self(mock_server).await;
+
+    let auth = hc_vault::token::Session::new("testToken".to_string()).unwrap();
+    hc_vault::Client::new_async(conf, auth).await.unwrap()
+}
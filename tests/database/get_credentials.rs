@@ -7,6 +7,8 @@ use serde_json::json;
 
 use std::time::Duration;
 
+use crate::common;
+
 #[tokio::test]
 async fn valid_get_credentials() {
     let mock_server = MockServer::start().await;
@@ -36,13 +38,14 @@ async fn valid_get_credentials() {
         .mount(&mock_server)
         .await;
 
-    let auth =
-        hc_vault::token::Session::new(client_token.to_string(), Duration::from_secs(120)).unwrap();
+    common::mock_lookup_self(&mock_server, client_token).await;
+
+    let auth = hc_vault::token::Session::new(client_token.to_string()).unwrap();
     let conf = hc_vault::Config {
         vault_url: mock_server.uri().clone(),
         ..Default::default()
     };
-    let mut client = match hc_vault::Client::new(conf, auth) {
+    let client = match hc_vault::Client::new_async(conf, auth).await {
         Err(e) => {
             assert!(false, "Should not return error: '{}'", e);
             return;
@@ -50,7 +53,7 @@ async fn valid_get_credentials() {
         Ok(s) => s,
     };
 
-    let data = match hc_vault::database::get_credentials(&mut client, "test_db").await {
+    let data = match hc_vault::database::get_credentials(&client, "test_db").await {
         Err(e) => {
             assert!(false, "Should not return error: '{}'", e);
             return;
@@ -0,0 +1,194 @@
+extern crate hc_vault;
+
+use wiremock::matchers::{body_json, header, method, path, query_param};
+use wiremock::{Match, Mock, MockServer, Request, ResponseTemplate};
+
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use hc_vault::Auth as AuthTrait;
+
+const TEST_KEY: &str = include_str!("test_key.pem");
+const TEST_PUBLIC_KEY: &[u8] = include_bytes!("test_key.pub.pem");
+
+#[derive(Deserialize)]
+struct Claims {
+    sub: String,
+    aud: String,
+}
+
+/// Checks that the login contains a JWT signed by the test key
+/// with the expected claims
+struct IamJwtMatcher;
+
+impl Match for IamJwtMatcher {
+    fn matches(&self, request: &Request) -> bool {
+        let body: Value = match serde_json::from_slice(&request.body) {
+            Err(_) => return false,
+            Ok(b) => b,
+        };
+        let jwt = body["jwt"].as_str().unwrap_or_default();
+
+        let key = jsonwebtoken::DecodingKey::from_rsa_pem(TEST_PUBLIC_KEY).unwrap();
+        let mut validation = jsonwebtoken::Validation::new(jsonwebtoken::Algorithm::RS256);
+        validation.set_audience(&["vault/testRole"]);
+        let decoded = match jsonwebtoken::decode::<Claims>(jwt, &key, &validation) {
+            Err(_) => return false,
+            Ok(d) => d,
+        };
+
+        body["role"] == "testRole"
+            && decoded.header.kid == Some("testKeyID".to_string())
+            && decoded.claims.sub == "test@test-project.iam.gserviceaccount.com"
+            && decoded.claims.aud == "vault/testRole"
+    }
+}
+
+fn login_response() -> ResponseTemplate {
+    ResponseTemplate::new(200).set_body_json(json!({
+        "auth": {
+            "client_token": "testToken",
+            "accessor": "testAccessor",
+            "policies": ["test"],
+            "lease_duration": 120,
+            "renewable": true,
+        },
+    }))
+}
+
+fn test_key() -> hc_vault::gcp::ServiceAccountKey {
+    let key_file = json!({
+        "type": "service_account",
+        "project_id": "test-project",
+        "private_key_id": "testKeyID",
+        "private_key": TEST_KEY,
+        "client_email": "test@test-project.iam.gserviceaccount.com",
+    });
+
+    hc_vault::gcp::ServiceAccountKey::from_json(&key_file.to_string()).unwrap()
+}
+
+#[tokio::test]
+async fn valid_iam_login() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/gcp/login"))
+        .and(IamJwtMatcher)
+        .respond_with(login_response())
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let config = hc_vault::gcp::GcpConfig {
+        role: "testRole".to_string(),
+        ..Default::default()
+    };
+    let tmp_auth =
+        match hc_vault::gcp::Session::new(config, hc_vault::gcp::GcpMethod::Iam(test_key())) {
+            Err(e) => {
+                assert!(false, "Should not return error: '{}'", e);
+                return;
+            }
+            Ok(s) => s,
+        };
+
+    match tmp_auth
+        .auth(&hc_vault::HttpClient::from_url(&mock_server.uri()).unwrap())
+        .await
+    {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(_) => assert!(true),
+    };
+
+    assert_eq!(tmp_auth.get_token(), "testToken".to_string());
+    assert_eq!(tmp_auth.get_total_duration(), 120);
+}
+
+#[test]
+fn invalid_iam_key() {
+    let mut key = test_key();
+    key.private_key = "not a key".to_string();
+
+    let config = hc_vault::gcp::GcpConfig {
+        role: "testRole".to_string(),
+        ..Default::default()
+    };
+    match hc_vault::gcp::Session::new(config, hc_vault::gcp::GcpMethod::Iam(key)) {
+        Err(_) => assert!(true),
+        Ok(_) => assert!(false, "Should return error"),
+    };
+}
+
+#[tokio::test]
+async fn valid_gce_login() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path(
+            "/computeMetadata/v1/instance/service-accounts/default/identity",
+        ))
+        .and(header("Metadata-Flavor", "Google"))
+        .and(query_param("audience", "vault/testRole"))
+        .and(query_param("format", "full"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("testIdentityToken"))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/gcp-prod/login"))
+        .and(body_json(json!({
+            "role": "testRole",
+            "jwt": "testIdentityToken",
+        })))
+        .respond_with(login_response())
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let config = hc_vault::gcp::GcpConfig {
+        role: "testRole".to_string(),
+        mount: "gcp-prod".to_string(),
+        metadata_url: mock_server.uri(),
+        ..Default::default()
+    };
+    let tmp_auth = hc_vault::gcp::Session::new(config, hc_vault::gcp::GcpMethod::Gce).unwrap();
+
+    match tmp_auth
+        .auth(&hc_vault::HttpClient::from_url(&mock_server.uri()).unwrap())
+        .await
+    {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(_) => assert!(true),
+    };
+
+    assert_eq!(tmp_auth.get_token(), "testToken".to_string());
+}
+
+#[tokio::test]
+async fn invalid_gce_metadata_unavailable() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/gcp/login"))
+        .respond_with(login_response())
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let config = hc_vault::gcp::GcpConfig {
+        role: "testRole".to_string(),
+        metadata_url: mock_server.uri(),
+        ..Default::default()
+    };
+    let tmp_auth = hc_vault::gcp::Session::new(config, hc_vault::gcp::GcpMethod::Gce).unwrap();
+
+    match tmp_auth
+        .auth(&hc_vault::HttpClient::from_url(&mock_server.uri()).unwrap())
+        .await
+    {
+        Err(_) => assert!(true),
+        Ok(_) => assert!(false, "Should return error"),
+    };
+}
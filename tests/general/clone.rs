@@ -0,0 +1,168 @@
+extern crate hc_vault;
+
+use std::time::Duration;
+
+use wiremock::matchers::{header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use serde_json::json;
+
+fn auth_response(token: &str, lease_duration: u64) -> ResponseTemplate {
+    ResponseTemplate::new(200).set_body_json(json!({
+        "auth": {
+            "client_token": token,
+            "accessor": "testAccessor",
+            "policies": ["default"],
+            "token_policies": ["default"],
+            "lease_duration": lease_duration,
+            "renewable": true,
+        },
+        "lease_id": "",
+        "lease_duration": 0,
+        "renewable": false,
+    }))
+}
+
+fn new_config(mock_server: &MockServer, renew_policy: hc_vault::RenewPolicy) -> hc_vault::Config {
+    hc_vault::Config {
+        vault_url: mock_server.uri(),
+        renew_policy,
+        retry: hc_vault::RetryPolicy::disabled(),
+        ..Default::default()
+    }
+}
+
+/// Accepts any Client, without being generic over its auth backend
+async fn read_password(client: &hc_vault::DynClient) -> Result<serde_json::Value, hc_vault::Error> {
+    let response = client.read::<serde_json::Value>("secret/app").await?;
+    Ok(response.data["password"].clone())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn valid_clones_share_session() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/approle/login"))
+        .respond_with(auth_response("testToken", 60))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v1/secret/app"))
+        .and(header("X-Vault-Token", "testToken"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({"data": {}})))
+        .expect(4)
+        .mount(&mock_server)
+        .await;
+
+    let auth =
+        hc_vault::approle::Session::new("testID".to_string(), "testSecret".to_string()).unwrap();
+    let client = hc_vault::Client::new_async(
+        new_config(&mock_server, hc_vault::RenewPolicy::Reauth),
+        auth,
+    )
+    .await
+    .unwrap();
+
+    let tasks: Vec<_> = (0..4)
+        .map(|_| {
+            let client = client.clone();
+            tokio::spawn(async move { client.read::<serde::de::IgnoredAny>("secret/app").await })
+        })
+        .collect();
+
+    for task in tasks {
+        match task.await.unwrap() {
+            Err(e) => assert!(false, "Should not return error: '{}'", e),
+            Ok(_) => assert!(true),
+        };
+    }
+}
+
+#[tokio::test]
+async fn valid_clones_share_renewal_task() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/approle/login"))
+        .respond_with(auth_response("testToken", 60))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let auth =
+        hc_vault::approle::Session::new("testID".to_string(), "testSecret".to_string()).unwrap();
+    let client = hc_vault::Client::new_async(
+        new_config(&mock_server, hc_vault::RenewPolicy::Renew(0.5)),
+        auth,
+    )
+    .await
+    .unwrap();
+    let cloned = client.with_namespace("tenant-a").clone();
+    assert!(cloned.renewal_handle().is_none());
+
+    let (task, _) = client.renewal_task().unwrap();
+    let task = tokio::spawn(task);
+
+    match cloned.renewal_task() {
+        Err(hc_vault::RenewError::AlreadyRunning) => assert!(true),
+        Err(e) => assert!(false, "Should return AlreadyRunning, got: '{}'", e),
+        Ok(_) => assert!(false, "Should return error"),
+    };
+
+    // The task can be stopped using any of the clones
+    match cloned.renewal_handle() {
+        Some(handle) => handle.stop(),
+        None => assert!(false, "Should share the renewal task"),
+    };
+    tokio::time::timeout(Duration::from_secs(5), task)
+        .await
+        .expect("The task should stop")
+        .unwrap();
+
+    // Once stopped, a new renewal task can be started
+    match cloned.renewal_task() {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok((_, handle)) => assert_eq!(handle.state().stopped, false),
+    };
+}
+
+#[tokio::test]
+async fn valid_dyn_client() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/approle/login"))
+        .respond_with(auth_response("testToken", 60))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v1/secret/app"))
+        .and(header("X-Vault-Token", "testToken"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": {
+                "password": "hunter2",
+            },
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let auth: Box<dyn hc_vault::Auth> = Box::new(
+        hc_vault::approle::Session::new("testID".to_string(), "testSecret".to_string()).unwrap(),
+    );
+    let client: hc_vault::DynClient = hc_vault::Client::new_async(
+        new_config(&mock_server, hc_vault::RenewPolicy::Reauth),
+        auth,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(client.get_token(), "testToken");
+    match read_password(&client).await {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(password) => assert_eq!(password, json!("hunter2")),
+    };
+}
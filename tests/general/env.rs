@@ -0,0 +1,292 @@
+extern crate hc_vault;
+
+use std::env;
+use std::path::PathBuf;
+use std::sync::{Mutex, MutexGuard};
+use std::time::Duration;
+
+use hc_vault::Auth as AuthTrait;
+
+// The environment is shared by all tests, so the tests in here need to run
+// one after another
+static ENV_LOCK: Mutex<()> = Mutex::new(());
+
+const VARS: &[&str] = &[
+    "VAULT_ADDR",
+    "VAULT_AGENT_ADDR",
+    "VAULT_TOKEN",
+    "VAULT_NAMESPACE",
+    "VAULT_CACERT",
+    "VAULT_CAPATH",
+    "VAULT_CLIENT_CERT",
+    "VAULT_CLIENT_KEY",
+    "VAULT_TLS_SERVER_NAME",
+    "VAULT_SKIP_VERIFY",
+    "VAULT_CLIENT_TIMEOUT",
+    "VAULT_MAX_RETRIES",
+];
+
+fn clean_env() -> MutexGuard<'static, ()> {
+    let guard = ENV_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    for name in VARS {
+        env::remove_var(name);
+    }
+    guard
+}
+
+fn test_file(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/general")
+        .join(name)
+}
+
+#[test]
+fn valid_defaults() {
+    let _guard = clean_env();
+
+    let config = hc_vault::Config::from_env().unwrap();
+    assert_eq!(config.vault_url, "http://localhost:8200".to_string());
+    assert_eq!(config.namespace, None);
+    assert_eq!(config.tls.ca_cert_file, None);
+    assert_eq!(config.tls.ca_cert_dir, None);
+    assert_eq!(config.tls.server_name, None);
+    assert_eq!(config.tls.insecure_skip_verify, false);
+    assert!(config.tls.client_cert.is_none());
+    assert_eq!(config.timeout, Some(Duration::from_secs(60)));
+    assert_eq!(config.retry.max_retries, 2);
+}
+
+#[test]
+fn valid_addr() {
+    let _guard = clean_env();
+    env::set_var("VAULT_ADDR", "https://vault.test:8200");
+
+    let config = hc_vault::Config::from_env().unwrap();
+    assert_eq!(config.vault_url, "https://vault.test:8200".to_string());
+}
+
+#[test]
+fn valid_agent_addr_precedence() {
+    let _guard = clean_env();
+    env::set_var("VAULT_ADDR", "https://vault.test:8200");
+    env::set_var("VAULT_AGENT_ADDR", "http://127.0.0.1:8100");
+
+    let config = hc_vault::Config::from_env().unwrap();
+    assert_eq!(config.vault_url, "http://127.0.0.1:8100".to_string());
+}
+
+#[test]
+fn valid_namespace() {
+    let _guard = clean_env();
+    env::set_var("VAULT_NAMESPACE", "tenant-a");
+
+    let config = hc_vault::Config::from_env().unwrap();
+    assert_eq!(config.namespace, Some("tenant-a".to_string()));
+}
+
+#[test]
+fn valid_tls() {
+    let _guard = clean_env();
+    env::set_var("VAULT_CACERT", test_file("tls_ca.pem"));
+    env::set_var("VAULT_CAPATH", test_file(""));
+    env::set_var("VAULT_CLIENT_CERT", test_file("tls_client.pem"));
+    env::set_var("VAULT_CLIENT_KEY", test_file("tls_client_key.pem"));
+    env::set_var("VAULT_TLS_SERVER_NAME", "vault.test");
+    env::set_var("VAULT_SKIP_VERIFY", "true");
+
+    let config = hc_vault::Config::from_env().unwrap();
+    assert_eq!(config.tls.ca_cert_file, Some(test_file("tls_ca.pem")));
+    assert_eq!(config.tls.ca_cert_dir, Some(test_file("")));
+    assert_eq!(config.tls.server_name, Some("vault.test".to_string()));
+    assert_eq!(config.tls.insecure_skip_verify, true);
+
+    let client_cert = config.tls.client_cert.unwrap();
+    assert_eq!(
+        client_cert.cert_pem,
+        include_bytes!("tls_client.pem").to_vec()
+    );
+    assert_eq!(
+        client_cert.key_pem,
+        include_bytes!("tls_client_key.pem").to_vec()
+    );
+}
+
+#[test]
+fn invalid_skip_verify() {
+    let _guard = clean_env();
+    env::set_var("VAULT_SKIP_VERIFY", "maybe");
+
+    match hc_vault::Config::from_env() {
+        Err(e) => match e {
+            hc_vault::Error::InvalidEnvVar(_) => assert!(true),
+            _ => assert!(false, "Should return InvalidEnvVar, got: '{}'", e),
+        },
+        Ok(_) => assert!(false, "Should return error"),
+    };
+}
+
+#[test]
+fn valid_client_timeout() {
+    let _guard = clean_env();
+
+    env::set_var("VAULT_CLIENT_TIMEOUT", "15");
+    let config = hc_vault::Config::from_env().unwrap();
+    assert_eq!(config.timeout, Some(Duration::from_secs(15)));
+
+    env::set_var("VAULT_CLIENT_TIMEOUT", "2m");
+    let config = hc_vault::Config::from_env().unwrap();
+    assert_eq!(config.timeout, Some(Duration::from_secs(120)));
+
+    env::set_var("VAULT_CLIENT_TIMEOUT", "500ms");
+    let config = hc_vault::Config::from_env().unwrap();
+    assert_eq!(config.timeout, Some(Duration::from_millis(500)));
+
+    env::set_var("VAULT_CLIENT_TIMEOUT", "1m30s");
+    let config = hc_vault::Config::from_env().unwrap();
+    assert_eq!(config.timeout, Some(Duration::from_secs(90)));
+
+    env::set_var("VAULT_CLIENT_TIMEOUT", "1.5s");
+    let config = hc_vault::Config::from_env().unwrap();
+    assert_eq!(config.timeout, Some(Duration::from_millis(1500)));
+}
+
+#[test]
+fn invalid_client_timeout() {
+    let _guard = clean_env();
+    env::set_var("VAULT_CLIENT_TIMEOUT", "soon");
+
+    match hc_vault::Config::from_env() {
+        Err(e) => match e {
+            hc_vault::Error::InvalidEnvVar(_) => assert!(true),
+            _ => assert!(false, "Should return InvalidEnvVar, got: '{}'", e),
+        },
+        Ok(_) => assert!(false, "Should return error"),
+    };
+}
+
+#[test]
+fn invalid_client_timeout_overflow() {
+    let _guard = clean_env();
+
+    for value in &["9999999999999999h", "18446744073709551615s"] {
+        env::set_var("VAULT_CLIENT_TIMEOUT", value);
+
+        match hc_vault::Config::from_env() {
+            Err(e) => match e {
+                hc_vault::Error::InvalidEnvVar(_) => assert!(true),
+                _ => assert!(false, "Should return InvalidEnvVar, got: '{}'", e),
+            },
+            Ok(_) => assert!(false, "Should return error for '{}'", value),
+        };
+    }
+}
+
+#[test]
+fn invalid_client_timeout_missing_unit() {
+    let _guard = clean_env();
+    env::set_var("VAULT_CLIENT_TIMEOUT", "1m30");
+
+    match hc_vault::Config::from_env() {
+        Err(e) => match e {
+            hc_vault::Error::InvalidEnvVar(_) => assert!(true),
+            _ => assert!(false, "Should return InvalidEnvVar, got: '{}'", e),
+        },
+        Ok(_) => assert!(false, "Should return error"),
+    };
+}
+
+#[test]
+fn valid_max_retries() {
+    let _guard = clean_env();
+
+    env::set_var("VAULT_MAX_RETRIES", "5");
+    let config = hc_vault::Config::from_env().unwrap();
+    assert_eq!(config.retry.max_retries, 5);
+
+    env::set_var("VAULT_MAX_RETRIES", "0");
+    let config = hc_vault::Config::from_env().unwrap();
+    assert_eq!(config.retry.max_retries, 0);
+}
+
+#[test]
+fn invalid_max_retries() {
+    let _guard = clean_env();
+    env::set_var("VAULT_MAX_RETRIES", "-1");
+
+    match hc_vault::Config::from_env() {
+        Err(e) => match e {
+            hc_vault::Error::InvalidEnvVar(_) => assert!(true),
+            _ => assert!(false, "Should return InvalidEnvVar, got: '{}'", e),
+        },
+        Ok(_) => assert!(false, "Should return error"),
+    };
+}
+
+#[test]
+fn invalid_client_cert_without_key() {
+    let _guard = clean_env();
+    env::set_var("VAULT_CLIENT_CERT", test_file("tls_client.pem"));
+
+    match hc_vault::Config::from_env() {
+        Err(e) => match e {
+            hc_vault::Error::InvalidEnvVar(_) => assert!(true),
+            _ => assert!(false, "Should return InvalidEnvVar, got: '{}'", e),
+        },
+        Ok(_) => assert!(false, "Should return error"),
+    };
+}
+
+#[test]
+fn valid_token_from_env() {
+    let _guard = clean_env();
+    env::set_var("VAULT_TOKEN", "testToken");
+
+    let session = hc_vault::token::Session::from_env().unwrap();
+    assert_eq!(session.get_token(), "testToken".to_string());
+}
+
+#[test]
+fn valid_token_from_file() {
+    let _guard = clean_env();
+    let home = env::temp_dir().join(format!("hc-vault-env-{}", std::process::id()));
+    std::fs::create_dir_all(&home).unwrap();
+    std::fs::write(home.join(".vault-token"), "fileToken\n").unwrap();
+
+    let old_home = env::var_os("HOME");
+    env::set_var("HOME", &home);
+    let result = hc_vault::token::Session::from_env();
+    match old_home {
+        Some(old_home) => env::set_var("HOME", old_home),
+        None => env::remove_var("HOME"),
+    };
+    std::fs::remove_dir_all(&home).unwrap();
+
+    match result {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(session) => assert_eq!(session.get_token(), "fileToken".to_string()),
+    };
+}
+
+#[test]
+fn invalid_token_missing() {
+    let _guard = clean_env();
+    let home = env::temp_dir().join(format!("hc-vault-env-empty-{}", std::process::id()));
+    std::fs::create_dir_all(&home).unwrap();
+
+    let old_home = env::var_os("HOME");
+    env::set_var("HOME", &home);
+    let result = hc_vault::token::Session::from_env();
+    match old_home {
+        Some(old_home) => env::set_var("HOME", old_home),
+        None => env::remove_var("HOME"),
+    };
+    std::fs::remove_dir_all(&home).unwrap();
+
+    match result {
+        Err(e) => match e {
+            hc_vault::Error::MissingCredentials(_) => assert!(true),
+            _ => assert!(false, "Should return MissingCredentials, got: '{}'", e),
+        },
+        Ok(_) => assert!(false, "Should return error"),
+    };
+}
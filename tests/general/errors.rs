@@ -0,0 +1,120 @@
+extern crate hc_vault;
+
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use serde_json::json;
+
+use crate::common::new_client_with_config;
+
+async fn new_client(mock_server: &MockServer) -> hc_vault::Client<hc_vault::token::Session> {
+    // The error mapping is tested here, so failed requests are not retried
+    let conf = hc_vault::Config {
+        vault_url: mock_server.uri(),
+        retry: hc_vault::RetryPolicy::disabled(),
+        ..Default::default()
+    };
+    new_client_with_config(mock_server, conf).await
+}
+
+#[tokio::test]
+async fn response_error_details() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/secret/data/test"))
+        .respond_with(ResponseTemplate::new(403).set_body_json(json!({
+            "errors": ["1 error occurred:\n\t* permission denied\n\n"],
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = new_client(&mock_server).await;
+
+    let err = match client
+        .vault_request::<String>(reqwest::Method::GET, "secret/data/test", None)
+        .await
+    {
+        Err(e) => e,
+        Ok(_) => {
+            assert!(false, "Should return error");
+            return;
+        }
+    };
+
+    let details = match err {
+        hc_vault::Error::Unauthorized(ref details) => details,
+        ref e => {
+            assert!(false, "Should return unauthorized error: '{}'", e);
+            return;
+        }
+    };
+
+    assert_eq!(details.status, 403);
+    assert_eq!(details.method, "GET".to_string());
+    assert_eq!(details.path, "/v1/secret/data/test".to_string());
+    assert_eq!(
+        details.errors,
+        vec!["1 error occurred:\n\t* permission denied\n\n".to_string()]
+    );
+    assert_eq!(err.status(), Some(403));
+}
+
+#[tokio::test]
+async fn status_variants() {
+    let mock_server = MockServer::start().await;
+
+    let client = new_client(&mock_server).await;
+
+    for status in &[400, 404, 412, 429, 500, 502, 503, 418] {
+        Mock::given(method("POST"))
+            .and(path(format!("/v1/status/{}", status).as_str()))
+            .respond_with(ResponseTemplate::new(*status))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let path = format!("status/{}", status);
+        let err = match client
+            .vault_request::<String>(reqwest::Method::POST, &path, None)
+            .await
+        {
+            Err(e) => e,
+            Ok(_) => {
+                assert!(false, "Should return error for {}", status);
+                return;
+            }
+        };
+
+        let matches = matches!(
+            (status, &err),
+            (400, hc_vault::Error::InvalidRequest(_))
+                | (404, hc_vault::Error::NotFound(_))
+                | (412, hc_vault::Error::ConsistencyNotSatisfied(_))
+                | (429, hc_vault::Error::RateLimited(_))
+                | (500, hc_vault::Error::Internal(_))
+                | (502, hc_vault::Error::Internal(_))
+                | (503, hc_vault::Error::IsSealed(_))
+                | (418, hc_vault::Error::UnexpectedStatus(_))
+        );
+        assert!(matches, "Wrong error for {}: '{}'", status, err);
+        assert_eq!(err.status(), Some(*status));
+        assert_eq!(err.response().unwrap().errors, Vec::<String>::new());
+    }
+}
+
+#[test]
+fn error_source() {
+    use std::error::Error as StdError;
+
+    let err = hc_vault::Error::from(url::Url::parse("not a url").unwrap_err());
+    assert!(err.source().is_some());
+    assert_eq!(err.status(), None);
+
+    let renew_err = hc_vault::RenewError::from(err);
+    let source = renew_err.source().unwrap();
+    assert!(source.downcast_ref::<hc_vault::Error>().is_some());
+
+    assert!(hc_vault::RenewError::NotEnabled.source().is_none());
+}
@@ -0,0 +1,170 @@
+extern crate hc_vault;
+
+use wiremock::matchers::{body_json, header, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::common::new_client;
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Secret {
+    password: String,
+}
+
+#[tokio::test]
+async fn valid_read() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/secret/app"))
+        .and(header("X-Vault-Token", "testToken"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "request_id": "testRequestID",
+            "lease_duration": 2764800,
+            "data": {
+                "password": "hunter2",
+            },
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = new_client(&mock_server).await;
+
+    match client.read::<Secret>("secret/app").await {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(response) => {
+            assert_eq!(response.request_id, "testRequestID".to_string());
+            assert_eq!(response.lease_duration, 2764800);
+            assert_eq!(
+                response.data,
+                Secret {
+                    password: "hunter2".to_string()
+                }
+            );
+        }
+    };
+}
+
+#[tokio::test]
+async fn valid_write_no_content() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/secret/app"))
+        .and(body_json(json!({
+            "password": "hunter2",
+        })))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = new_client(&mock_server).await;
+
+    let body = Secret {
+        password: "hunter2".to_string(),
+    };
+    match client.write::<Secret, Secret>("secret/app", &body).await {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(response) => assert!(response.is_none()),
+    };
+}
+
+#[tokio::test]
+async fn valid_write_with_response() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/transit/random/8"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": {
+                "password": "cmFuZG9t",
+            },
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = new_client(&mock_server).await;
+
+    match client
+        .write::<_, Secret>("transit/random/8", &json!({}))
+        .await
+    {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(response) => assert_eq!(response.unwrap().data.password, "cmFuZG9t".to_string()),
+    };
+}
+
+#[tokio::test]
+async fn valid_list() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/secret/apps"))
+        .and(query_param("list", "true"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": {
+                "keys": ["first", "nested/"],
+            },
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = new_client(&mock_server).await;
+
+    match client.list("secret/apps").await {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(response) => assert_eq!(
+            response.data.keys,
+            vec!["first".to_string(), "nested/".to_string()]
+        ),
+    };
+}
+
+#[tokio::test]
+async fn valid_delete() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/v1/secret/app"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = new_client(&mock_server).await;
+
+    match client.delete("secret/app").await {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(_) => assert!(true),
+    };
+}
+
+#[tokio::test]
+async fn invalid_read_body() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/secret/app"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": {
+                "other": 1,
+            },
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = new_client(&mock_server).await;
+
+    match client.read::<Secret>("secret/app").await {
+        Err(hc_vault::Error::JSONError(_)) => assert!(true),
+        Err(e) => assert!(false, "Should return json error: '{}'", e),
+        Ok(_) => assert!(false, "Should return error"),
+    };
+}
@@ -0,0 +1,72 @@
+extern crate hc_vault;
+
+use std::time::Duration;
+
+use wiremock::matchers::{header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use serde_json::json;
+
+#[test]
+fn invalid_url() {
+    match hc_vault::HttpClient::from_url("not a url") {
+        Err(e) => match e {
+            hc_vault::Error::ParseError(_) => assert!(true),
+            _ => assert!(false, "Should return ParseError, got: '{}'", e),
+        },
+        Ok(_) => assert!(false, "Should return error"),
+    };
+}
+
+#[tokio::test]
+async fn valid_pool_settings() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/auth/token/lookup-self"))
+        .and(header("X-Vault-Token", "testToken"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": {
+                "ttl": 0,
+                "renewable": false,
+                "policies": ["root"],
+            },
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v1/secret/app"))
+        .and(header("X-Vault-Token", "testToken"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": {
+                "password": "hunter2",
+            },
+        })))
+        .expect(3)
+        .mount(&mock_server)
+        .await;
+
+    let auth = hc_vault::token::Session::new("testToken".to_string()).unwrap();
+    let conf = hc_vault::Config {
+        vault_url: mock_server.uri(),
+        pool_max_idle_per_host: 1,
+        pool_idle_timeout: Some(Duration::from_secs(5)),
+        tcp_keepalive: Some(Duration::from_secs(30)),
+        ..Default::default()
+    };
+    let client = match hc_vault::Client::new_async(conf, auth).await {
+        Err(e) => {
+            assert!(false, "Should not return error: '{}'", e);
+            return;
+        }
+        Ok(c) => c,
+    };
+
+    for _ in 0..3 {
+        match client.read::<serde::de::IgnoredAny>("secret/app").await {
+            Err(e) => assert!(false, "Should not return error: '{}'", e),
+            Ok(_) => assert!(true),
+        };
+    }
+}
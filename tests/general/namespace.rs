@@ -0,0 +1,212 @@
+extern crate hc_vault;
+
+use wiremock::matchers::{header, method, path};
+use wiremock::{Match, Mock, MockServer, Request, ResponseTemplate};
+
+use serde_json::json;
+
+use hc_vault::Auth as AuthTrait;
+
+/// Matches requests that do not select any namespace
+struct NoNamespace;
+
+impl Match for NoNamespace {
+    fn matches(&self, request: &Request) -> bool {
+        !request
+            .headers
+            .keys()
+            .any(|name| name.as_str().eq_ignore_ascii_case("X-Vault-Namespace"))
+    }
+}
+
+async fn new_client(
+    mock_server: &MockServer,
+    namespace: Option<&str>,
+) -> hc_vault::Client<hc_vault::token::Session> {
+    let auth = hc_vault::token::Session::new("testToken".to_string()).unwrap();
+    let conf = hc_vault::Config {
+        vault_url: mock_server.uri(),
+        namespace: namespace.map(|n| n.to_string()),
+        ..Default::default()
+    };
+    hc_vault::Client::new_async(conf, auth).await.unwrap()
+}
+
+fn lookup_response() -> ResponseTemplate {
+    ResponseTemplate::new(200).set_body_json(json!({
+        "data": {
+            "ttl": 0,
+            "renewable": false,
+            "policies": ["root"],
+        },
+    }))
+}
+
+fn secret_response() -> ResponseTemplate {
+    ResponseTemplate::new(200).set_body_json(json!({
+        "data": {
+            "password": "hunter2",
+        },
+    }))
+}
+
+#[tokio::test]
+async fn valid_config_namespace() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/auth/token/lookup-self"))
+        .and(header("X-Vault-Namespace", "tenant-a"))
+        .respond_with(lookup_response())
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v1/secret/app"))
+        .and(header("X-Vault-Namespace", "tenant-a"))
+        .respond_with(secret_response())
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = new_client(&mock_server, Some("tenant-a")).await;
+    assert_eq!(client.namespace(), Some("tenant-a"));
+
+    match client.read::<serde::de::IgnoredAny>("secret/app").await {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(_) => assert!(true),
+    };
+}
+
+#[tokio::test]
+async fn valid_root_namespace() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/auth/token/lookup-self"))
+        .and(NoNamespace)
+        .respond_with(lookup_response())
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v1/secret/app"))
+        .and(NoNamespace)
+        .respond_with(secret_response())
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = new_client(&mock_server, Some("")).await;
+    assert_eq!(client.namespace(), None);
+
+    match client.read::<serde::de::IgnoredAny>("secret/app").await {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(_) => assert!(true),
+    };
+}
+
+#[tokio::test]
+async fn valid_with_namespace() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/auth/token/lookup-self"))
+        .and(header("X-Vault-Namespace", "tenant-a"))
+        .respond_with(lookup_response())
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v1/secret/app"))
+        .and(header("X-Vault-Namespace", "tenant-a/team-b"))
+        .respond_with(secret_response())
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v1/secret/app"))
+        .and(NoNamespace)
+        .respond_with(secret_response())
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = new_client(&mock_server, Some("tenant-a")).await;
+
+    let team = client.with_namespace("tenant-a/team-b");
+    assert_eq!(team.namespace(), Some("tenant-a/team-b"));
+    assert_eq!(team.get_token(), client.get_token());
+    match team.read::<serde::de::IgnoredAny>("secret/app").await {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(_) => assert!(true),
+    };
+
+    let root = client.with_namespace("");
+    assert_eq!(root.namespace(), None);
+    match root.read::<serde::de::IgnoredAny>("secret/app").await {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(_) => assert!(true),
+    };
+
+    // The original client still uses its own namespace
+    assert_eq!(client.namespace(), Some("tenant-a"));
+}
+
+#[tokio::test]
+async fn valid_auth_namespace() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/approle/login"))
+        .and(header("X-Vault-Namespace", "tenant-a"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "auth": {
+                "client_token": "testToken",
+                "accessor": "testAccessor",
+                "token_policies": ["test"],
+                "lease_duration": 120,
+                "renewable": true,
+            },
+            "lease_id": "",
+            "lease_duration": 0,
+            "renewable": false,
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/token/renew-self"))
+        .and(header("X-Vault-Namespace", "tenant-a"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "auth": {
+                "client_token": "testToken",
+                "policies": ["test"],
+                "lease_duration": 120,
+                "renewable": true,
+            },
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let http = hc_vault::HttpClient::new(&hc_vault::Config {
+        vault_url: mock_server.uri(),
+        namespace: Some("tenant-a".to_string()),
+        ..Default::default()
+    })
+    .unwrap();
+    assert_eq!(http.namespace(), Some("tenant-a"));
+
+    let tmp_auth =
+        hc_vault::approle::Session::new("testID".to_string(), "testSecret".to_string()).unwrap();
+
+    match tmp_auth.auth(&http).await {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(_) => assert!(true),
+    };
+    match tmp_auth.renew(&http).await {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(_) => assert!(true),
+    };
+}
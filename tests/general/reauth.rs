@@ -1,7 +1,5 @@
 extern crate hc_vault;
 
-use async_std::task;
-
 use wiremock::matchers::{body_json, method, path};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
@@ -25,9 +23,9 @@ struct ApproleResponse {
     lease_id: String,
 }
 
-#[test]
-fn valid_auth_from_multiple_threads() {
-    let mock_server = task::block_on(MockServer::start());
+#[tokio::test(flavor = "multi_thread")]
+async fn valid_auth_from_multiple_threads() {
+    let mock_server = MockServer::start().await;
 
     let test_role_id = "testID".to_string();
     let test_secret_id = "testSecret".to_string();
@@ -50,14 +48,13 @@ fn valid_auth_from_multiple_threads() {
         lease_id: "".to_string(),
     });
 
-    task::block_on(
-        Mock::given(method("POST"))
-            .and(path("/v1/auth/approle/login"))
-            .and(body_json(&expected_body))
-            .respond_with(first_response)
-            .expect(1)
-            .mount(&mock_server),
-    );
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/approle/login"))
+        .and(body_json(&expected_body))
+        .respond_with(first_response)
+        .expect(1)
+        .mount(&mock_server)
+        .await;
 
     let tmp_auth = match hc_vault::approle::Session::new(test_role_id, test_secret_id) {
         Err(e) => {
@@ -73,7 +70,7 @@ fn valid_auth_from_multiple_threads() {
         vault_url: mock_server.uri(),
         ..Default::default()
     };
-    let tmp_client = match hc_vault::Client::new(config, tmp_auth) {
+    let tmp_client = match hc_vault::Client::new_async(config, tmp_auth).await {
         Err(e) => {
             assert!(false, "Should not return error: {}", e);
             return;
@@ -83,7 +80,7 @@ fn valid_auth_from_multiple_threads() {
 
     println!("After client");
 
-    task::block_on(mock_server.reset());
+    mock_server.reset().await;
 
     let second_response = ResponseTemplate::new(200).set_body_json(ApproleResponse {
         auth: ApproleAuthResponse {
@@ -98,37 +95,35 @@ fn valid_auth_from_multiple_threads() {
         lease_id: "".to_string(),
     });
 
-    task::block_on(
-        Mock::given(method("POST"))
-            .and(path("/v1/auth/approle/login"))
-            .respond_with(second_response)
-            .expect(1)
-            .mount(&mock_server),
-    );
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/approle/login"))
+        .respond_with(second_response)
+        .expect(1)
+        .mount(&mock_server)
+        .await;
 
     let client_arc = std::sync::Arc::new(tmp_client);
-    let barrier = std::sync::Arc::new(std::sync::Barrier::new(5));
-    let done_barrier = std::sync::Arc::new(std::sync::Barrier::new(6));
+    let barrier = std::sync::Arc::new(tokio::sync::Barrier::new(5));
 
+    let mut handles = Vec::new();
     for _ in 0..5 {
         let c = std::sync::Arc::clone(&barrier);
         let c_client = std::sync::Arc::clone(&client_arc);
-        let c_done = std::sync::Arc::clone(&done_barrier);
 
-        std::thread::spawn(move || {
-            c.wait();
-            match task::block_on(c_client.check_session()) {
+        handles.push(tokio::spawn(async move {
+            c.wait().await;
+            match c_client.check_session().await {
                 Err(e) => {
                     assert!(false, "Should not return error {}", e);
                 }
                 Ok(_) => {}
             };
-
-            c_done.wait();
-        });
+        }));
     }
 
-    done_barrier.wait();
+    for handle in handles {
+        handle.await.unwrap();
+    }
 
     assert_eq!(client_arc.get_token(), String::from("concurrentToken"));
 }
@@ -36,6 +36,7 @@ fn valid_renew_then_fail() {
     let config = hc_vault::Config {
         vault_url: mock_server.uri(),
         renew_policy: hc_vault::RenewPolicy::Renew(0.75),
+        ..Default::default()
     };
 
     let client = hc_vault::Client::new(config, auth).unwrap();
@@ -103,6 +104,7 @@ fn renew_cant_renew() {
     let config = hc_vault::Config {
         vault_url: mock_server.uri(),
         renew_policy: hc_vault::RenewPolicy::Renew(0.75),
+        ..Default::default()
     };
 
     let client = hc_vault::Client::new(config, auth).unwrap();
@@ -151,6 +153,7 @@ fn renew_not_enabled() {
     let config = hc_vault::Config {
         vault_url: mock_server.uri(),
         renew_policy: hc_vault::RenewPolicy::Nothing,
+        ..Default::default()
     };
 
     let client = hc_vault::Client::new(config, auth).unwrap();
@@ -169,3 +172,183 @@ fn renew_not_enabled() {
         }
     }
 }
+
+#[tokio::test]
+async fn valid_renew_async_then_fail() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/approle/login"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "auth": {
+                "renewable": true,
+                "lease_duration": 2,
+                "token_policies": vec!["test"],
+                "accessor": "testAccessor",
+                "client_token": "testToken",
+            },
+            "lease_duration": 0,
+            "renewable": true,
+            "lease_id": "",
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/token/renew-self"))
+        .and(header("X-Vault-Token", "testToken"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "auth": {
+                "client_token": "testToken",
+                "policies": vec!["test"],
+                "lease_duration": 2,
+                "renewable": true,
+            },
+        })))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let auth = hc_vault::approle::Session::new("test".to_string(), "test".to_string()).unwrap();
+
+    let config = hc_vault::Config {
+        vault_url: mock_server.uri(),
+        renew_policy: hc_vault::RenewPolicy::Renew(0.5),
+        retry: hc_vault::RetryPolicy::disabled(),
+        ..Default::default()
+    };
+
+    let client = hc_vault::Client::new_async(config, auth).await.unwrap();
+
+    match client.renew_background_async().await {
+        Err(hc_vault::RenewError::AuthError(_)) => assert!(true),
+        Err(e) => assert!(false, "Should return AuthError, got: {}", e),
+        Ok(_) => assert!(false, "Should return error"),
+    };
+}
+
+#[tokio::test]
+async fn valid_renew_async_relogin_at_max_ttl() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/approle/login"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "auth": {
+                "renewable": true,
+                "lease_duration": 2,
+                "token_policies": vec!["test"],
+                "accessor": "testAccessor",
+                "client_token": "oldToken",
+            },
+            "lease_duration": 0,
+            "renewable": true,
+            "lease_id": "",
+        })))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    // The new session can not be renewed, which ends the loop
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/approle/login"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "auth": {
+                "renewable": false,
+                "lease_duration": 60,
+                "token_policies": vec!["test"],
+                "accessor": "testAccessor",
+                "client_token": "newToken",
+            },
+            "lease_duration": 0,
+            "renewable": true,
+            "lease_id": "",
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    // Vault caps the lease, because the token reached its max TTL
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/token/renew-self"))
+        .and(header("X-Vault-Token", "oldToken"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "auth": {
+                "client_token": "oldToken",
+                "policies": vec!["test"],
+                "lease_duration": 1,
+                "renewable": true,
+            },
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let auth = hc_vault::approle::Session::new("test".to_string(), "test".to_string()).unwrap();
+
+    let config = hc_vault::Config {
+        vault_url: mock_server.uri(),
+        renew_policy: hc_vault::RenewPolicy::Renew(0.75),
+        retry: hc_vault::RetryPolicy::disabled(),
+        ..Default::default()
+    };
+
+    let client = hc_vault::Client::new_async(config, auth).await.unwrap();
+
+    match client.renew_background_async().await {
+        Err(hc_vault::RenewError::NotRenewable) => assert!(true),
+        Err(e) => assert!(false, "Should return NotRenewable, got: {}", e),
+        Ok(_) => assert!(false, "Should return error"),
+    };
+    assert_eq!(client.get_token(), "newToken");
+}
+
+#[tokio::test]
+async fn invalid_new_inside_runtime() {
+    let auth = hc_vault::token::Session::new("testToken".to_string()).unwrap();
+    let config = hc_vault::Config {
+        vault_url: "http://127.0.0.1:8200".to_string(),
+        ..Default::default()
+    };
+
+    match hc_vault::Client::new(config, auth) {
+        Err(hc_vault::Error::InsideRuntime) => assert!(true),
+        Err(e) => assert!(false, "Should return InsideRuntime, got: '{}'", e),
+        Ok(_) => assert!(false, "Should return error"),
+    };
+}
+
+#[tokio::test]
+async fn invalid_renew_background_inside_runtime() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/approle/login"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "auth": {
+                "renewable": true,
+                "lease_duration": 10,
+                "token_policies": vec!["test"],
+                "accessor": "testAccessor",
+                "client_token": "testToken",
+            },
+            "lease_duration": 0,
+            "renewable": true,
+            "lease_id": "",
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let auth = hc_vault::approle::Session::new("test".to_string(), "test".to_string()).unwrap();
+    let config = hc_vault::Config {
+        vault_url: mock_server.uri(),
+        renew_policy: hc_vault::RenewPolicy::Renew(0.75),
+        ..Default::default()
+    };
+    let client = hc_vault::Client::new_async(config, auth).await.unwrap();
+
+    match client.renew_background() {
+        Err(hc_vault::RenewError::AuthError(hc_vault::Error::InsideRuntime)) => assert!(true),
+        Err(e) => assert!(false, "Should return InsideRuntime, got: '{}'", e),
+        Ok(_) => assert!(false, "Should return error"),
+    };
+}
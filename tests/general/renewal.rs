@@ -0,0 +1,436 @@
+extern crate hc_vault;
+
+use std::time::Duration;
+
+use wiremock::matchers::{body_json, header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use serde_json::json;
+
+fn auth_response(token: &str, lease_duration: u64) -> ResponseTemplate {
+    ResponseTemplate::new(200).set_body_json(json!({
+        "auth": {
+            "client_token": token,
+            "accessor": "testAccessor",
+            "policies": ["default"],
+            "token_policies": ["default"],
+            "lease_duration": lease_duration,
+            "renewable": true,
+        },
+        "lease_id": "",
+        "lease_duration": 0,
+        "renewable": false,
+    }))
+}
+
+async fn new_client(
+    mock_server: &MockServer,
+    renew_policy: hc_vault::RenewPolicy,
+) -> hc_vault::Client<hc_vault::approle::Session> {
+    let auth =
+        hc_vault::approle::Session::new("testID".to_string(), "testSecret".to_string()).unwrap();
+    let config = hc_vault::Config {
+        vault_url: mock_server.uri(),
+        renew_policy,
+        retry: hc_vault::RetryPolicy {
+            max_retries: 0,
+            min_backoff: Duration::from_millis(50),
+            max_backoff: Duration::from_millis(50),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    hc_vault::Client::new_async(config, auth).await.unwrap()
+}
+
+/// Waits until the given condition holds for the state of the session
+async fn wait_for<F>(handle: &hc_vault::RenewalHandle, condition: F) -> hc_vault::SessionState
+where
+    F: Fn(&hc_vault::SessionState) -> bool,
+{
+    let mut receiver = handle.watch();
+    let wait = async {
+        loop {
+            let state = receiver.borrow_and_update().clone();
+            if condition(&state) {
+                return state;
+            }
+            receiver.changed().await.unwrap();
+        }
+    };
+
+    tokio::time::timeout(Duration::from_secs(5), wait)
+        .await
+        .expect("The session should reach the expected state")
+}
+
+#[tokio::test]
+async fn valid_renew_then_stop() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/approle/login"))
+        .respond_with(auth_response("testToken", 1))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/token/renew-self"))
+        .and(header("X-Vault-Token", "testToken"))
+        .respond_with(auth_response("testToken", 1))
+        .expect(1..)
+        .mount(&mock_server)
+        .await;
+
+    let client = new_client(&mock_server, hc_vault::RenewPolicy::Renew(0.9)).await;
+
+    let (task, handle) = match client.renewal_task() {
+        Err(e) => return assert!(false, "Should not return error: '{}'", e),
+        Ok(r) => r,
+    };
+    let task = tokio::spawn(task);
+
+    let state = wait_for(&handle, |s| s.last_renew.is_some()).await;
+    assert_eq!(state.ttl, Duration::from_secs(1));
+    assert!(state.last_error.is_none());
+    assert_eq!(state.stopped, false);
+
+    handle.stop();
+    tokio::time::timeout(Duration::from_secs(5), task)
+        .await
+        .expect("The task should stop")
+        .unwrap();
+    assert_eq!(handle.state().stopped, true);
+    assert_eq!(client.get_token(), "testToken");
+}
+
+#[tokio::test]
+async fn valid_out_of_range_threshold() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/approle/login"))
+        .respond_with(auth_response("testToken", 1))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/token/renew-self"))
+        .and(header("X-Vault-Token", "testToken"))
+        .respond_with(auth_response("testToken", 1))
+        .expect(1..)
+        .mount(&mock_server)
+        .await;
+
+    // A threshold above 1 renews right away instead of making the task panic
+    let client = new_client(&mock_server, hc_vault::RenewPolicy::Renew(1.5)).await;
+
+    let (task, handle) = client.renewal_task().unwrap();
+    let task = tokio::spawn(task);
+
+    let state = wait_for(&handle, |s| s.last_renew.is_some()).await;
+    assert!(state.last_error.is_none());
+    assert_eq!(state.stopped, false);
+
+    handle.stop();
+    tokio::time::timeout(Duration::from_secs(5), task)
+        .await
+        .expect("The task should stop")
+        .unwrap();
+    assert_eq!(handle.state().stopped, true);
+}
+
+#[tokio::test]
+async fn valid_renew_fails_reauth() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/approle/login"))
+        .respond_with(auth_response("oldToken", 1))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/approle/login"))
+        .respond_with(auth_response("newToken", 60))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/token/renew-self"))
+        .and(header("X-Vault-Token", "oldToken"))
+        .respond_with(ResponseTemplate::new(500))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = new_client(&mock_server, hc_vault::RenewPolicy::Renew(0.9)).await;
+
+    let (task, handle) = client.renewal_task().unwrap();
+    let task = tokio::spawn(task);
+
+    let state = wait_for(&handle, |s| s.last_renew.is_some()).await;
+    assert_eq!(state.ttl, Duration::from_secs(60));
+    match state.last_error.as_deref() {
+        Some(hc_vault::Error::Internal(_)) => assert!(true),
+        Some(e) => assert!(false, "Should report the failed renew, got: '{}'", e),
+        None => assert!(false, "Should report the failed renew"),
+    };
+    assert_eq!(client.get_token(), "newToken");
+
+    handle.stop();
+    task.await.unwrap();
+}
+
+#[tokio::test]
+async fn valid_capped_renew_reauth() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/approle/login"))
+        .respond_with(auth_response("oldToken", 2))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/approle/login"))
+        .respond_with(auth_response("newToken", 60))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    // The token reached its max TTL, so vault only renews it for the time
+    // that is left
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/token/renew-self"))
+        .and(header("X-Vault-Token", "oldToken"))
+        .respond_with(auth_response("oldToken", 1))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = new_client(&mock_server, hc_vault::RenewPolicy::Renew(0.9)).await;
+
+    let (task, handle) = client.renewal_task().unwrap();
+    let task = tokio::spawn(task);
+
+    let state = wait_for(&handle, |s| s.ttl == Duration::from_secs(60)).await;
+    assert!(state.last_renew.is_some());
+    assert!(state.last_error.is_none());
+    assert_eq!(client.get_token(), "newToken");
+
+    handle.stop();
+    task.await.unwrap();
+}
+
+#[tokio::test]
+async fn valid_max_ttl_relogin() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/approle/login"))
+        .respond_with(auth_response("oldToken", 1))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/approle/login"))
+        .respond_with(auth_response("newToken", 60))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    // The requested increment is capped, because the token is about to
+    // reach its max TTL
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/token/renew-self"))
+        .and(header("X-Vault-Token", "oldToken"))
+        .and(body_json(json!({"increment": 30})))
+        .respond_with(auth_response("oldToken", 1))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let options = hc_vault::RenewOptions {
+        threshold: 0.9,
+        increment: Some(30),
+        ..Default::default()
+    };
+    let client = new_client(&mock_server, hc_vault::RenewPolicy::RenewWith(options)).await;
+
+    let (task, handle) = client.renewal_task().unwrap();
+    let task = tokio::spawn(task);
+
+    let state = wait_for(&handle, |s| s.ttl == Duration::from_secs(60)).await;
+    assert!(state.last_error.is_none());
+    assert_eq!(state.max_ttl_remaining, None);
+    assert_eq!(client.get_token(), "newToken");
+
+    handle.stop();
+    task.await.unwrap();
+}
+
+#[tokio::test]
+async fn invalid_reauth_fails_keeps_running() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/approle/login"))
+        .respond_with(auth_response("testToken", 1))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/approle/login"))
+        .respond_with(ResponseTemplate::new(500))
+        .expect(2..)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/token/renew-self"))
+        .respond_with(ResponseTemplate::new(500))
+        .expect(1..)
+        .mount(&mock_server)
+        .await;
+
+    let client = new_client(&mock_server, hc_vault::RenewPolicy::Renew(0.9)).await;
+
+    let (task, handle) = client.renewal_task().unwrap();
+    let task = tokio::spawn(task);
+
+    // The first attempt fails, the task then keeps retrying the login
+    let state = wait_for(&handle, |s| s.last_error.is_some()).await;
+    assert!(state.last_renew.is_none());
+    assert_eq!(state.stopped, false);
+
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    assert_eq!(handle.state().stopped, false);
+
+    handle.stop();
+    task.await.unwrap();
+    assert_eq!(handle.state().stopped, true);
+}
+
+#[tokio::test]
+async fn invalid_not_enabled() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/approle/login"))
+        .respond_with(auth_response("testToken", 60))
+        .mount(&mock_server)
+        .await;
+
+    let client = new_client(&mock_server, hc_vault::RenewPolicy::Reauth).await;
+
+    match client.renewal_task() {
+        Err(hc_vault::RenewError::NotEnabled) => assert!(true),
+        Err(e) => assert!(false, "Should return NotEnabled, got: '{}'", e),
+        Ok(_) => assert!(false, "Should return error"),
+    };
+}
+
+#[tokio::test]
+async fn valid_restart_after_dropped_task() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/approle/login"))
+        .respond_with(auth_response("testToken", 60))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = new_client(&mock_server, hc_vault::RenewPolicy::Renew(0.5)).await;
+
+    // The task is dropped without ever being spawned
+    let (task, handle) = client.renewal_task().unwrap();
+    drop(task);
+    assert_eq!(handle.state().stopped, true);
+
+    // The task is aborted while it is running
+    let (task, handle) = match client.renewal_task() {
+        Err(e) => return assert!(false, "Should not return error: '{}'", e),
+        Ok(r) => r,
+    };
+    let task = tokio::spawn(task);
+    task.abort();
+    let _ = task.await;
+    assert_eq!(handle.state().stopped, true);
+
+    match client.renewal_task() {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok((_, handle)) => assert_eq!(handle.state().stopped, false),
+    };
+}
+
+#[tokio::test]
+async fn valid_non_expiring_token_not_renewed() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/approle/login"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "auth": {
+                "client_token": "testToken",
+                "accessor": "testAccessor",
+                "policies": ["root"],
+                "token_policies": ["root"],
+                "lease_duration": 0,
+                "renewable": false,
+            },
+            "lease_id": "",
+            "lease_duration": 0,
+            "renewable": false,
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = new_client(&mock_server, hc_vault::RenewPolicy::Renew(0.5)).await;
+
+    let (task, handle) = client.renewal_task().unwrap();
+    let task = tokio::spawn(task);
+
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    let state = handle.state();
+    assert!(state.last_renew.is_none());
+    assert!(state.last_error.is_none());
+    assert_eq!(state.stopped, false);
+
+    handle.stop();
+    task.await.unwrap();
+}
+
+#[tokio::test]
+async fn valid_renewable_token_without_ttl_renewed() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/approle/login"))
+        .respond_with(auth_response("testToken", 0))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/token/renew-self"))
+        .and(header("X-Vault-Token", "testToken"))
+        .respond_with(auth_response("testToken", 60))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = new_client(&mock_server, hc_vault::RenewPolicy::Renew(0.5)).await;
+
+    let (task, handle) = client.renewal_task().unwrap();
+    let task = tokio::spawn(task);
+
+    let state = wait_for(&handle, |s| s.last_renew.is_some()).await;
+    assert_eq!(state.ttl, Duration::from_secs(60));
+
+    handle.stop();
+    task.await.unwrap();
+}
@@ -0,0 +1,229 @@
+extern crate hc_vault;
+
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use serde::Deserialize;
+use serde_json::json;
+
+use std::sync::{Arc, Mutex};
+
+use crate::common::new_client_with_config;
+
+#[derive(Deserialize, Debug, PartialEq)]
+struct TestData {
+    field: String,
+}
+
+async fn new_client(
+    mock_server: &MockServer,
+    warning_handler: Option<hc_vault::WarningHandler>,
+) -> hc_vault::Client<hc_vault::token::Session> {
+    let conf = hc_vault::Config {
+        vault_url: mock_server.uri(),
+        warning_handler,
+        ..Default::default()
+    };
+    new_client_with_config(mock_server, conf).await
+}
+
+#[tokio::test]
+async fn valid_response_envelope() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/test/data"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "request_id": "testRequestID",
+            "lease_id": "",
+            "lease_duration": 3600,
+            "renewable": false,
+            "data": {
+                "field": "value",
+            },
+            "wrap_info": null,
+            "warnings": null,
+            "auth": null,
+            "mount_type": "kv",
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = new_client(&mock_server, None).await;
+
+    let response = match client
+        .request::<(), TestData>(reqwest::Method::GET, "test/data", None)
+        .await
+    {
+        Err(e) => {
+            assert!(false, "Should not return error: '{}'", e);
+            return;
+        }
+        Ok(r) => r,
+    };
+
+    assert_eq!(response.request_id, "testRequestID".to_string());
+    assert_eq!(response.lease_duration, 3600);
+    assert_eq!(response.mount_type, "kv".to_string());
+    assert_eq!(response.warnings, Vec::<String>::new());
+    assert_eq!(response.wrap_info, None);
+    assert_eq!(
+        response.data,
+        TestData {
+            field: "value".to_string()
+        }
+    );
+}
+
+#[tokio::test]
+async fn valid_warning_handler() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/token/create"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "request_id": "testRequestID",
+            "data": {
+                "field": "value",
+            },
+            "warnings": ["TTL of \"768h\" exceeded the effective max_ttl of \"1h\"; TTL value is capped accordingly"],
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let reported = Arc::new(Mutex::new(Vec::<(String, Vec<String>)>::new()));
+    let handler_reported = reported.clone();
+    let handler: hc_vault::WarningHandler = Arc::new(move |path, warnings| {
+        handler_reported
+            .lock()
+            .unwrap()
+            .push((path.to_string(), warnings.to_vec()));
+    });
+
+    let client = new_client(&mock_server, Some(handler)).await;
+
+    let response = match client
+        .request::<(), TestData>(reqwest::Method::POST, "auth/token/create", None)
+        .await
+    {
+        Err(e) => {
+            assert!(false, "Should not return error: '{}'", e);
+            return;
+        }
+        Ok(r) => r,
+    };
+
+    assert_eq!(response.warnings.len(), 1);
+
+    let reported = reported.lock().unwrap();
+    assert_eq!(reported.len(), 1);
+    assert_eq!(reported[0].0, "auth/token/create".to_string());
+    assert_eq!(reported[0].1, response.warnings);
+}
+
+/// The path and warnings of every call to a warning handler
+type Reported = Arc<Mutex<Vec<(String, Vec<String>)>>>;
+
+/// Collects all warnings passed to the returned handler
+fn collect_warnings() -> (hc_vault::WarningHandler, Reported) {
+    let reported = Arc::new(Mutex::new(Vec::<(String, Vec<String>)>::new()));
+    let handler_reported = reported.clone();
+    let handler: hc_vault::WarningHandler = Arc::new(move |path, warnings| {
+        handler_reported
+            .lock()
+            .unwrap()
+            .push((path.to_string(), warnings.to_vec()));
+    });
+
+    (handler, reported)
+}
+
+#[tokio::test]
+async fn valid_warning_handler_token_create() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/token/create"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "auth": {
+                "client_token": "childToken",
+                "accessor": "childAccessor",
+                "policies": ["default"],
+                "token_policies": ["default"],
+                "metadata": null,
+                "lease_duration": 3600,
+                "renewable": true,
+                "entity_id": "",
+                "token_type": "service",
+                "orphan": false,
+            },
+            "warnings": ["TTL of \"768h\" exceeded the effective max_ttl of \"1h\"; TTL value is capped accordingly"],
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let (handler, reported) = collect_warnings();
+    let client = new_client(&mock_server, Some(handler)).await;
+
+    let request = hc_vault::token::TokenCreateRequest {
+        ttl: Some("768h".to_string()),
+        ..Default::default()
+    };
+    match hc_vault::token::create(&client, &request).await {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(auth) => assert_eq!(auth.lease_duration, 3600),
+    };
+
+    let reported = reported.lock().unwrap();
+    assert_eq!(reported.len(), 1);
+    assert_eq!(reported[0].0, "auth/token/create".to_string());
+}
+
+#[tokio::test]
+async fn valid_warning_handler_login() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/approle/login"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "auth": {
+                "client_token": "testToken",
+                "accessor": "testAccessor",
+                "policies": ["default"],
+                "token_policies": ["default"],
+                "lease_duration": 3600,
+                "renewable": true,
+            },
+            "warnings": ["Endpoint ignored these unrecognized parameters: [unknown]"],
+            "lease_id": "",
+            "lease_duration": 0,
+            "renewable": false,
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let (handler, reported) = collect_warnings();
+    let auth =
+        hc_vault::approle::Session::new("testID".to_string(), "testSecret".to_string()).unwrap();
+    let conf = hc_vault::Config {
+        vault_url: mock_server.uri(),
+        warning_handler: Some(handler),
+        ..Default::default()
+    };
+
+    if let Err(e) = hc_vault::Client::new_async(conf, auth).await {
+        assert!(false, "Should not return error: '{}'", e);
+    }
+
+    let reported = reported.lock().unwrap();
+    assert_eq!(reported.len(), 1);
+    assert_eq!(reported[0].0, "auth/approle/login".to_string());
+    assert_eq!(
+        reported[0].1,
+        vec!["Endpoint ignored these unrecognized parameters: [unknown]".to_string()]
+    );
+}
@@ -0,0 +1,193 @@
+extern crate hc_vault;
+
+use std::time::Duration;
+
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use serde_json::json;
+
+use hc_vault::Auth as AuthTrait;
+
+use crate::common::new_client_with_config;
+
+fn retry_policy(max_retries: u32) -> hc_vault::RetryPolicy {
+    hc_vault::RetryPolicy {
+        max_retries,
+        min_backoff: Duration::from_millis(10),
+        max_backoff: Duration::from_millis(20),
+        ..Default::default()
+    }
+}
+
+async fn new_client(
+    mock_server: &MockServer,
+    retry: hc_vault::RetryPolicy,
+) -> hc_vault::Client<hc_vault::token::Session> {
+    let conf = hc_vault::Config {
+        vault_url: mock_server.uri(),
+        retry,
+        ..Default::default()
+    };
+    new_client_with_config(mock_server, conf).await
+}
+
+async fn mount_failures(mock_server: &MockServer, status: u16, times: u64) {
+    Mock::given(method("GET"))
+        .and(path("/v1/secret/app"))
+        .respond_with(ResponseTemplate::new(status))
+        .up_to_n_times(times)
+        .expect(times)
+        .mount(mock_server)
+        .await;
+}
+
+async fn mount_success(mock_server: &MockServer, times: u64) {
+    Mock::given(method("GET"))
+        .and(path("/v1/secret/app"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": {
+                "password": "hunter2",
+            },
+        })))
+        .expect(times)
+        .mount(mock_server)
+        .await;
+}
+
+#[test]
+fn valid_default_policy() {
+    let policy = hc_vault::RetryPolicy::default();
+    assert_eq!(policy.max_retries, 2);
+    assert!(policy.retry_statuses.contains(&412));
+    assert!(policy.retry_statuses.contains(&500));
+    assert!(policy.retry_statuses.contains(&503));
+    assert!(!policy.retry_statuses.contains(&501));
+    assert!(!policy.retry_statuses.contains(&429));
+    assert!(!policy.retry_statuses.contains(&404));
+}
+
+#[tokio::test]
+async fn valid_retry_then_success() {
+    let mock_server = MockServer::start().await;
+
+    mount_failures(&mock_server, 503, 2).await;
+    mount_success(&mock_server, 1).await;
+
+    let client = new_client(&mock_server, retry_policy(2)).await;
+
+    match client.read::<serde::de::IgnoredAny>("secret/app").await {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(_) => assert!(true),
+    };
+}
+
+#[tokio::test]
+async fn valid_retry_custom_status() {
+    let mock_server = MockServer::start().await;
+
+    mount_failures(&mock_server, 429, 1).await;
+    mount_success(&mock_server, 1).await;
+
+    let mut retry = retry_policy(1);
+    retry.retry_statuses.push(429);
+    let client = new_client(&mock_server, retry).await;
+
+    match client.read::<serde::de::IgnoredAny>("secret/app").await {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(_) => assert!(true),
+    };
+}
+
+#[tokio::test]
+async fn invalid_retries_exhausted() {
+    let mock_server = MockServer::start().await;
+
+    mount_failures(&mock_server, 503, 3).await;
+    mount_success(&mock_server, 0).await;
+
+    let client = new_client(&mock_server, retry_policy(2)).await;
+
+    match client.read::<serde::de::IgnoredAny>("secret/app").await {
+        Err(hc_vault::Error::IsSealed(_)) => assert!(true),
+        Err(e) => assert!(false, "Should return IsSealed, got: '{}'", e),
+        Ok(_) => assert!(false, "Should return error"),
+    };
+}
+
+#[tokio::test]
+async fn invalid_status_not_retried() {
+    let mock_server = MockServer::start().await;
+
+    mount_failures(&mock_server, 400, 1).await;
+    mount_success(&mock_server, 0).await;
+
+    let client = new_client(&mock_server, retry_policy(2)).await;
+
+    match client.read::<serde::de::IgnoredAny>("secret/app").await {
+        Err(hc_vault::Error::InvalidRequest(_)) => assert!(true),
+        Err(e) => assert!(false, "Should return InvalidRequest, got: '{}'", e),
+        Ok(_) => assert!(false, "Should return error"),
+    };
+}
+
+#[tokio::test]
+async fn invalid_retry_disabled() {
+    let mock_server = MockServer::start().await;
+
+    mount_failures(&mock_server, 503, 1).await;
+    mount_success(&mock_server, 0).await;
+
+    let client = new_client(&mock_server, hc_vault::RetryPolicy::disabled()).await;
+
+    match client.read::<serde::de::IgnoredAny>("secret/app").await {
+        Err(hc_vault::Error::IsSealed(_)) => assert!(true),
+        Err(e) => assert!(false, "Should return IsSealed, got: '{}'", e),
+        Ok(_) => assert!(false, "Should return error"),
+    };
+}
+
+#[tokio::test]
+async fn valid_auth_retry() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/approle/login"))
+        .respond_with(ResponseTemplate::new(500))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/approle/login"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "auth": {
+                "client_token": "testToken",
+                "accessor": "testAccessor",
+                "token_policies": ["test"],
+                "lease_duration": 120,
+                "renewable": true,
+            },
+            "lease_id": "",
+            "lease_duration": 0,
+            "renewable": false,
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let http = hc_vault::HttpClient::new(&hc_vault::Config {
+        vault_url: mock_server.uri(),
+        retry: retry_policy(1),
+        ..Default::default()
+    })
+    .unwrap();
+
+    let tmp_auth =
+        hc_vault::approle::Session::new("testID".to_string(), "testSecret".to_string()).unwrap();
+
+    match tmp_auth.auth(&http).await {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(_) => assert!(true),
+    };
+}
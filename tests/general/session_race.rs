@@ -0,0 +1,152 @@
+extern crate hc_vault;
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use wiremock::matchers::{header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use serde_json::json;
+
+use hc_vault::Auth as AuthTrait;
+
+fn auth_response(token: &str, lease_duration: u64) -> ResponseTemplate {
+    ResponseTemplate::new(200).set_body_json(json!({
+        "auth": {
+            "client_token": token,
+            "accessor": "testAccessor",
+            "policies": ["default"],
+            "token_policies": ["default"],
+            "lease_duration": lease_duration,
+            "renewable": true,
+        },
+        "lease_id": "",
+        "lease_duration": 0,
+        "renewable": false,
+    }))
+}
+
+fn new_http(mock_server: &MockServer) -> hc_vault::HttpClient {
+    hc_vault::HttpClient::new(&hc_vault::Config {
+        vault_url: mock_server.uri(),
+        retry: hc_vault::RetryPolicy::disabled(),
+        ..Default::default()
+    })
+    .unwrap()
+}
+
+fn new_session() -> Arc<hc_vault::approle::Session> {
+    Arc::new(
+        hc_vault::approle::Session::new("testID".to_string(), "testSecret".to_string()).unwrap(),
+    )
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn valid_concurrent_auth_renew_get_token() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/approle/login"))
+        .respond_with(auth_response("testToken", 120))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/token/renew-self"))
+        .and(header("X-Vault-Token", "testToken"))
+        .respond_with(auth_response("testToken", 120))
+        .mount(&mock_server)
+        .await;
+
+    let http = Arc::new(new_http(&mock_server));
+    let session = new_session();
+    session.auth(&http).await.unwrap();
+
+    // Readers spin on their own threads, while the session is constantly
+    // being replaced and renewed by the tasks below
+    let done = Arc::new(AtomicBool::new(false));
+    let readers: Vec<_> = (0..4)
+        .map(|_| {
+            let session = session.clone();
+            let done = done.clone();
+            std::thread::spawn(move || {
+                let mut reads = 0u64;
+                while !done.load(Ordering::SeqCst) {
+                    assert_eq!(session.get_token(), "testToken");
+                    assert_eq!(session.get_total_duration(), 120);
+                    assert!(!session.is_expired());
+                    reads += 1;
+                }
+                reads
+            })
+        })
+        .collect();
+
+    let mut writers = Vec::new();
+    for i in 0..4 {
+        let session = session.clone();
+        let http = http.clone();
+        writers.push(tokio::spawn(async move {
+            for _ in 0..25 {
+                let result = match i % 2 {
+                    0 => session.auth(&http).await,
+                    _ => session.renew(&http).await,
+                };
+                if let Err(e) = result {
+                    assert!(false, "Should not return error: '{}'", e);
+                }
+            }
+        }));
+    }
+    for writer in writers {
+        writer.await.unwrap();
+    }
+
+    done.store(true, Ordering::SeqCst);
+    for reader in readers {
+        assert!(reader.join().unwrap() > 0);
+    }
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn valid_stale_renew_ignored() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/approle/login"))
+        .respond_with(auth_response("oldToken", 60))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/approle/login"))
+        .respond_with(auth_response("newToken", 300))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    // The renew of the old token only completes after the new login
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/token/renew-self"))
+        .and(header("X-Vault-Token", "oldToken"))
+        .respond_with(auth_response("oldToken", 90).set_delay(Duration::from_millis(300)))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let http = new_http(&mock_server);
+    let session = new_session();
+    session.auth(&http).await.unwrap();
+
+    let relogin = async {
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        session.auth(&http).await
+    };
+    let (renewed, relogged) = tokio::join!(session.renew(&http), relogin);
+    if let Err(e) = renewed.and(relogged) {
+        assert!(false, "Should not return error: '{}'", e);
+    }
+
+    assert_eq!(session.get_token(), "newToken");
+    assert_eq!(session.get_total_duration(), 300);
+}
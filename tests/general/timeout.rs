@@ -0,0 +1,156 @@
+extern crate hc_vault;
+
+use std::time::Duration;
+
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use serde_json::json;
+
+use hc_vault::Auth as AuthTrait;
+
+use crate::common::new_client_with_config;
+
+fn slow_response(delay: Duration) -> ResponseTemplate {
+    ResponseTemplate::new(200)
+        .set_body_json(json!({
+            "data": {
+                "password": "hunter2",
+            },
+        }))
+        .set_delay(delay)
+}
+
+#[tokio::test]
+async fn invalid_config_timeout() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/secret/app"))
+        .respond_with(slow_response(Duration::from_millis(500)))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = new_client_with_config(
+        &mock_server,
+        hc_vault::Config {
+            vault_url: mock_server.uri(),
+            timeout: Some(Duration::from_millis(100)),
+            retry: hc_vault::RetryPolicy::disabled(),
+            ..Default::default()
+        },
+    )
+    .await;
+
+    match client.read::<serde::de::IgnoredAny>("secret/app").await {
+        Err(hc_vault::Error::Timeout(_)) => assert!(true),
+        Err(e) => assert!(false, "Should return Timeout, got: '{}'", e),
+        Ok(_) => assert!(false, "Should return error"),
+    };
+}
+
+#[tokio::test]
+async fn valid_with_timeout() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/secret/app"))
+        .respond_with(slow_response(Duration::from_millis(300)))
+        .expect(2)
+        .mount(&mock_server)
+        .await;
+
+    let client = new_client_with_config(
+        &mock_server,
+        hc_vault::Config {
+            vault_url: mock_server.uri(),
+            retry: hc_vault::RetryPolicy::disabled(),
+            ..Default::default()
+        },
+    )
+    .await;
+
+    let short = client.with_timeout(Duration::from_millis(100));
+    assert_eq!(short.timeout(), Some(Duration::from_millis(100)));
+    assert_eq!(short.with_namespace("tenant-a").timeout(), short.timeout());
+    match short.read::<serde::de::IgnoredAny>("secret/app").await {
+        Err(hc_vault::Error::Timeout(_)) => assert!(true),
+        Err(e) => assert!(false, "Should return Timeout, got: '{}'", e),
+        Ok(_) => assert!(false, "Should return error"),
+    };
+
+    // The original client still uses the timeout from the config
+    assert_eq!(client.timeout(), None);
+    match client.read::<serde::de::IgnoredAny>("secret/app").await {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(_) => assert!(true),
+    };
+}
+
+#[tokio::test]
+async fn invalid_deadline_stops_retries() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/secret/app"))
+        .respond_with(ResponseTemplate::new(503))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = new_client_with_config(
+        &mock_server,
+        hc_vault::Config {
+            vault_url: mock_server.uri(),
+            retry: hc_vault::RetryPolicy {
+                max_retries: 2,
+                min_backoff: Duration::from_millis(500),
+                max_backoff: Duration::from_millis(500),
+                jitter: false,
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+    )
+    .await;
+
+    match client
+        .with_timeout(Duration::from_millis(200))
+        .read::<serde::de::IgnoredAny>("secret/app")
+        .await
+    {
+        Err(hc_vault::Error::IsSealed(_)) => assert!(true),
+        Err(e) => assert!(false, "Should return IsSealed, got: '{}'", e),
+        Ok(_) => assert!(false, "Should return error"),
+    };
+}
+
+#[tokio::test]
+async fn invalid_auth_timeout() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/approle/login"))
+        .respond_with(ResponseTemplate::new(200).set_delay(Duration::from_millis(500)))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let http = hc_vault::HttpClient::new(&hc_vault::Config {
+        vault_url: mock_server.uri(),
+        timeout: Some(Duration::from_millis(100)),
+        retry: hc_vault::RetryPolicy::disabled(),
+        ..Default::default()
+    })
+    .unwrap();
+
+    let tmp_auth =
+        hc_vault::approle::Session::new("testID".to_string(), "testSecret".to_string()).unwrap();
+
+    match tmp_auth.auth(&http).await {
+        Err(hc_vault::Error::Timeout(_)) => assert!(true),
+        Err(e) => assert!(false, "Should return Timeout, got: '{}'", e),
+        Ok(_) => assert!(false, "Should return error"),
+    };
+}
@@ -0,0 +1,398 @@
+extern crate hc_vault;
+
+use std::io::{BufReader, Read, Write};
+use std::net::TcpListener;
+use std::sync::Arc;
+use std::thread;
+
+use rustls::server::AllowAnyAuthenticatedClient;
+use rustls::{Certificate, PrivateKey, RootCertStore, ServerConfig, ServerConnection, Stream};
+
+const CA_CERT: &[u8] = include_bytes!("tls_ca.pem");
+const SERVER_CERT: &[u8] = include_bytes!("tls_server.pem");
+const SERVER_KEY: &[u8] = include_bytes!("tls_server_key.pem");
+const CLIENT_CERT: &[u8] = include_bytes!("tls_client.pem");
+const CLIENT_KEY: &[u8] = include_bytes!("tls_client_key.pem");
+
+// The certificate of the server is only valid for this name
+const SERVER_NAME: &str = "vault.test";
+
+// Works as the response to a token lookup as well as to a login
+const RESPONSE_BODY: &str = r#"{"data":{"ttl":0,"renewable":false,"policies":["root"]},"auth":{"client_token":"certToken","accessor":"testAccessor","policies":["root"],"lease_duration":0,"renewable":false}}"#;
+
+/// Starts a minimal HTTPS server in the background, that answers every request
+/// with a valid token lookup and login response and returns the vault url to reach it
+fn start_server(require_client_cert: bool) -> String {
+    format!("https://localhost:{}", serve(require_client_cert, false))
+}
+
+/// Starts the server like `start_server`, but only answers requests that were
+/// sent with the SNI of the server name, and returns the vault url to reach it
+/// by its IP address
+fn start_sni_server() -> String {
+    format!("https://127.0.0.1:{}", serve(false, true))
+}
+
+/// Serves the requests in the background and returns the port of the server,
+/// requests with an unexpected SNI or Host header are rejected with a 421
+fn serve(require_client_cert: bool, require_sni: bool) -> u16 {
+    let builder = ServerConfig::builder().with_safe_defaults();
+    let builder = if require_client_cert {
+        let mut roots = RootCertStore::empty();
+        let ca_certs = rustls_pemfile::certs(&mut BufReader::new(CA_CERT)).unwrap();
+        roots.add_parsable_certificates(&ca_certs);
+        builder.with_client_cert_verifier(AllowAnyAuthenticatedClient::new(roots).boxed())
+    } else {
+        builder.with_no_client_auth()
+    };
+
+    let certs = rustls_pemfile::certs(&mut BufReader::new(SERVER_CERT))
+        .unwrap()
+        .into_iter()
+        .map(Certificate)
+        .collect();
+    let mut keys = rustls_pemfile::pkcs8_private_keys(&mut BufReader::new(SERVER_KEY)).unwrap();
+    let config = builder
+        .with_single_cert(certs, PrivateKey(keys.remove(0)))
+        .unwrap();
+    let config = Arc::new(config);
+
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let port = listener.local_addr().unwrap().port();
+
+    thread::spawn(move || {
+        for sock in listener.incoming() {
+            let mut sock = match sock {
+                Err(_) => continue,
+                Ok(s) => s,
+            };
+            let mut session = ServerConnection::new(config.clone()).unwrap();
+            let mut stream = Stream::new(&mut session, &mut sock);
+
+            let mut request = Vec::new();
+            let mut buf = [0; 4096];
+            while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+                match stream.read(&mut buf) {
+                    Ok(0) | Err(_) => break,
+                    Ok(n) => request.extend_from_slice(&buf[..n]),
+                };
+            }
+            if request.is_empty() {
+                continue;
+            }
+
+            let expected_host = format!("host: 127.0.0.1:{}\r\n", port);
+            let misdirected = require_sni
+                && (stream.conn.server_name() != Some(SERVER_NAME)
+                    || !String::from_utf8_lossy(&request)
+                        .to_lowercase()
+                        .contains(&expected_host));
+            let status = match misdirected {
+                true => "421 Misdirected Request",
+                false => "200 OK",
+            };
+
+            let response = format!(
+                "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                RESPONSE_BODY.len(),
+                RESPONSE_BODY
+            );
+            let _ = stream.write_all(response.as_bytes());
+            session.send_close_notify();
+            let _ = session.complete_io(&mut sock);
+        }
+    });
+
+    port
+}
+
+fn new_client(
+    vault_url: String,
+    tls: hc_vault::TlsConfig,
+) -> Result<hc_vault::Client<hc_vault::token::Session>, hc_vault::Error> {
+    let auth = hc_vault::token::Session::new("testToken".to_string()).unwrap();
+    let conf = hc_vault::Config {
+        vault_url,
+        tls,
+        ..Default::default()
+    };
+    hc_vault::Client::new(conf, auth)
+}
+
+#[test]
+fn valid_ca_cert_pem() {
+    let tls = hc_vault::TlsConfig {
+        ca_cert_pem: Some(CA_CERT.to_vec()),
+        server_name: Some(SERVER_NAME.to_string()),
+        ..Default::default()
+    };
+
+    match new_client(start_server(false), tls) {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(_) => assert!(true),
+    };
+}
+
+#[test]
+fn valid_ca_cert_dir() {
+    let dir = std::env::temp_dir().join(format!("hc-vault-tls-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(dir.join("ca.pem"), CA_CERT).unwrap();
+
+    let tls = hc_vault::TlsConfig {
+        ca_cert_dir: Some(dir.clone()),
+        server_name: Some(SERVER_NAME.to_string()),
+        ..Default::default()
+    };
+    let result = new_client(start_server(false), tls);
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    match result {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(_) => assert!(true),
+    };
+}
+
+#[tokio::test]
+async fn valid_async_request() {
+    let tls = hc_vault::TlsConfig {
+        ca_cert_pem: Some(CA_CERT.to_vec()),
+        server_name: Some(SERVER_NAME.to_string()),
+        ..Default::default()
+    };
+    let auth = hc_vault::token::Session::new("testToken".to_string()).unwrap();
+    let conf = hc_vault::Config {
+        vault_url: start_server(false),
+        tls,
+        ..Default::default()
+    };
+
+    let client = hc_vault::Client::new_async(conf, auth).await.unwrap();
+
+    match client.read::<serde::de::IgnoredAny>("secret/app").await {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(_) => assert!(true),
+    };
+}
+
+#[test]
+fn invalid_unknown_ca() {
+    let tls = hc_vault::TlsConfig {
+        server_name: Some(SERVER_NAME.to_string()),
+        ..Default::default()
+    };
+
+    match new_client(start_server(false), tls) {
+        Err(_) => assert!(true),
+        Ok(_) => assert!(false, "Should return error"),
+    };
+}
+
+#[test]
+fn valid_server_name_sni() {
+    let tls = hc_vault::TlsConfig {
+        ca_cert_pem: Some(CA_CERT.to_vec()),
+        server_name: Some(SERVER_NAME.to_string()),
+        ..Default::default()
+    };
+
+    match new_client(start_sni_server(), tls) {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(_) => assert!(true),
+    };
+}
+
+#[test]
+fn invalid_missing_sni() {
+    let tls = hc_vault::TlsConfig {
+        insecure_skip_verify: true,
+        ..Default::default()
+    };
+
+    match new_client(start_sni_server(), tls) {
+        Err(_) => assert!(true),
+        Ok(_) => assert!(false, "Should return error"),
+    };
+}
+
+#[cfg(feature = "ureq")]
+#[test]
+fn valid_server_name_sni_ureq() {
+    let tls = hc_vault::TlsConfig {
+        ca_cert_pem: Some(CA_CERT.to_vec()),
+        server_name: Some(SERVER_NAME.to_string()),
+        ..Default::default()
+    };
+    let mut conf = hc_vault::Config {
+        vault_url: start_sni_server(),
+        tls,
+        ..Default::default()
+    };
+    let transport = hc_vault::transport::UreqTransport::new(&conf).unwrap();
+    conf.transport = Some(Arc::new(transport));
+
+    let auth = hc_vault::token::Session::new("testToken".to_string()).unwrap();
+    match hc_vault::Client::new(conf, auth) {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(_) => assert!(true),
+    };
+}
+
+#[test]
+fn invalid_server_name_mismatch() {
+    let tls = hc_vault::TlsConfig {
+        ca_cert_pem: Some(CA_CERT.to_vec()),
+        ..Default::default()
+    };
+
+    match new_client(start_server(false), tls) {
+        Err(_) => assert!(true),
+        Ok(_) => assert!(false, "Should return error"),
+    };
+}
+
+#[test]
+fn valid_insecure_skip_verify() {
+    let tls = hc_vault::TlsConfig {
+        insecure_skip_verify: true,
+        ..Default::default()
+    };
+
+    match new_client(start_server(false), tls) {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(_) => assert!(true),
+    };
+}
+
+#[test]
+fn valid_client_cert() {
+    let tls = hc_vault::TlsConfig {
+        ca_cert_pem: Some(CA_CERT.to_vec()),
+        server_name: Some(SERVER_NAME.to_string()),
+        client_cert: Some(hc_vault::ClientCert {
+            cert_pem: CLIENT_CERT.to_vec(),
+            key_pem: CLIENT_KEY.to_vec(),
+        }),
+        ..Default::default()
+    };
+
+    match new_client(start_server(true), tls) {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(_) => assert!(true),
+    };
+}
+
+#[test]
+fn valid_cert_login() {
+    // The client certificate is only presented by the Auth-Backend
+    let tls = hc_vault::TlsConfig {
+        ca_cert_pem: Some(CA_CERT.to_vec()),
+        server_name: Some(SERVER_NAME.to_string()),
+        ..Default::default()
+    };
+    let conf = hc_vault::Config {
+        vault_url: start_server(true),
+        tls,
+        ..Default::default()
+    };
+    let auth = hc_vault::cert::Session::new(None, CLIENT_CERT, CLIENT_KEY).unwrap();
+
+    match hc_vault::Client::new(conf, auth) {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(client) => assert_eq!(client.get_token(), "certToken".to_string()),
+    };
+}
+
+#[test]
+fn invalid_missing_client_cert() {
+    let tls = hc_vault::TlsConfig {
+        ca_cert_pem: Some(CA_CERT.to_vec()),
+        server_name: Some(SERVER_NAME.to_string()),
+        ..Default::default()
+    };
+
+    match new_client(start_server(true), tls) {
+        Err(_) => assert!(true),
+        Ok(_) => assert!(false, "Should return error"),
+    };
+}
+
+#[test]
+fn invalid_ca_cert_pem() {
+    let tls = hc_vault::TlsConfig {
+        ca_cert_pem: Some(b"not a cert".to_vec()),
+        ..Default::default()
+    };
+
+    match hc_vault::HttpClient::new(&hc_vault::Config {
+        tls,
+        ..Default::default()
+    }) {
+        Err(e) => match e {
+            hc_vault::Error::InvalidTLSConfig(_) => assert!(true),
+            _ => assert!(false, "Should return InvalidTLSConfig, got: '{}'", e),
+        },
+        Ok(_) => assert!(false, "Should return error"),
+    };
+}
+
+#[test]
+fn invalid_ca_cert_file() {
+    let tls = hc_vault::TlsConfig {
+        ca_cert_file: Some("does/not/exist.pem".into()),
+        ..Default::default()
+    };
+
+    match hc_vault::HttpClient::new(&hc_vault::Config {
+        tls,
+        ..Default::default()
+    }) {
+        Err(e) => match e {
+            hc_vault::Error::IOError(_) => assert!(true),
+            _ => assert!(false, "Should return IOError, got: '{}'", e),
+        },
+        Ok(_) => assert!(false, "Should return error"),
+    };
+}
+
+#[test]
+fn invalid_client_key() {
+    let tls = hc_vault::TlsConfig {
+        client_cert: Some(hc_vault::ClientCert {
+            cert_pem: CLIENT_CERT.to_vec(),
+            key_pem: b"not a key".to_vec(),
+        }),
+        ..Default::default()
+    };
+
+    match hc_vault::HttpClient::new(&hc_vault::Config {
+        tls,
+        ..Default::default()
+    }) {
+        Err(e) => match e {
+            hc_vault::Error::InvalidTLSConfig(_) => assert!(true),
+            _ => assert!(false, "Should return InvalidTLSConfig, got: '{}'", e),
+        },
+        Ok(_) => assert!(false, "Should return error"),
+    };
+}
+
+#[test]
+fn invalid_server_name() {
+    let tls = hc_vault::TlsConfig {
+        server_name: Some("not a valid name".to_string()),
+        ..Default::default()
+    };
+
+    match hc_vault::HttpClient::new(&hc_vault::Config {
+        tls,
+        ..Default::default()
+    }) {
+        Err(e) => match e {
+            hc_vault::Error::InvalidTLSConfig(_) => assert!(true),
+            _ => assert!(false, "Should return InvalidTLSConfig, got: '{}'", e),
+        },
+        Ok(_) => assert!(false, "Should return error"),
+    };
+}
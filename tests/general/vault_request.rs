@@ -5,7 +5,7 @@ use wiremock::{Mock, MockServer, ResponseTemplate};
 
 use serde_json::json;
 
-use std::time::Duration;
+use crate::common;
 
 #[tokio::test]
 async fn valid_vault_request_no_body() {
@@ -24,13 +24,14 @@ async fn valid_vault_request_no_body() {
         .mount(&mock_server)
         .await;
 
-    let auth =
-        hc_vault::token::Session::new("testToken".to_string(), Duration::from_secs(120)).unwrap();
+    common::mock_lookup_self(&mock_server, "testToken").await;
+
+    let auth = hc_vault::token::Session::new("testToken".to_string()).unwrap();
     let conf = hc_vault::Config {
         vault_url: mock_server.uri().clone(),
         ..Default::default()
     };
-    let client = match hc_vault::Client::new(conf, auth) {
+    let client = match hc_vault::Client::new_async(conf, auth).await {
         Err(e) => {
             assert!(false, "Should not return error: {}", e);
             return;
@@ -73,13 +74,14 @@ async fn valid_vault_request_with_body() {
         .mount(&mock_server)
         .await;
 
-    let auth =
-        hc_vault::token::Session::new("testToken".to_string(), Duration::from_secs(120)).unwrap();
+    common::mock_lookup_self(&mock_server, "testToken").await;
+
+    let auth = hc_vault::token::Session::new("testToken".to_string()).unwrap();
     let conf = hc_vault::Config {
         vault_url: mock_server.uri().clone(),
         ..Default::default()
     };
-    let client = match hc_vault::Client::new(conf, auth) {
+    let client = match hc_vault::Client::new_async(conf, auth).await {
         Err(e) => {
             assert!(false, "Should not return error: {}", e);
             return;
@@ -0,0 +1,145 @@
+extern crate hc_vault;
+
+use wiremock::matchers::{body_json, header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use serde_json::json;
+
+use hc_vault::Auth as AuthTrait;
+
+#[tokio::test]
+async fn valid_login() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/github/login"))
+        .and(body_json(json!({
+            "token": "testGithubToken",
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "auth": {
+                "client_token": "testToken",
+                "accessor": "testAccessor",
+                "policies": ["test"],
+                "metadata": {
+                    "org": "test-org",
+                    "username": "test-user",
+                },
+                "lease_duration": 120,
+                "renewable": true,
+            },
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let config = hc_vault::github::GithubConfig {
+        token: "testGithubToken".to_string(),
+        ..Default::default()
+    };
+    let tmp_auth = match hc_vault::github::Session::new(config) {
+        Err(e) => {
+            assert!(false, "Should not return error: '{}'", e);
+            return;
+        }
+        Ok(s) => s,
+    };
+
+    match tmp_auth
+        .auth(&hc_vault::HttpClient::from_url(&mock_server.uri()).unwrap())
+        .await
+    {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(_) => assert!(true),
+    };
+
+    assert_eq!(tmp_auth.get_token(), "testToken".to_string());
+    assert_eq!(tmp_auth.get_total_duration(), 120);
+    assert_eq!(tmp_auth.is_renewable(), true);
+    assert_eq!(tmp_auth.is_expired(), false);
+}
+
+#[tokio::test]
+async fn invalid_login_403() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/github-org/login"))
+        .respond_with(ResponseTemplate::new(403))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let config = hc_vault::github::GithubConfig {
+        token: "testGithubToken".to_string(),
+        mount: "github-org".to_string(),
+    };
+    let tmp_auth = hc_vault::github::Session::new(config).unwrap();
+
+    match tmp_auth
+        .auth(&hc_vault::HttpClient::from_url(&mock_server.uri()).unwrap())
+        .await
+    {
+        Err(_) => assert!(true),
+        Ok(_) => assert!(false, "Should return error"),
+    };
+}
+
+#[tokio::test]
+async fn valid_renew() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/github/login"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "auth": {
+                "client_token": "testToken",
+                "policies": ["test"],
+                "lease_duration": 10,
+                "renewable": true,
+            },
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/token/renew-self"))
+        .and(header("X-Vault-Token", "testToken"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "auth": {
+                "client_token": "testToken",
+                "policies": ["test"],
+                "lease_duration": 120,
+                "renewable": false,
+            },
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let config = hc_vault::github::GithubConfig {
+        token: "testGithubToken".to_string(),
+        ..Default::default()
+    };
+    let tmp_auth = hc_vault::github::Session::new(config).unwrap();
+
+    match tmp_auth
+        .auth(&hc_vault::HttpClient::from_url(&mock_server.uri()).unwrap())
+        .await
+    {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(_) => assert!(true),
+    };
+
+    match tmp_auth
+        .renew(&hc_vault::HttpClient::from_url(&mock_server.uri()).unwrap())
+        .await
+    {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(_) => assert!(true),
+    };
+
+    assert_eq!(tmp_auth.get_total_duration(), 120);
+    assert_eq!(tmp_auth.is_renewable(), false);
+}
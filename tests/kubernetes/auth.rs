@@ -1,7 +1,5 @@
 extern crate hc_vault;
 
-use async_std::task;
-
 use wiremock::matchers::{body_json, method, path};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
@@ -9,9 +7,9 @@ use serde_json::json;
 
 use hc_vault::Auth as AuthTrait;
 
-#[test]
-fn valid_new_kubernetes() {
-    let mock_server = task::block_on(MockServer::start());
+#[tokio::test]
+async fn valid_new_kubernetes() {
+    let mock_server = MockServer::start().await;
 
     let test_role = "testRole".to_string();
     let test_jwt = "testJWT".to_string();
@@ -41,13 +39,12 @@ fn valid_new_kubernetes() {
     let mut response = ResponseTemplate::new(200);
     response = response.set_body_json(response_body);
 
-    task::block_on(
-        Mock::given(method("POST"))
-            .and(path("/v1/auth/kubernetes/login"))
-            .and(body_json(&expected_body))
-            .respond_with(response)
-            .mount(&mock_server),
-    );
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/kubernetes/login"))
+        .and(body_json(&expected_body))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
 
     let tmp_auth = match hc_vault::kubernetes::Session::new(test_role, test_jwt) {
         Err(e) => {
@@ -57,15 +54,18 @@ fn valid_new_kubernetes() {
         Ok(s) => s,
     };
 
-    match tmp_auth.auth(&mock_server.uri()) {
+    match tmp_auth
+        .auth(&hc_vault::HttpClient::from_url(&mock_server.uri()).unwrap())
+        .await
+    {
         Err(e) => assert!(false, "Should not return error: '{}'", e),
         Ok(_) => assert!(true),
     };
 }
 
-#[test]
-fn invalid_new_kubernetes_not_found() {
-    let mock_server = task::block_on(MockServer::start());
+#[tokio::test]
+async fn invalid_new_kubernetes_not_found() {
+    let mock_server = MockServer::start().await;
 
     let test_role = "testRole".to_string();
     let test_jwt = "testJWT".to_string();
@@ -77,13 +77,12 @@ fn invalid_new_kubernetes_not_found() {
 
     let response = ResponseTemplate::new(404);
 
-    task::block_on(
-        Mock::given(method("POST"))
-            .and(path("/v1/auth/kubernetes/login"))
-            .and(body_json(&expected_body))
-            .respond_with(response)
-            .mount(&mock_server),
-    );
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/kubernetes/login"))
+        .and(body_json(&expected_body))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
 
     let tmp_auth = match hc_vault::kubernetes::Session::new(test_role, test_jwt) {
         Err(e) => {
@@ -93,15 +92,18 @@ fn invalid_new_kubernetes_not_found() {
         Ok(s) => s,
     };
 
-    match tmp_auth.auth(&mock_server.uri()) {
+    match tmp_auth
+        .auth(&hc_vault::HttpClient::from_url(&mock_server.uri()).unwrap())
+        .await
+    {
         Err(_) => assert!(true),
         Ok(_) => assert!(false, "Should return error"),
     };
 }
 
-#[test]
-fn invalid_new_kubernetes_not_valid_403() {
-    let mock_server = task::block_on(MockServer::start());
+#[tokio::test]
+async fn invalid_new_kubernetes_not_valid_403() {
+    let mock_server = MockServer::start().await;
 
     let test_role = "testRole".to_string();
     let test_jwt = "testJWT".to_string();
@@ -113,13 +115,12 @@ fn invalid_new_kubernetes_not_valid_403() {
 
     let response = ResponseTemplate::new(403);
 
-    task::block_on(
-        Mock::given(method("POST"))
-            .and(path("/v1/auth/kubernetes/login"))
-            .and(body_json(&expected_body))
-            .respond_with(response)
-            .mount(&mock_server),
-    );
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/kubernetes/login"))
+        .and(body_json(&expected_body))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
 
     let tmp_auth = match hc_vault::kubernetes::Session::new(test_role, test_jwt) {
         Err(e) => {
@@ -129,7 +130,10 @@ fn invalid_new_kubernetes_not_valid_403() {
         Ok(s) => s,
     };
 
-    match tmp_auth.auth(&mock_server.uri()) {
+    match tmp_auth
+        .auth(&hc_vault::HttpClient::from_url(&mock_server.uri()).unwrap())
+        .await
+    {
         Err(_) => assert!(true),
         Ok(_) => assert!(false, "Should return error"),
     };
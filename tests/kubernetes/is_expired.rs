@@ -1,7 +1,5 @@
 extern crate hc_vault;
 
-use async_std::task;
-
 use wiremock::matchers::{method, path};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
@@ -9,9 +7,9 @@ use serde_json::json;
 
 use hc_vault::Auth as AuthTrait;
 
-#[test]
-fn is_expired_true() {
-    let mock_server = task::block_on(MockServer::start());
+#[tokio::test]
+async fn is_expired_true() {
+    let mock_server = MockServer::start().await;
 
     let response_body = json!({
         "auth": {
@@ -33,12 +31,11 @@ fn is_expired_true() {
     let mut response = ResponseTemplate::new(200);
     response = response.set_body_json(response_body);
 
-    task::block_on(
-        Mock::given(method("POST"))
-            .and(path("/v1/auth/kubernetes/login"))
-            .respond_with(response)
-            .mount(&mock_server),
-    );
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/kubernetes/login"))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
 
     let tmp_auth =
         match hc_vault::kubernetes::Session::new("testRole".to_string(), "testJWT".to_string()) {
@@ -49,7 +46,10 @@ fn is_expired_true() {
             Ok(s) => s,
         };
 
-    match tmp_auth.auth(&mock_server.uri()) {
+    match tmp_auth
+        .auth(&hc_vault::HttpClient::from_url(&mock_server.uri()).unwrap())
+        .await
+    {
         Err(e) => assert!(false, "Should not return error: '{}'", e),
         Ok(_) => assert!(true),
     };
@@ -59,9 +59,9 @@ fn is_expired_true() {
     assert_eq!(true, tmp_auth.is_expired())
 }
 
-#[test]
-fn is_expired_false() {
-    let mock_server = task::block_on(MockServer::start());
+#[tokio::test]
+async fn is_expired_false() {
+    let mock_server = MockServer::start().await;
 
     let response_body = json!({
         "auth": {
@@ -83,12 +83,11 @@ fn is_expired_false() {
     let mut response = ResponseTemplate::new(200);
     response = response.set_body_json(response_body);
 
-    task::block_on(
-        Mock::given(method("POST"))
-            .and(path("/v1/auth/kubernetes/login"))
-            .respond_with(response)
-            .mount(&mock_server),
-    );
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/kubernetes/login"))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
 
     let tmp_auth =
         match hc_vault::kubernetes::Session::new("testRole".to_string(), "testJWT".to_string()) {
@@ -99,7 +98,10 @@ fn is_expired_false() {
             Ok(s) => s,
         };
 
-    match tmp_auth.auth(&mock_server.uri()) {
+    match tmp_auth
+        .auth(&hc_vault::HttpClient::from_url(&mock_server.uri()).unwrap())
+        .await
+    {
         Err(e) => assert!(false, "Should not return error: '{}'", e),
         Ok(_) => assert!(true),
     };
@@ -1,7 +1,5 @@
 extern crate hc_vault;
 
-use async_std::task;
-
 use wiremock::matchers::{header, method, path};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
@@ -9,9 +7,9 @@ use serde_json::json;
 
 use hc_vault::Auth as AuthTrait;
 
-#[test]
-fn valid_renew() {
-    let mock_server = task::block_on(MockServer::start());
+#[tokio::test]
+async fn valid_renew() {
+    let mock_server = MockServer::start().await;
 
     let response_body = json!({
         "auth": {
@@ -33,29 +31,27 @@ fn valid_renew() {
     let mut response = ResponseTemplate::new(200);
     response = response.set_body_json(response_body);
 
-    task::block_on(
-        Mock::given(method("POST"))
-            .and(path("/v1/auth/kubernetes/login"))
-            .respond_with(response)
-            .expect(1)
-            .mount(&mock_server),
-    );
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/kubernetes/login"))
+        .respond_with(response)
+        .expect(1)
+        .mount(&mock_server)
+        .await;
 
-    task::block_on(
-        Mock::given(method("POST"))
-            .and(path("/v1/auth/token/renew-self"))
-            .and(header("X-Vault-Token", "testToken"))
-            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
-                "auth": {
-                    "renewable": true,
-                    "lease_duration": 120,
-                    "policies": vec!["test".to_string()],
-                    "client_token": "testToken".to_string(),
-                },
-            })))
-            .expect(1)
-            .mount(&mock_server),
-    );
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/token/renew-self"))
+        .and(header("X-Vault-Token", "testToken"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "auth": {
+                "renewable": true,
+                "lease_duration": 120,
+                "policies": vec!["test".to_string()],
+                "client_token": "testToken".to_string(),
+            },
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
 
     let tmp_auth =
         match hc_vault::kubernetes::Session::new("testRole".to_string(), "testJWT".to_string()) {
@@ -66,12 +62,18 @@ fn valid_renew() {
             Ok(s) => s,
         };
 
-    match tmp_auth.auth(&mock_server.uri()) {
+    match tmp_auth
+        .auth(&hc_vault::HttpClient::from_url(&mock_server.uri()).unwrap())
+        .await
+    {
         Err(e) => assert!(false, "Should not return error: '{}'", e),
         Ok(_) => assert!(true),
     };
 
-    match tmp_auth.renew(&mock_server.uri()) {
+    match tmp_auth
+        .renew(&hc_vault::HttpClient::from_url(&mock_server.uri()).unwrap())
+        .await
+    {
         Err(e) => assert!(false, "Should not return error: '{}'", e),
         Ok(_) => assert!(true),
     }
@@ -4,7 +4,8 @@ use wiremock::matchers::{body_json, header, method, path};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
 use serde_json::json;
-use std::time::Duration;
+
+use crate::common;
 
 #[tokio::test]
 async fn valid_configure_no_options() {
@@ -29,13 +30,14 @@ async fn valid_configure_no_options() {
         .mount(&mock_server)
         .await;
 
-    let auth =
-        hc_vault::token::Session::new(client_token.to_string(), Duration::from_secs(120)).unwrap();
+    common::mock_lookup_self(&mock_server, client_token).await;
+
+    let auth = hc_vault::token::Session::new(client_token.to_string()).unwrap();
     let conf = hc_vault::Config {
         vault_url: mock_server.uri().clone(),
         ..Default::default()
     };
-    let client = match hc_vault::Client::new(conf, auth) {
+    let client = match hc_vault::Client::new_async(conf, auth).await {
         Err(e) => {
             assert!(false, "Should not return error: '{}'", e);
             return;
@@ -78,13 +80,14 @@ async fn valid_configure_only_max_verison_option() {
         .mount(&mock_server)
         .await;
 
-    let auth =
-        hc_vault::token::Session::new(client_token.to_string(), Duration::from_secs(120)).unwrap();
+    common::mock_lookup_self(&mock_server, client_token).await;
+
+    let auth = hc_vault::token::Session::new(client_token.to_string()).unwrap();
     let conf = hc_vault::Config {
         vault_url: mock_server.uri().clone(),
         ..Default::default()
     };
-    let client = match hc_vault::Client::new(conf, auth) {
+    let client = match hc_vault::Client::new_async(conf, auth).await {
         Err(e) => {
             assert!(false, "Should not return error: '{}'", e);
             return;
@@ -127,13 +130,14 @@ async fn valid_configure_only_cas_required_option() {
         .mount(&mock_server)
         .await;
 
-    let auth =
-        hc_vault::token::Session::new(client_token.to_string(), Duration::from_secs(120)).unwrap();
+    common::mock_lookup_self(&mock_server, client_token).await;
+
+    let auth = hc_vault::token::Session::new(client_token.to_string()).unwrap();
     let conf = hc_vault::Config {
         vault_url: mock_server.uri().clone(),
         ..Default::default()
     };
-    let client = match hc_vault::Client::new(conf, auth) {
+    let client = match hc_vault::Client::new_async(conf, auth).await {
         Err(e) => {
             assert!(false, "Should not return error: '{}'", e);
             return;
@@ -176,13 +180,14 @@ async fn valid_configure_only_delete_version_after_option() {
         .mount(&mock_server)
         .await;
 
-    let auth =
-        hc_vault::token::Session::new(client_token.to_string(), Duration::from_secs(120)).unwrap();
+    common::mock_lookup_self(&mock_server, client_token).await;
+
+    let auth = hc_vault::token::Session::new(client_token.to_string()).unwrap();
     let conf = hc_vault::Config {
         vault_url: mock_server.uri().clone(),
         ..Default::default()
     };
-    let client = match hc_vault::Client::new(conf, auth) {
+    let client = match hc_vault::Client::new_async(conf, auth).await {
         Err(e) => {
             assert!(false, "Should not return error: '{}'", e);
             return;
@@ -227,13 +232,14 @@ async fn valid_configure_all_options() {
         .mount(&mock_server)
         .await;
 
-    let auth =
-        hc_vault::token::Session::new(client_token.to_string(), Duration::from_secs(120)).unwrap();
+    common::mock_lookup_self(&mock_server, client_token).await;
+
+    let auth = hc_vault::token::Session::new(client_token.to_string()).unwrap();
     let conf = hc_vault::Config {
         vault_url: mock_server.uri().clone(),
         ..Default::default()
     };
-    let client = match hc_vault::Client::new(conf, auth) {
+    let client = match hc_vault::Client::new_async(conf, auth).await {
         Err(e) => {
             assert!(false, "Should not return error: '{}'", e);
             return;
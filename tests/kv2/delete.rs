@@ -3,7 +3,7 @@ extern crate hc_vault;
 use wiremock::matchers::{header, method, path};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
-use std::time::Duration;
+use crate::common;
 
 #[tokio::test]
 async fn valid_delete() {
@@ -19,13 +19,14 @@ async fn valid_delete() {
         .mount(&mock_server)
         .await;
 
-    let auth =
-        hc_vault::token::Session::new(client_token.to_string(), Duration::from_secs(120)).unwrap();
+    common::mock_lookup_self(&mock_server, client_token).await;
+
+    let auth = hc_vault::token::Session::new(client_token.to_string()).unwrap();
     let conf = hc_vault::Config {
         vault_url: mock_server.uri().clone(),
         ..Default::default()
     };
-    let client = match hc_vault::Client::new(conf, auth) {
+    let client = match hc_vault::Client::new_async(conf, auth).await {
         Err(e) => {
             assert!(false, "Should not return error: '{}'", e);
             return;
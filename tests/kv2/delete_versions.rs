@@ -4,7 +4,8 @@ use wiremock::matchers::{body_json, header, method, path};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
 use serde_json::json;
-use std::time::Duration;
+
+use crate::common;
 
 #[tokio::test]
 async fn valid_delete_versions() {
@@ -27,13 +28,14 @@ async fn valid_delete_versions() {
         .mount(&mock_server)
         .await;
 
-    let auth =
-        hc_vault::token::Session::new(client_token.to_string(), Duration::from_secs(120)).unwrap();
+    common::mock_lookup_self(&mock_server, client_token).await;
+
+    let auth = hc_vault::token::Session::new(client_token.to_string()).unwrap();
     let conf = hc_vault::Config {
         vault_url: mock_server.uri().clone(),
         ..Default::default()
     };
-    let client = match hc_vault::Client::new(conf, auth) {
+    let client = match hc_vault::Client::new_async(conf, auth).await {
         Err(e) => {
             assert!(false, "Should not return error: '{}'", e);
             return;
@@ -0,0 +1,167 @@
+extern crate hc_vault;
+
+use wiremock::matchers::{body_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use serde_json::json;
+
+use hc_vault::Auth as AuthTrait;
+
+fn login_response() -> ResponseTemplate {
+    ResponseTemplate::new(200).set_body_json(json!({
+        "auth": {
+            "client_token": "testToken",
+            "accessor": "testAccessor",
+            "policies": ["test"],
+            "lease_duration": 120,
+            "renewable": true,
+        },
+    }))
+}
+
+#[tokio::test]
+async fn valid_login_password() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/okta/login/testUser"))
+        .and(body_json(json!({
+            "password": "testPassword",
+        })))
+        .respond_with(login_response())
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let config = hc_vault::okta::OktaConfig {
+        username: "testUser".to_string(),
+        password: "testPassword".to_string(),
+        ..Default::default()
+    };
+    let tmp_auth = match hc_vault::okta::Session::new(config) {
+        Err(e) => {
+            assert!(false, "Should not return error: '{}'", e);
+            return;
+        }
+        Ok(s) => s,
+    };
+
+    match tmp_auth
+        .auth(&hc_vault::HttpClient::from_url(&mock_server.uri()).unwrap())
+        .await
+    {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(_) => assert!(true),
+    };
+
+    assert_eq!(tmp_auth.get_token(), "testToken".to_string());
+    assert_eq!(tmp_auth.get_total_duration(), 120);
+}
+
+#[tokio::test]
+async fn valid_login_totp() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/okta-corp/login/testUser"))
+        .and(body_json(json!({
+            "password": "testPassword",
+            "provider": "OKTA",
+            "totp": "123456",
+        })))
+        .respond_with(login_response())
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let config = hc_vault::okta::OktaConfig {
+        username: "testUser".to_string(),
+        password: "testPassword".to_string(),
+        totp: Some("123456".to_string()),
+        provider: Some("OKTA".to_string()),
+        mount: "okta-corp".to_string(),
+        ..Default::default()
+    };
+    let tmp_auth = hc_vault::okta::Session::new(config).unwrap();
+
+    match tmp_auth
+        .auth(&hc_vault::HttpClient::from_url(&mock_server.uri()).unwrap())
+        .await
+    {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(_) => assert!(true),
+    };
+}
+
+#[tokio::test]
+async fn valid_login_push_nonce() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/okta/login/testUser"))
+        .and(body_json(json!({
+            "nonce": "testNonce",
+            "password": "testPassword",
+        })))
+        .respond_with(login_response())
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/auth/okta/verify/testNonce"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": {
+                "correct_answer": 94,
+            },
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let config = hc_vault::okta::OktaConfig {
+        username: "testUser".to_string(),
+        password: "testPassword".to_string(),
+        nonce: Some("testNonce".to_string()),
+        ..Default::default()
+    };
+    let tmp_auth = hc_vault::okta::Session::new(config).unwrap();
+
+    let http = hc_vault::HttpClient::from_url(&mock_server.uri()).unwrap();
+    match hc_vault::okta::verify_number(&http, "okta", "testNonce").await {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(number) => assert_eq!(number, 94),
+    };
+
+    match tmp_auth.auth(&http).await {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(_) => assert!(true),
+    };
+}
+
+#[tokio::test]
+async fn invalid_login_403() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/okta/login/testUser"))
+        .respond_with(ResponseTemplate::new(403))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let config = hc_vault::okta::OktaConfig {
+        username: "testUser".to_string(),
+        password: "wrongPassword".to_string(),
+        ..Default::default()
+    };
+    let tmp_auth = hc_vault::okta::Session::new(config).unwrap();
+
+    match tmp_auth
+        .auth(&hc_vault::HttpClient::from_url(&mock_server.uri()).unwrap())
+        .await
+    {
+        Err(_) => assert!(true),
+        Ok(_) => assert!(false, "Should return error"),
+    };
+}
@@ -0,0 +1,82 @@
+extern crate hc_vault;
+
+use wiremock::matchers::{body_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use serde_json::json;
+
+use hc_vault::Auth as AuthTrait;
+
+#[tokio::test]
+async fn valid_login() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/radius/login/testUser"))
+        .and(body_json(json!({
+            "password": "testPassword",
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "auth": {
+                "client_token": "testToken",
+                "accessor": "testAccessor",
+                "policies": ["test"],
+                "lease_duration": 120,
+                "renewable": true,
+            },
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let config = hc_vault::radius::RadiusConfig {
+        username: "testUser".to_string(),
+        password: "testPassword".to_string(),
+        ..Default::default()
+    };
+    let tmp_auth = match hc_vault::radius::Session::new(config) {
+        Err(e) => {
+            assert!(false, "Should not return error: '{}'", e);
+            return;
+        }
+        Ok(s) => s,
+    };
+
+    match tmp_auth
+        .auth(&hc_vault::HttpClient::from_url(&mock_server.uri()).unwrap())
+        .await
+    {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(_) => assert!(true),
+    };
+
+    assert_eq!(tmp_auth.get_token(), "testToken".to_string());
+    assert_eq!(tmp_auth.get_total_duration(), 120);
+}
+
+#[tokio::test]
+async fn invalid_login_custom_mount_403() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/radius-corp/login/testUser"))
+        .respond_with(ResponseTemplate::new(403))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let config = hc_vault::radius::RadiusConfig {
+        username: "testUser".to_string(),
+        password: "wrongPassword".to_string(),
+        mount: "radius-corp".to_string(),
+    };
+    let tmp_auth = hc_vault::radius::Session::new(config).unwrap();
+
+    match tmp_auth
+        .auth(&hc_vault::HttpClient::from_url(&mock_server.uri()).unwrap())
+        .await
+    {
+        Err(_) => assert!(true),
+        Ok(_) => assert!(false, "Should return error"),
+    };
+}
@@ -0,0 +1,186 @@
+extern crate hc_vault;
+
+use std::collections::HashMap;
+
+use wiremock::matchers::{body_json, header, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use serde_json::json;
+
+use crate::common::new_client;
+
+fn namespace_response() -> ResponseTemplate {
+    ResponseTemplate::new(200).set_body_json(json!({
+        "data": {
+            "id": "testID",
+            "path": "tenant-a/team-b/",
+            "custom_metadata": {
+                "owner": "team-b",
+            },
+        },
+    }))
+}
+
+fn expected_namespace() -> hc_vault::sys::Namespace {
+    let mut custom_metadata = HashMap::new();
+    custom_metadata.insert("owner".to_string(), "team-b".to_string());
+
+    hc_vault::sys::Namespace {
+        id: "testID".to_string(),
+        path: "tenant-a/team-b/".to_string(),
+        custom_metadata,
+    }
+}
+
+#[tokio::test]
+async fn valid_create_namespace() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/sys/namespaces/team-b"))
+        .and(header("X-Vault-Namespace", "tenant-a"))
+        .and(body_json(json!({
+            "custom_metadata": {
+                "owner": "team-b",
+            },
+        })))
+        .respond_with(namespace_response())
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = new_client(&mock_server).await;
+
+    let mut custom_metadata = HashMap::new();
+    custom_metadata.insert("owner".to_string(), "team-b".to_string());
+
+    match hc_vault::sys::create_namespace(
+        &client.with_namespace("tenant-a"),
+        "team-b",
+        Some(&custom_metadata),
+    )
+    .await
+    {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(ns) => assert_eq!(ns, expected_namespace()),
+    };
+}
+
+#[tokio::test]
+async fn valid_update_namespace() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("PATCH"))
+        .and(path("/v1/sys/namespaces/team-b"))
+        .and(header("X-Vault-Namespace", "tenant-a"))
+        .and(header("Content-Type", "application/merge-patch+json"))
+        .and(body_json(json!({
+            "custom_metadata": {
+                "owner": "team-b",
+            },
+        })))
+        .respond_with(namespace_response())
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = new_client(&mock_server).await;
+
+    let mut custom_metadata = HashMap::new();
+    custom_metadata.insert("owner".to_string(), "team-b".to_string());
+
+    match hc_vault::sys::update_namespace(
+        &client.with_namespace("tenant-a"),
+        "team-b",
+        &custom_metadata,
+    )
+    .await
+    {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(ns) => assert_eq!(ns, expected_namespace()),
+    };
+}
+
+#[tokio::test]
+async fn valid_read_namespace() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/sys/namespaces/team-b"))
+        .and(header("X-Vault-Namespace", "tenant-a"))
+        .respond_with(namespace_response())
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = new_client(&mock_server).await;
+
+    match hc_vault::sys::read_namespace(&client.with_namespace("tenant-a"), "team-b").await {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(ns) => assert_eq!(ns, expected_namespace()),
+    };
+}
+
+#[tokio::test]
+async fn invalid_read_namespace_404() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/sys/namespaces/team-b"))
+        .respond_with(ResponseTemplate::new(404))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = new_client(&mock_server).await;
+
+    match hc_vault::sys::read_namespace(&client, "team-b").await {
+        Err(hc_vault::Error::NotFound(_)) => assert!(true),
+        Err(e) => assert!(false, "Should return NotFound, got: '{}'", e),
+        Ok(_) => assert!(false, "Should return error"),
+    };
+}
+
+#[tokio::test]
+async fn valid_list_namespaces() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/sys/namespaces"))
+        .and(query_param("list", "true"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": {
+                "keys": ["tenant-a/", "tenant-b/"],
+            },
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = new_client(&mock_server).await;
+
+    match hc_vault::sys::list_namespaces(&client).await {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(keys) => assert_eq!(keys, vec!["tenant-a/".to_string(), "tenant-b/".to_string()]),
+    };
+}
+
+#[tokio::test]
+async fn valid_delete_namespace() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/v1/sys/namespaces/team-b"))
+        .and(header("X-Vault-Namespace", "tenant-a"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = new_client(&mock_server).await;
+
+    match hc_vault::sys::delete_namespace(&client.with_namespace("tenant-a"), "team-b").await {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(_) => assert!(true),
+    };
+}
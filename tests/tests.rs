@@ -1,19 +1,70 @@
+// The tests deliberately use `assert!(true/false, ..)` inside of matches to
+// make the expected outcome of every branch explicit
+#![allow(
+    clippy::assertions_on_constants,
+    clippy::bool_assert_comparison,
+    clippy::single_match
+)]
+
+mod common;
+
 mod approle {
     mod auth;
     mod is_expired;
     mod renew;
+    mod role;
+    mod secret_id;
+}
+
+mod aws {
+    mod auth;
+    mod signing;
+}
+
+mod azure {
+    mod auth;
+}
+
+#[cfg(feature = "blocking")]
+mod blocking {
+    mod client;
+    mod modules;
+}
+
+mod cert {
+    mod auth;
 }
 
 mod database {
     mod get_credentials;
 }
 
+mod gcp {
+    mod auth;
+}
+
 mod general {
+    mod clone;
+    mod env;
+    mod errors;
+    mod helpers;
+    mod http;
+    mod namespace;
     mod reauth;
     mod renew;
+    mod renewal;
+    mod response;
+    mod retry;
+    mod session_race;
+    mod timeout;
+    mod tls;
     mod vault_request;
 }
 
+mod github {
+    mod auth;
+}
+
 mod kubernetes {
     mod auth;
     mod is_expired;
@@ -31,3 +82,30 @@ mod kv2 {
     mod undelete_versions;
     mod update_set;
 }
+
+mod okta {
+    mod auth;
+}
+
+mod radius {
+    mod auth;
+}
+
+mod sys {
+    mod namespaces;
+}
+
+mod token {
+    mod auth;
+    mod create;
+    mod lookup;
+    mod renew;
+    mod revoke;
+    mod roles;
+}
+
+mod transport {
+    mod memory;
+    #[cfg(feature = "ureq")]
+    mod ureq;
+}
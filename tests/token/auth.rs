@@ -0,0 +1,151 @@
+extern crate hc_vault;
+
+use wiremock::matchers::{header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use serde_json::json;
+
+use hc_vault::Auth as AuthTrait;
+
+#[tokio::test]
+async fn valid_lookup() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/auth/token/lookup-self"))
+        .and(header("X-Vault-Token", "testToken"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": {
+                "accessor": "testAccessor",
+                "ttl": 120,
+                "renewable": true,
+                "policies": ["default", "test"],
+            },
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let tmp_auth = match hc_vault::token::Session::new("testToken".to_string()) {
+        Err(e) => {
+            assert!(false, "Should not return error: '{}'", e);
+            return;
+        }
+        Ok(s) => s,
+    };
+
+    match tmp_auth
+        .auth(&hc_vault::HttpClient::from_url(&mock_server.uri()).unwrap())
+        .await
+    {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(_) => assert!(true),
+    };
+
+    assert_eq!(tmp_auth.get_token(), "testToken".to_string());
+    assert_eq!(tmp_auth.get_total_duration(), 120);
+    assert_eq!(tmp_auth.is_renewable(), true);
+    assert_eq!(tmp_auth.is_expired(), false);
+    assert_eq!(
+        tmp_auth.policies(),
+        vec!["default".to_string(), "test".to_string()]
+    );
+}
+
+#[tokio::test]
+async fn valid_lookup_non_expiring() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/auth/token/lookup-self"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": {
+                "ttl": 0,
+                "renewable": false,
+                "policies": ["root"],
+            },
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let tmp_auth = hc_vault::token::Session::new("rootToken".to_string()).unwrap();
+
+    match tmp_auth
+        .auth(&hc_vault::HttpClient::from_url(&mock_server.uri()).unwrap())
+        .await
+    {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(_) => assert!(true),
+    };
+
+    assert_eq!(tmp_auth.get_total_duration(), 0);
+    assert_eq!(tmp_auth.is_renewable(), false);
+    assert_eq!(tmp_auth.is_expired(), false);
+}
+
+#[tokio::test]
+async fn invalid_lookup_403() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/auth/token/lookup-self"))
+        .respond_with(ResponseTemplate::new(403))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let tmp_auth = hc_vault::token::Session::new("invalidToken".to_string()).unwrap();
+
+    match tmp_auth
+        .auth(&hc_vault::HttpClient::from_url(&mock_server.uri()).unwrap())
+        .await
+    {
+        Err(hc_vault::Error::Unauthorized(_)) => assert!(true),
+        Err(e) => assert!(false, "Should return unauthorized error: '{}'", e),
+        Ok(_) => assert!(false, "Should return error"),
+    };
+}
+
+#[tokio::test]
+async fn valid_lookup_explicit_max_ttl() {
+    let mock_server = MockServer::start().await;
+
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+
+    Mock::given(method("GET"))
+        .and(path("/v1/auth/token/lookup-self"))
+        .and(header("X-Vault-Token", "testToken"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": {
+                "accessor": "testAccessor",
+                "ttl": 120,
+                "renewable": true,
+                "policies": ["default"],
+                "creation_time": now - 100,
+                "explicit_max_ttl": 1000,
+            },
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let tmp_auth = hc_vault::token::Session::new("testToken".to_string()).unwrap();
+    assert_eq!(tmp_auth.get_max_ttl_remaining(), None);
+
+    match tmp_auth
+        .auth(&hc_vault::HttpClient::from_url(&mock_server.uri()).unwrap())
+        .await
+    {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(_) => assert!(true),
+    };
+
+    match tmp_auth.get_max_ttl_remaining() {
+        Some(remaining) => assert!((898..=900).contains(&remaining)),
+        None => assert!(false, "Should know the max TTL of the token"),
+    };
+}
@@ -0,0 +1,154 @@
+extern crate hc_vault;
+
+use wiremock::matchers::{body_json, header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use serde_json::json;
+
+use crate::common::new_client;
+
+fn create_response() -> ResponseTemplate {
+    ResponseTemplate::new(200).set_body_json(json!({
+        "auth": {
+            "client_token": "childToken",
+            "accessor": "childAccessor",
+            "policies": ["ci", "default"],
+            "token_policies": ["ci", "default"],
+            "metadata": {
+                "job": "build",
+            },
+            "lease_duration": 3600,
+            "renewable": true,
+            "entity_id": "",
+            "token_type": "service",
+            "orphan": false,
+        },
+    }))
+}
+
+#[tokio::test]
+async fn valid_create() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/token/create"))
+        .and(header("X-Vault-Token", "testToken"))
+        .and(body_json(json!({
+            "explicit_max_ttl": "24h",
+            "meta": {
+                "job": "build",
+            },
+            "num_uses": 10,
+            "policies": ["ci"],
+            "ttl": "1h",
+            "type": "service",
+        })))
+        .respond_with(create_response())
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = new_client(&mock_server).await;
+
+    let mut meta = std::collections::HashMap::new();
+    meta.insert("job".to_string(), "build".to_string());
+    let request = hc_vault::token::TokenCreateRequest {
+        explicit_max_ttl: Some("24h".to_string()),
+        meta: Some(meta),
+        num_uses: Some(10),
+        policies: Some(vec!["ci".to_string()]),
+        ttl: Some("1h".to_string()),
+        token_type: Some(hc_vault::token::TokenType::Service),
+        ..Default::default()
+    };
+
+    let auth = match hc_vault::token::create(&client, &request).await {
+        Err(e) => {
+            assert!(false, "Should not return error: '{}'", e);
+            return;
+        }
+        Ok(a) => a,
+    };
+
+    assert_eq!(auth.client_token, "childToken".to_string());
+    assert_eq!(auth.accessor, "childAccessor".to_string());
+    assert_eq!(auth.lease_duration, 3600);
+    assert_eq!(auth.renewable, true);
+    assert_eq!(auth.orphan, false);
+}
+
+#[tokio::test]
+async fn valid_create_orphan_batch() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/token/create-orphan"))
+        .and(body_json(json!({
+            "period": "1h",
+            "type": "batch",
+        })))
+        .respond_with(create_response())
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = new_client(&mock_server).await;
+
+    let request = hc_vault::token::TokenCreateRequest {
+        period: Some("1h".to_string()),
+        token_type: Some(hc_vault::token::TokenType::Batch),
+        ..Default::default()
+    };
+
+    match hc_vault::token::create_orphan(&client, &request).await {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(auth) => assert_eq!(auth.client_token, "childToken".to_string()),
+    };
+}
+
+#[tokio::test]
+async fn valid_create_with_role() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/token/create/ci-jobs"))
+        .and(body_json(json!({
+            "no_parent": true,
+        })))
+        .respond_with(create_response())
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = new_client(&mock_server).await;
+
+    let request = hc_vault::token::TokenCreateRequest {
+        no_parent: Some(true),
+        ..Default::default()
+    };
+
+    match hc_vault::token::create_with_role(&client, "ci-jobs", &request).await {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(auth) => assert_eq!(auth.client_token, "childToken".to_string()),
+    };
+}
+
+#[tokio::test]
+async fn invalid_create_403() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/token/create"))
+        .respond_with(ResponseTemplate::new(403))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = new_client(&mock_server).await;
+
+    match hc_vault::token::create(&client, &Default::default()).await {
+        Err(hc_vault::Error::Unauthorized(_)) => assert!(true),
+        Err(e) => assert!(false, "Should return unauthorized error: '{}'", e),
+        Ok(_) => assert!(false, "Should return error"),
+    };
+}
@@ -0,0 +1,106 @@
+extern crate hc_vault;
+
+use wiremock::matchers::{body_json, header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use serde_json::json;
+
+use crate::common::new_client;
+
+fn lookup_response() -> ResponseTemplate {
+    ResponseTemplate::new(200).set_body_json(json!({
+        "data": {
+            "accessor": "childAccessor",
+            "creation_time": 1523979354,
+            "creation_ttl": 3600,
+            "display_name": "token",
+            "entity_id": "",
+            "expire_time": "2018-04-17T11:35:54.466476215-04:00",
+            "explicit_max_ttl": 0,
+            "id": "childToken",
+            "issue_time": "2018-04-17T10:35:54.466476078-04:00",
+            "meta": null,
+            "num_uses": 0,
+            "orphan": false,
+            "path": "auth/token/create",
+            "policies": ["ci", "default"],
+            "renewable": true,
+            "ttl": 3540,
+            "type": "service",
+        },
+    }))
+}
+
+#[tokio::test]
+async fn valid_lookup() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/token/lookup"))
+        .and(header("X-Vault-Token", "testToken"))
+        .and(body_json(json!({
+            "token": "childToken",
+        })))
+        .respond_with(lookup_response())
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = new_client(&mock_server).await;
+
+    let info = match hc_vault::token::lookup(&client, "childToken").await {
+        Err(e) => {
+            assert!(false, "Should not return error: '{}'", e);
+            return;
+        }
+        Ok(i) => i,
+    };
+
+    assert_eq!(info.id, "childToken".to_string());
+    assert_eq!(info.accessor, "childAccessor".to_string());
+    assert_eq!(info.ttl, 3540);
+    assert_eq!(info.policies, vec!["ci".to_string(), "default".to_string()]);
+    assert_eq!(info.token_type, "service".to_string());
+    assert_eq!(info.meta, None);
+}
+
+#[tokio::test]
+async fn valid_lookup_accessor() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/token/lookup-accessor"))
+        .and(body_json(json!({
+            "accessor": "childAccessor",
+        })))
+        .respond_with(lookup_response())
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = new_client(&mock_server).await;
+
+    match hc_vault::token::lookup_accessor(&client, "childAccessor").await {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(info) => assert_eq!(info.accessor, "childAccessor".to_string()),
+    };
+}
+
+#[tokio::test]
+async fn invalid_lookup_404() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/token/lookup"))
+        .respond_with(ResponseTemplate::new(404))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = new_client(&mock_server).await;
+
+    match hc_vault::token::lookup(&client, "unknownToken").await {
+        Err(_) => assert!(true),
+        Ok(_) => assert!(false, "Should return error"),
+    };
+}
@@ -0,0 +1,154 @@
+extern crate hc_vault;
+
+use wiremock::matchers::{body_json, header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use serde_json::json;
+
+use hc_vault::Auth as AuthTrait;
+
+fn renew_response() -> ResponseTemplate {
+    ResponseTemplate::new(200).set_body_json(json!({
+        "auth": {
+            "client_token": "testToken",
+            "accessor": "testAccessor",
+            "policies": ["test"],
+            "lease_duration": 3600,
+            "renewable": true,
+        },
+    }))
+}
+
+#[tokio::test]
+async fn valid_renew() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/token/renew-self"))
+        .and(header("X-Vault-Token", "testToken"))
+        .respond_with(renew_response())
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let tmp_auth = hc_vault::token::Session::new("testToken".to_string()).unwrap();
+
+    match tmp_auth
+        .renew(&hc_vault::HttpClient::from_url(&mock_server.uri()).unwrap())
+        .await
+    {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(_) => assert!(true),
+    };
+
+    assert_eq!(tmp_auth.get_token(), "testToken".to_string());
+    assert_eq!(tmp_auth.get_total_duration(), 3600);
+    assert_eq!(tmp_auth.is_renewable(), true);
+}
+
+#[tokio::test]
+async fn valid_renew_with_increment() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/token/renew-self"))
+        .and(header("X-Vault-Token", "testToken"))
+        .and(body_json(json!({
+            "increment": 3600,
+        })))
+        .respond_with(renew_response())
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let tmp_auth =
+        hc_vault::token::Session::with_increment("testToken".to_string(), Some(3600)).unwrap();
+
+    match tmp_auth
+        .renew(&hc_vault::HttpClient::from_url(&mock_server.uri()).unwrap())
+        .await
+    {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(_) => assert!(true),
+    };
+
+    assert_eq!(tmp_auth.get_total_duration(), 3600);
+}
+
+#[tokio::test]
+async fn invalid_renew_403() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/token/renew-self"))
+        .respond_with(ResponseTemplate::new(403))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let tmp_auth = hc_vault::token::Session::new("testToken".to_string()).unwrap();
+
+    match tmp_auth
+        .renew(&hc_vault::HttpClient::from_url(&mock_server.uri()).unwrap())
+        .await
+    {
+        Err(_) => assert!(true),
+        Ok(_) => assert!(false, "Should return error"),
+    };
+}
+
+#[tokio::test]
+async fn valid_renew_token() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/auth/token/lookup-self"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": {
+                "ttl": 0,
+                "renewable": false,
+                "policies": ["root"],
+            },
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/token/renew"))
+        .and(header("X-Vault-Token", "testToken"))
+        .and(body_json(json!({
+            "increment": 600,
+            "token": "childToken",
+        })))
+        .respond_with(renew_response())
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/token/renew-accessor"))
+        .and(body_json(json!({
+            "accessor": "childAccessor",
+        })))
+        .respond_with(renew_response())
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let auth = hc_vault::token::Session::new("testToken".to_string()).unwrap();
+    let conf = hc_vault::Config {
+        vault_url: mock_server.uri(),
+        ..Default::default()
+    };
+    let client = hc_vault::Client::new_async(conf, auth).await.unwrap();
+
+    match hc_vault::token::renew(&client, "childToken", Some(600)).await {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(auth) => assert_eq!(auth.lease_duration, 3600),
+    };
+
+    match hc_vault::token::renew_accessor(&client, "childAccessor", None).await {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(auth) => assert_eq!(auth.renewable, true),
+    };
+}
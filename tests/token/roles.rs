@@ -0,0 +1,162 @@
+extern crate hc_vault;
+
+use wiremock::matchers::{body_json, header, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use serde_json::json;
+
+use crate::common::new_client;
+
+#[tokio::test]
+async fn valid_write_role() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/token/roles/ci-jobs"))
+        .and(header("X-Vault-Token", "testToken"))
+        .and(body_json(json!({
+            "allowed_policies": ["ci"],
+            "orphan": true,
+            "token_ttl": 3600,
+            "token_type": "batch",
+        })))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = new_client(&mock_server).await;
+
+    let role = hc_vault::token::TokenRole {
+        allowed_policies: Some(vec!["ci".to_string()]),
+        orphan: Some(true),
+        token_ttl: Some(3600),
+        token_type: Some(hc_vault::token::TokenType::Batch),
+        ..Default::default()
+    };
+
+    match hc_vault::token::write_role(&client, "ci-jobs", &role).await {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(_) => assert!(true),
+    };
+}
+
+#[tokio::test]
+async fn valid_read_role() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/auth/token/roles/ci-jobs"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": {
+                "allowed_policies": ["ci"],
+                "disallowed_policies": [],
+                "name": "ci-jobs",
+                "orphan": true,
+                "path_suffix": "",
+                "renewable": true,
+                "token_explicit_max_ttl": 0,
+                "token_period": 0,
+                "token_ttl": 3600,
+                "token_type": "batch",
+            },
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = new_client(&mock_server).await;
+
+    let role = match hc_vault::token::read_role(&client, "ci-jobs").await {
+        Err(e) => {
+            assert!(false, "Should not return error: '{}'", e);
+            return;
+        }
+        Ok(r) => r,
+    };
+
+    assert_eq!(role.allowed_policies, Some(vec!["ci".to_string()]));
+    assert_eq!(role.orphan, Some(true));
+    assert_eq!(role.token_ttl, Some(3600));
+    assert_eq!(role.token_type, Some(hc_vault::token::TokenType::Batch));
+    assert_eq!(role.token_max_ttl, None);
+}
+
+#[tokio::test]
+async fn valid_read_role_default_type() {
+    let mock_server = MockServer::start().await;
+
+    // Roles created without an explicit type report vault's default
+    Mock::given(method("GET"))
+        .and(path("/v1/auth/token/roles/web"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": {
+                "allowed_policies": [],
+                "disallowed_policies": [],
+                "name": "web",
+                "orphan": false,
+                "path_suffix": "",
+                "renewable": true,
+                "token_explicit_max_ttl": 0,
+                "token_period": 0,
+                "token_ttl": 0,
+                "token_type": "default-service",
+            },
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = new_client(&mock_server).await;
+
+    match hc_vault::token::read_role(&client, "web").await {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(role) => assert_eq!(
+            role.token_type,
+            Some(hc_vault::token::TokenType::DefaultService)
+        ),
+    };
+}
+
+#[tokio::test]
+async fn valid_list_roles() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/auth/token/roles"))
+        .and(query_param("list", "true"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": {
+                "keys": ["ci-jobs", "deploy"],
+            },
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = new_client(&mock_server).await;
+
+    match hc_vault::token::list_roles(&client).await {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(roles) => assert_eq!(roles, vec!["ci-jobs".to_string(), "deploy".to_string()]),
+    };
+}
+
+#[tokio::test]
+async fn valid_delete_role() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/v1/auth/token/roles/ci-jobs"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = new_client(&mock_server).await;
+
+    match hc_vault::token::delete_role(&client, "ci-jobs").await {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(_) => assert!(true),
+    };
+}
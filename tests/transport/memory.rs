@@ -0,0 +1,221 @@
+extern crate hc_vault;
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use hc_vault::transport::{
+    MemoryTransport, Request, Response, StatusCode, TransportError, TransportErrorKind,
+};
+
+use serde_json::json;
+
+/// Answers the token lookup of the Auth-Backend and passes all other
+/// requests to the given handler
+fn fake_vault<F>(handler: F) -> MemoryTransport
+where
+    F: Fn(Request) -> Result<Response, TransportError> + Send + Sync + 'static,
+{
+    MemoryTransport::new(move |req| {
+        if req.url.path() == "/v1/auth/token/lookup-self" {
+            return Ok(Response::from_json(
+                StatusCode::OK,
+                &json!({
+                    "data": {
+                        "ttl": 0,
+                        "renewable": false,
+                        "policies": ["root"],
+                    },
+                }),
+            ));
+        }
+
+        handler(req)
+    })
+}
+
+async fn new_client(
+    transport: MemoryTransport,
+    retry: hc_vault::RetryPolicy,
+) -> hc_vault::Client<hc_vault::token::Session> {
+    let auth = hc_vault::token::Session::new("testToken".to_string()).unwrap();
+    let conf = hc_vault::Config {
+        transport: Some(Arc::new(transport)),
+        retry,
+        ..Default::default()
+    };
+    hc_vault::Client::new_async(conf, auth).await.unwrap()
+}
+
+fn retry_policy() -> hc_vault::RetryPolicy {
+    hc_vault::RetryPolicy {
+        max_retries: 2,
+        min_backoff: Duration::from_millis(10),
+        max_backoff: Duration::from_millis(20),
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn valid_read() {
+    let transport = fake_vault(|req| {
+        assert_eq!(req.method, hc_vault::transport::Method::GET);
+        assert_eq!(req.url.as_str(), "http://localhost:8200/v1/secret/app");
+        assert_eq!(req.get_header("x-vault-token"), Some("testToken"));
+        assert_eq!(req.get_header("X-Vault-Namespace"), None);
+
+        Ok(Response::from_json(
+            StatusCode::OK,
+            &json!({
+                "data": {
+                    "password": "hunter2",
+                },
+            }),
+        ))
+    });
+    let client = new_client(transport, hc_vault::RetryPolicy::disabled()).await;
+
+    match client.read::<serde_json::Value>("secret/app").await {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(resp) => assert_eq!(resp.data, json!({"password": "hunter2"})),
+    };
+}
+
+#[tokio::test]
+async fn valid_write_with_namespace() {
+    let transport = fake_vault(|req| {
+        assert_eq!(req.method, hc_vault::transport::Method::POST);
+        assert_eq!(req.get_header("X-Vault-Namespace"), Some("tenant-a"));
+        assert_eq!(
+            req.body,
+            Some(br#"{"password":"hunter2"}"#.to_vec()),
+            "Should send the body as JSON"
+        );
+
+        Ok(Response::new(StatusCode::NO_CONTENT, ""))
+    });
+    let client = new_client(transport, hc_vault::RetryPolicy::disabled()).await;
+
+    match client
+        .with_namespace("tenant-a")
+        .write::<_, serde::de::IgnoredAny>("secret/app", &json!({"password": "hunter2"}))
+        .await
+    {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(resp) => assert!(resp.is_none()),
+    };
+}
+
+#[tokio::test]
+async fn invalid_status() {
+    let transport = fake_vault(|_| {
+        Ok(Response::from_json(
+            StatusCode::NOT_FOUND,
+            &json!({
+                "errors": ["no handler for route"],
+            }),
+        ))
+    });
+    let client = new_client(transport, hc_vault::RetryPolicy::disabled()).await;
+
+    match client.read::<serde::de::IgnoredAny>("secret/app").await {
+        Err(hc_vault::Error::NotFound(r)) => {
+            assert_eq!(r.path, "/v1/secret/app");
+            assert_eq!(r.errors, vec!["no handler for route".to_string()]);
+        }
+        Err(e) => assert!(false, "Should return NotFound, got: '{}'", e),
+        Ok(_) => assert!(false, "Should return error"),
+    };
+}
+
+#[tokio::test]
+async fn valid_connect_error_retried() {
+    let attempts = Arc::new(AtomicUsize::new(0));
+    let counter = attempts.clone();
+    let transport = fake_vault(move |_| {
+        if counter.fetch_add(1, Ordering::SeqCst) == 0 {
+            return Err(TransportError::new(
+                TransportErrorKind::Connect,
+                "connection refused",
+            ));
+        }
+
+        Ok(Response::from_json(StatusCode::OK, &json!({"data": {}})))
+    });
+    let client = new_client(transport, retry_policy()).await;
+
+    match client.read::<serde::de::IgnoredAny>("secret/app").await {
+        Err(e) => assert!(false, "Should not return error: '{}'", e),
+        Ok(_) => assert_eq!(attempts.load(Ordering::SeqCst), 2),
+    };
+}
+
+#[tokio::test]
+async fn invalid_other_error_not_retried() {
+    let attempts = Arc::new(AtomicUsize::new(0));
+    let counter = attempts.clone();
+    let transport = fake_vault(move |_| {
+        counter.fetch_add(1, Ordering::SeqCst);
+        Err(TransportError::new(
+            TransportErrorKind::Other,
+            "invalid request",
+        ))
+    });
+    let client = new_client(transport, retry_policy()).await;
+
+    match client.read::<serde::de::IgnoredAny>("secret/app").await {
+        Err(hc_vault::Error::TransportError(e)) => {
+            assert_eq!(e.kind(), TransportErrorKind::Other);
+            assert_eq!(attempts.load(Ordering::SeqCst), 1);
+        }
+        Err(e) => assert!(false, "Should return TransportError, got: '{}'", e),
+        Ok(_) => assert!(false, "Should return error"),
+    };
+}
+
+#[tokio::test]
+async fn invalid_timeout() {
+    let transport = fake_vault(|req| {
+        // The timeout is the time that is left until the deadline
+        let timeout = req.timeout.unwrap();
+        assert!(timeout <= Duration::from_secs(5) && timeout > Duration::from_secs(4));
+        Err(TransportError::new(
+            TransportErrorKind::Timeout,
+            "timed out",
+        ))
+    });
+    let client = new_client(transport, hc_vault::RetryPolicy::disabled()).await;
+
+    match client
+        .with_timeout(Duration::from_secs(5))
+        .read::<serde::de::IgnoredAny>("secret/app")
+        .await
+    {
+        Err(hc_vault::Error::Timeout(_)) => assert!(true),
+        Err(e) => assert!(false, "Should return Timeout, got: '{}'", e),
+        Ok(_) => assert!(false, "Should return error"),
+    };
+}
+
+#[test]
+fn valid_without_tokio() {
+    let attempts = Arc::new(AtomicUsize::new(0));
+    let counter = attempts.clone();
+    let transport = fake_vault(move |_| {
+        if counter.fetch_add(1, Ordering::SeqCst) == 0 {
+            return Ok(Response::new(StatusCode::SERVICE_UNAVAILABLE, ""));
+        }
+
+        Ok(Response::from_json(StatusCode::OK, &json!({"data": {}})))
+    });
+
+    // The retry backoff does not depend on a tokio runtime
+    async_std::task::block_on(async {
+        let client = new_client(transport, retry_policy()).await;
+
+        match client.read::<serde::de::IgnoredAny>("secret/app").await {
+            Err(e) => assert!(false, "Should not return error: '{}'", e),
+            Ok(_) => assert_eq!(attempts.load(Ordering::SeqCst), 2),
+        };
+    });
+}
@@ -0,0 +1,103 @@
+extern crate hc_vault;
+
+use async_std::task;
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use wiremock::matchers::{header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use serde_json::json;
+
+use crate::common::new_client_with_config;
+
+async fn new_client(mock_server: &MockServer) -> hc_vault::Client<hc_vault::token::Session> {
+    let mut conf = hc_vault::Config {
+        vault_url: mock_server.uri(),
+        retry: hc_vault::RetryPolicy::disabled(),
+        ..Default::default()
+    };
+    let transport = hc_vault::transport::UreqTransport::new(&conf).unwrap();
+    conf.transport = Some(Arc::new(transport));
+
+    new_client_with_config(mock_server, conf).await
+}
+
+#[test]
+fn valid_read() {
+    task::block_on(async {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/v1/secret/app"))
+            .and(header("X-Vault-Token", "testToken"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "data": {
+                    "password": "hunter2",
+                },
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = new_client(&mock_server).await;
+
+        match client.read::<serde_json::Value>("secret/app").await {
+            Err(e) => assert!(false, "Should not return error: '{}'", e),
+            Ok(resp) => assert_eq!(resp.data, json!({"password": "hunter2"})),
+        };
+    });
+}
+
+#[test]
+fn invalid_read_not_found() {
+    task::block_on(async {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/v1/secret/app"))
+            .respond_with(ResponseTemplate::new(404))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = new_client(&mock_server).await;
+
+        match client.read::<serde::de::IgnoredAny>("secret/app").await {
+            Err(hc_vault::Error::NotFound(r)) => assert_eq!(r.path, "/v1/secret/app"),
+            Err(e) => assert!(false, "Should return NotFound, got: '{}'", e),
+            Ok(_) => assert!(false, "Should return error"),
+        };
+    });
+}
+
+#[test]
+fn invalid_timeout() {
+    task::block_on(async {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/v1/secret/app"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(json!({"data": {}}))
+                    .set_delay(Duration::from_millis(500)),
+            )
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = new_client(&mock_server).await;
+
+        match client
+            .with_timeout(Duration::from_millis(100))
+            .read::<serde::de::IgnoredAny>("secret/app")
+            .await
+        {
+            Err(hc_vault::Error::Timeout(_)) => assert!(true),
+            Err(e) => assert!(false, "Should return Timeout, got: '{}'", e),
+            Ok(_) => assert!(false, "Should return error"),
+        };
+    });
+}